@@ -0,0 +1,348 @@
+## Üst çubuk ve uygulama geneli
+
+menu-theme = 🎨 Theme
+menu-theme-editor = Theme editor…
+menu-settings = ⚙ Settings
+settings-data-server = Data server
+settings-same-origin = same origin
+settings-reset-default = Reset to default
+settings-server-override = This session uses { $url }, given at startup
+settings-use-saved-url = Use the saved address
+settings-live-updates = Live updates from the server
+settings-live-updates-hint = Subscribe to the server's change feed (/changes) and update open customer tables as rows change
+settings-sign-in = 🔑 Sign in…
+settings-signed-in-as = Signed in as { $user }
+settings-signed-in-token = Signed in with an API token
+settings-sign-out = Sign out
+warning-title = Warning
+last-workspace-warning = The last remaining workspace cannot be deleted.
+ok = Ok
+
+## Çalışma alanı sekmeleri
+
+workspace-tab-hint = Double-click to rename, drag to reorder, right-click for more
+workspace-tab-rename = ✏ Rename
+workspace-tab-duplicate = ⎘ Duplicate
+workspace-tab-close = ✖ Close
+workspace-tab-close-others = Close others
+workspace-default-name = Workspace{ $number }
+workspace-copy-name = { $name } (copy)
+workspace-welcome-name = Welcome
+
+## Çöp kutusu
+
+trash-menu = 🗑 Trash ({ $count })
+trash-empty = Trash is empty
+trash-window-count = { $count ->
+    [one] 1 window
+   *[other] { $count } windows
+}
+trash-restore = ↺ Restore
+trash-delete-permanently = Delete permanently
+trash-empty-all = Empty trash
+trash-deleted-one = Deleted workspace “{ $name }”
+trash-deleted-many = Deleted { $count } workspaces
+trash-undo = ↶ Undo
+
+## Tema düzenleyicisi
+
+theme-editor-title = Theme
+theme-preset-light = ☀ Light
+theme-preset-dark = 🌙 Dark
+theme-preset-high-contrast = ◐ High contrast
+theme-preset-custom = 🎨 Custom
+theme-base = Base
+theme-base-light = Light
+theme-base-dark = Dark
+theme-window-fill = Window fill
+theme-accent = Accent
+theme-text-color = Text color
+theme-override = Override
+theme-window-rounding = Window rounding
+theme-body-size = Body text
+theme-heading-size = Headings
+theme-monospace-size = Monospace
+theme-small-size = Small text
+theme-item-spacing = Item spacing
+theme-button-padding = Button padding
+theme-shadows = Shadows
+theme-shadows-windows-menus = Windows and menus
+theme-shadow-blur = Shadow blur
+theme-shadow-offset = Shadow offset
+theme-shadow-color = Shadow color
+theme-preview = Preview
+theme-preview-heading = Heading
+theme-preview-body = Body text
+theme-preview-button = Button
+theme-preview-selected = Selected
+theme-preview-link = Link
+theme-preview-checkbox = Checkbox
+theme-preview-small = Small text
+
+## Çalışma alanı paneli
+
+workspace-current = Current workspace
+workspace-menu = Menu
+workspace-section = Workspace
+workspace-delete = 🗑 Delete workspace
+workspace-add = ➕ Add workspace
+workspace-export = 📤 Export workspace
+workspace-import = 📥 Import workspace
+workspace-file-label = Workspace layout
+workspace-exported = Exported to { $target }
+workspace-imported = Imported "{ $name }" from { $source }
+workspace-windows = Windows
+workspace-docked = 🗔 Docked layout
+workspace-docked-hint = Show views as tabs in splittable panes instead of floating windows
+workspace-organize-windows = Organize windows
+workspace-close-all-windows = Close all windows
+window-close = Close window
+window-rename = Rename window
+window-float = Float window
+window-dock = Dock window
+dock-empty = Open a view from the menu to dock it here
+dock-float = ⧉ Float
+dock-close = ✖ Close
+
+## Görünüm menüsü; anahtarlar görünüm türünden (`view-<kind>`) ve kategoriden türetilir.
+
+category-data = Data
+category-dashboards = Dashboards
+category-other = Other
+view-sqlite_data = Connect Sqlite Database
+view-query_console = SQL Query Console
+view-schema_browser = Schema Browser
+view-customer_orders = Customer Orders
+view-chart = Chart
+view-test_window = Test Window
+
+## Ortak
+
+close = Close
+loading = Loading...
+refresh = 🔄 Refresh
+query = Query
+query-run = ▶ Run
+query-running = Running query...
+rows-count = { $count } row(s)
+error-retry = 🔄 Retry
+error-sign-in = 🔑 Sign in
+error-copy-diagnostics = 📋 Copy diagnostics
+export-menu = 💾 Export
+export-done = Exported { $count } row(s) to { $target }
+
+## Veri kaynağı seçici
+
+source = Source:
+source-http = HTTP server
+source-local = Local file
+source-local-unavailable = This build was compiled without the `local-sqlite` feature.
+source-database-file = Database file:
+
+## Hata paneli başlıkları
+
+error-connection-refused = Cannot connect to the data server
+error-timeout = The data server did not respond in time
+error-http-status = The data server returned an error
+error-invalid-json = The response is not valid JSON
+error-schema-mismatch = The response has an unexpected shape
+error-database = Database error
+error-request = The request could not be sent
+error-unauthorized = Sign-in required
+
+## Sonuç tablosu
+
+grid-no-columns = No columns
+grid-clear = ✖ Clear sort and filters
+grid-matching-rows = { $count } of { $total } row(s) match the filters
+grid-sort-hint = Click to sort, Shift+click to add to the sort
+grid-filter = Filter
+grid-filter-hint =
+    text: contains
+    =text: equals
+    10..20, >5, <=3: numeric range
+
+## Satır düzenleme
+
+edits-keep-row = Keep this row
+edits-delete-row = Delete this row on save
+edits-add-row = ➕ Add row
+edits-add = Add
+edits-remove-new-row = Remove this new row
+edits-new = new
+edits-unsaved = { $count } unsaved change(s)
+edits-save-all = 💾 Save all
+edits-discard = ↺ Discard
+edits-saving = Saving...
+edits-failed = ⚠ { $count } change(s) could not be saved; hover the highlighted cells for details.
+
+## Müşteri tablosu
+
+customers-name-required = Customer name is required
+customers-name-too-long = Customer name must be at most { $max } characters
+customers-address-too-long = Address must be at most { $max } characters
+customers-page-size = Page size:
+customers-page-count = of { $count }
+customers-rows-range = Rows { $first }–{ $last } of { $total } customers
+customers-heading = Customer Data from Server
+customers-fetch = Fetch Customer Data
+customers-edit-rows = ✏ Edit rows
+customers-edit-rows-hint = Edit cells, add rows and mark rows for deletion, then save them all at once
+customers-fetching = Fetching data from server...
+customers-raw-json = Raw JSON Data
+customers-raw-json-hint = Click '{ customers-fetch }' to load data from { $source }
+customers-table = Customer Data Table
+customers-export-name = Customers
+customers-show-orders = Show orders
+customers-selected-id = Selected Customer ID: { $id }
+
+## Müşteri siparişleri
+
+orders-title = Customer Orders: { $customer }
+orders-empty = Click a customer name in the customer table to see their orders.
+orders-back = ⬅ Back
+orders-count = { $count } order(s)
+orders-lines = { $count } line(s)
+orders-items = { $count } item(s)
+orders-total = Total { $total }
+orders-order-total = Order total { $total }
+orders-product-not-found = Product not found
+orders-show-lines = Show order lines
+orders-show-product = Show product
+orders-order-crumb = Order { $id }
+
+## SQL konsolu ve şema tarayıcısı
+
+query-console-table-title = Table: { $table }
+query-executed-in = executed in { $ms } ms
+query-round-trip = round trip { $ms } ms
+schema-open-data = Open data
+schema-open-data-hint = Double-click to open data
+schema-indexes = Indexes
+schema-foreign-keys = Foreign keys
+schema-filter-tables = Filter tables
+schema-loading = Loading schema...
+schema-tables = Tables
+schema-views = Views
+
+## Grafikler
+
+chart-preset-title = Chart: { $preset }
+chart-preset = Preset:
+chart-choose = Choose...
+chart-preset-orders-per-month = Orders per month
+chart-preset-revenue-per-country = Revenue per country
+chart-preset-top-products = Top 10 products
+chart-preset-revenue-share = Revenue share by category
+chart-preset-price-vs-quantity = Price vs. quantity sold
+chart-type-bar = 📊 Bar
+chart-type-line = 📈 Line
+chart-type-pie = ◔ Pie
+chart-type-scatter = ⁘ Scatter
+chart-aggregation-count = Count
+chart-aggregation-sum = Sum
+chart-aggregation-average = Average
+chart-aggregation-min = Min
+chart-aggregation-max = Max
+chart-series = { $aggregation } of { $column }
+chart-x = X:
+chart-y = Y:
+chart-row-count = (row count)
+chart-order-category = A→Z
+chart-order-largest = Largest first
+chart-top = Top:
+chart-top-hint = Show only the first N categories (0 = all)
+chart-no-rows = No rows to chart
+chart-no-positive-values = No positive values to show
+chart-choose-x = Choose a column for the X axis
+chart-missing-column = Column '{ $column }' is not in the result
+chart-scatter-needs-y = A scatter chart needs a numeric Y column
+chart-legend-series = { $series } by { $column }
+chart-legend-points = { $y } vs. { $x } ({ $count } points)
+chart-share-of-total = { $share }% of { $total }
+
+## Bilgi ve test pencereleri
+
+info-heading = Info
+info-text = This is a demo application. It is based on the Egui framework and is intended to be a simple example of how to use Egui. Visit the{" "}
+info-link = emarti GitHUB Repositories
+test-window-label = This is a test window with a special label.
+
+## Komut paleti ve klavye kısayolları
+
+menu-keyboard-shortcuts = ⌨ Keyboard shortcuts…
+palette-hint = Type a command or workspace name…
+palette-no-match = No matching commands
+command-palette = 🔍 Command palette
+command-select-workspace = Switch to workspace: { $name }
+command-next-workspace = Next workspace
+command-previous-workspace = Previous workspace
+command-add-workspace = ➕ Add workspace
+command-delete-workspace = 🗑 Delete workspace
+command-restore-workspace = ↺ Restore last deleted workspace
+command-open-view = Open { $view }
+command-organize-windows = Organize windows
+command-close-all-windows = Close all windows
+command-refresh-data = 🔄 Refresh data
+command-toggle-theme = Toggle light/dark theme
+command-theme-editor = 🎨 Theme editor
+command-keyboard-shortcuts = ⌨ Keyboard shortcuts
+shortcuts-title = Keyboard shortcuts
+shortcuts-set = Set
+shortcuts-clear = Remove shortcut
+shortcuts-press-keys = Press a key with Ctrl/Cmd or Alt, or an F key… (Esc cancels)
+shortcuts-reset = Reset to defaults
+
+## Kendiliğinden yenileme
+
+refresh-auto = 🔄 Auto-refresh
+refresh-off = Off
+refresh-custom = Custom
+refresh-seconds = { $count } s
+refresh-minutes = { $count } min
+refresh-seconds-suffix = { " " }s
+refresh-paused = ⏸ Paused while editing
+refresh-updated-seconds = Updated { $count ->
+    [one] 1 second
+   *[other] { $count } seconds
+} ago
+refresh-updated-minutes = Updated { $count ->
+    [one] 1 minute
+   *[other] { $count } minutes
+} ago
+refresh-updated-hours = Updated { $count ->
+    [one] 1 hour
+   *[other] { $count } hours
+} ago
+
+## Canlı güncellemeler
+
+feed-connecting = ◌ Connecting…
+feed-connecting-hint = Connecting to the change feed at { $url }
+feed-live = ● Live
+feed-live-hint = Receiving changes from { $url }
+feed-reconnecting = ● Reconnecting (attempt { $attempt })
+feed-reconnecting-hint = The change feed at { $url } is unavailable: { $message }
+    Next attempt in { $seconds } s.
+feed-closed = The change feed connection was closed
+feed-timed-out = The server sent nothing for { $seconds } s, so the connection was dropped
+feed-unauthorized = The server requires signing in
+
+## Oturum açma
+
+login-title = 🔑 Sign in
+login-server = Data server: { $url }
+login-required = The data server requires signing in. Waiting requests continue after you sign in.
+login-rejected = The server rejected the saved credentials. Sign in again to continue.
+login-method-password = Username and password
+login-method-token = API token
+login-username = Username
+login-password = Password
+login-token = Token
+login-token-hint = sent as a Bearer token
+login-remember = Remember on this device
+login-remember-hint = Saves the token unencrypted with the app state in a file on this computer; anyone who can read that file can use it. Otherwise the token is forgotten when the app closes.
+login-remember-hint-web = Saves the token unencrypted in this browser's local storage, where any script running on this site can read it. Otherwise the token is forgotten when the page closes.
+login-invalid = Wrong username or password.
+login-sign-in = Sign in
+login-cancel = Cancel
@@ -0,0 +1,336 @@
+## Üst çubuk ve uygulama geneli
+
+menu-theme = 🎨 Tema
+menu-theme-editor = Tema düzenleyicisi…
+menu-settings = ⚙ Ayarlar
+settings-data-server = Veri sunucusu
+settings-same-origin = aynı köken
+settings-reset-default = Varsayılana dön
+settings-server-override = Bu oturumda açılışta verilen { $url } kullanılıyor
+settings-use-saved-url = Kayıtlı adresi kullan
+settings-live-updates = Sunucudan canlı güncellemeler
+settings-live-updates-hint = Sunucunun değişiklik akışına (/changes) abone olur ve satırlar değiştikçe açık müşteri tablolarını günceller
+settings-sign-in = 🔑 Oturum aç…
+settings-signed-in-as = { $user } olarak oturum açıldı
+settings-signed-in-token = API belirteciyle oturum açıldı
+settings-sign-out = Oturumu kapat
+warning-title = Uyarı
+last-workspace-warning = Kalan son çalışma alanı silinemez.
+ok = Tamam
+
+## Çalışma alanı sekmeleri
+
+workspace-tab-hint = Yeniden adlandırmak için çift tıklayın, sıralamak için sürükleyin, diğer işlemler için sağ tıklayın
+workspace-tab-rename = ✏ Yeniden adlandır
+workspace-tab-duplicate = ⎘ Çoğalt
+workspace-tab-close = ✖ Kapat
+workspace-tab-close-others = Diğerlerini kapat
+workspace-default-name = Çalışma alanı { $number }
+workspace-copy-name = { $name } (kopya)
+workspace-welcome-name = Hoş geldiniz
+
+## Çöp kutusu
+
+trash-menu = 🗑 Çöp kutusu ({ $count })
+trash-empty = Çöp kutusu boş
+trash-window-count = { $count } pencere
+trash-restore = ↺ Geri al
+trash-delete-permanently = Kalıcı olarak sil
+trash-empty-all = Çöp kutusunu boşalt
+trash-deleted-one = “{ $name }” çalışma alanı silindi
+trash-deleted-many = { $count } çalışma alanı silindi
+trash-undo = ↶ Geri al
+
+## Tema düzenleyicisi
+
+theme-editor-title = Tema
+theme-preset-light = ☀ Açık
+theme-preset-dark = 🌙 Koyu
+theme-preset-high-contrast = ◐ Yüksek karşıtlık
+theme-preset-custom = 🎨 Özel
+theme-base = Temel
+theme-base-light = Açık
+theme-base-dark = Koyu
+theme-window-fill = Pencere dolgusu
+theme-accent = Vurgu rengi
+theme-text-color = Metin rengi
+theme-override = Değiştir
+theme-window-rounding = Pencere köşeleri
+theme-body-size = Gövde metni
+theme-heading-size = Başlıklar
+theme-monospace-size = Eş aralıklı
+theme-small-size = Küçük metin
+theme-item-spacing = Öğe aralığı
+theme-button-padding = Düğme iç boşluğu
+theme-shadows = Gölgeler
+theme-shadows-windows-menus = Pencereler ve menüler
+theme-shadow-blur = Gölge bulanıklığı
+theme-shadow-offset = Gölge kayması
+theme-shadow-color = Gölge rengi
+theme-preview = Önizleme
+theme-preview-heading = Başlık
+theme-preview-body = Gövde metni
+theme-preview-button = Düğme
+theme-preview-selected = Seçili
+theme-preview-link = Bağlantı
+theme-preview-checkbox = Onay kutusu
+theme-preview-small = Küçük metin
+
+## Çalışma alanı paneli
+
+workspace-current = Geçerli çalışma alanı
+workspace-menu = Menü
+workspace-section = Çalışma alanı
+workspace-delete = 🗑 Çalışma alanını sil
+workspace-add = ➕ Çalışma alanı ekle
+workspace-export = 📤 Çalışma alanını dışa aktar
+workspace-import = 📥 Çalışma alanını içe aktar
+workspace-file-label = Çalışma alanı düzeni
+workspace-exported = Dışa aktarıldı: { $target }
+workspace-imported = "{ $name }" içe aktarıldı: { $source }
+workspace-windows = Pencereler
+workspace-docked = 🗔 Yerleşik düzen
+workspace-docked-hint = Görünümleri yüzen pencereler yerine bölünebilir panellerde sekme olarak göster
+workspace-organize-windows = Pencereleri düzenle
+workspace-close-all-windows = Tüm pencereleri kapat
+window-close = Pencereyi kapat
+window-rename = Pencereyi yeniden adlandır
+window-float = Pencereyi yüzdür
+window-dock = Pencereyi yerleştir
+dock-empty = Buraya yerleştirmek için menüden bir görünüm açın
+dock-float = ⧉ Yüzdür
+dock-close = ✖ Kapat
+
+## Görünüm menüsü; anahtarlar görünüm türünden (`view-<kind>`) ve kategoriden türetilir.
+
+category-data = Veri
+category-dashboards = Panolar
+category-other = Diğer
+view-sqlite_data = Sqlite Veritabanına Bağlan
+view-query_console = SQL Sorgu Konsolu
+view-schema_browser = Şema Tarayıcısı
+view-customer_orders = Müşteri Siparişleri
+view-chart = Grafik
+view-test_window = Test Penceresi
+
+## Ortak
+
+close = Kapat
+loading = Yükleniyor...
+refresh = 🔄 Yenile
+query = Sorgu
+query-run = ▶ Çalıştır
+query-running = Sorgu çalışıyor...
+rows-count = { $count } satır
+error-retry = 🔄 Yeniden dene
+error-sign-in = 🔑 Oturum aç
+error-copy-diagnostics = 📋 Tanılama bilgisini kopyala
+export-menu = 💾 Dışa aktar
+export-done = { $count } satır dışa aktarıldı: { $target }
+
+## Veri kaynağı seçici
+
+source = Kaynak:
+source-http = HTTP sunucusu
+source-local = Yerel dosya
+source-local-unavailable = Bu derleme `local-sqlite` özelliği olmadan derlendi.
+source-database-file = Veritabanı dosyası:
+
+## Hata paneli başlıkları
+
+error-connection-refused = Veri sunucusuna bağlanılamıyor
+error-timeout = Veri sunucusu zamanında yanıt vermedi
+error-http-status = Veri sunucusu bir hata döndürdü
+error-invalid-json = Yanıt geçerli bir JSON değil
+error-schema-mismatch = Yanıt beklenmeyen biçimde
+error-database = Veritabanı hatası
+error-request = İstek gönderilemedi
+error-unauthorized = Oturum açmanız gerekiyor
+
+## Sonuç tablosu
+
+grid-no-columns = Sütun yok
+grid-clear = ✖ Sıralama ve filtreleri temizle
+grid-matching-rows = { $total } satırdan { $count } tanesi filtrelere uyuyor
+grid-sort-hint = Sıralamak için tıklayın, sıralamaya eklemek için Shift+tıklayın
+grid-filter = Filtre
+grid-filter-hint =
+    metin: içerir
+    =metin: eşittir
+    10..20, >5, <=3: sayı aralığı
+
+## Satır düzenleme
+
+edits-keep-row = Bu satırı koru
+edits-delete-row = Kaydederken bu satırı sil
+edits-add-row = ➕ Satır ekle
+edits-add = Ekle
+edits-remove-new-row = Bu yeni satırı kaldır
+edits-new = yeni
+edits-unsaved = { $count } kaydedilmemiş değişiklik
+edits-save-all = 💾 Tümünü kaydet
+edits-discard = ↺ Vazgeç
+edits-saving = Kaydediliyor...
+edits-failed = ⚠ { $count } değişiklik kaydedilemedi; ayrıntılar için vurgulanan hücrelerin üzerine gelin.
+
+## Müşteri tablosu
+
+customers-name-required = Müşteri adı zorunludur
+customers-name-too-long = Müşteri adı en fazla { $max } karakter olabilir
+customers-address-too-long = Adres en fazla { $max } karakter olabilir
+customers-page-size = Sayfa boyutu:
+customers-page-count = / { $count }
+customers-rows-range = { $total } müşteriden { $first }–{ $last } arası satırlar
+customers-heading = Sunucudaki Müşteri Verileri
+customers-fetch = Müşteri Verilerini Getir
+customers-edit-rows = ✏ Satırları düzenle
+customers-edit-rows-hint = Hücreleri düzenleyin, satır ekleyin ve silinecek satırları işaretleyin; ardından hepsini birlikte kaydedin
+customers-fetching = Sunucudan veri getiriliyor...
+customers-raw-json = Ham JSON Verisi
+customers-raw-json-hint = { $source } adresinden veri yüklemek için '{ customers-fetch }' düğmesine tıklayın
+customers-table = Müşteri Veri Tablosu
+customers-export-name = Müşteriler
+customers-show-orders = Siparişleri göster
+customers-selected-id = Seçili müşteri kimliği: { $id }
+
+## Müşteri siparişleri
+
+orders-title = Müşteri Siparişleri: { $customer }
+orders-empty = Siparişlerini görmek için müşteri tablosunda bir müşteri adına tıklayın.
+orders-back = ⬅ Geri
+orders-count = { $count } sipariş
+orders-lines = { $count } kalem
+orders-items = { $count } ürün
+orders-total = Toplam { $total }
+orders-order-total = Sipariş toplamı { $total }
+orders-product-not-found = Ürün bulunamadı
+orders-show-lines = Sipariş kalemlerini göster
+orders-show-product = Ürünü göster
+orders-order-crumb = Sipariş { $id }
+
+## SQL konsolu ve şema tarayıcısı
+
+query-console-table-title = Tablo: { $table }
+query-executed-in = { $ms } ms'de çalıştı
+query-round-trip = gidiş-dönüş { $ms } ms
+schema-open-data = Veriyi aç
+schema-open-data-hint = Veriyi açmak için çift tıklayın
+schema-indexes = Dizinler
+schema-foreign-keys = Yabancı anahtarlar
+schema-filter-tables = Tabloları filtrele
+schema-loading = Şema yükleniyor...
+schema-tables = Tablolar
+schema-views = Görünümler
+
+## Grafikler
+
+chart-preset-title = Grafik: { $preset }
+chart-preset = Hazır grafik:
+chart-choose = Seçin...
+chart-preset-orders-per-month = Aylık sipariş sayısı
+chart-preset-revenue-per-country = Ülkelere göre gelir
+chart-preset-top-products = En çok satan 10 ürün
+chart-preset-revenue-share = Kategorilere göre gelir payı
+chart-preset-price-vs-quantity = Fiyat ve satılan miktar
+chart-type-bar = 📊 Çubuk
+chart-type-line = 📈 Çizgi
+chart-type-pie = ◔ Pasta
+chart-type-scatter = ⁘ Serpilme
+chart-aggregation-count = Sayı
+chart-aggregation-sum = Toplam
+chart-aggregation-average = Ortalama
+chart-aggregation-min = En küçük
+chart-aggregation-max = En büyük
+chart-series = { $column } ({ $aggregation })
+chart-x = X:
+chart-y = Y:
+chart-row-count = (satır sayısı)
+chart-order-category = A→Z
+chart-order-largest = Büyükten küçüğe
+chart-top = İlk:
+chart-top-hint = Yalnızca ilk N kategoriyi göster (0 = tümü)
+chart-no-rows = Çizilecek satır yok
+chart-no-positive-values = Gösterilecek pozitif değer yok
+chart-choose-x = X ekseni için bir sütun seçin
+chart-missing-column = '{ $column }' sütunu sonuçta yok
+chart-scatter-needs-y = Serpilme grafiği sayısal bir Y sütunu gerektirir
+chart-legend-series = { $column } başına { $series }
+chart-legend-points = { $y } - { $x } ({ $count } nokta)
+chart-share-of-total = { $total } içinde %{ $share }
+
+## Bilgi ve test pencereleri
+
+info-heading = Bilgi
+info-text = Bu bir tanıtım uygulamasıdır. Egui çatısını temel alır ve Egui'nin nasıl kullanılacağını gösteren basit bir örnek olması amaçlanmıştır. Ziyaret edin:{" "}
+info-link = emarti GitHUB Depoları
+test-window-label = Bu, özel bir etiketi olan bir test penceresidir.
+
+## Komut paleti ve klavye kısayolları
+
+menu-keyboard-shortcuts = ⌨ Klavye kısayolları…
+palette-hint = Bir komut ya da çalışma alanı adı yazın…
+palette-no-match = Eşleşen komut yok
+command-palette = 🔍 Komut paleti
+command-select-workspace = Çalışma alanına geç: { $name }
+command-next-workspace = Sonraki çalışma alanı
+command-previous-workspace = Önceki çalışma alanı
+command-add-workspace = ➕ Çalışma alanı ekle
+command-delete-workspace = 🗑 Çalışma alanını sil
+command-restore-workspace = ↺ Son silinen çalışma alanını geri al
+command-open-view = Aç: { $view }
+command-organize-windows = Pencereleri düzenle
+command-close-all-windows = Tüm pencereleri kapat
+command-refresh-data = 🔄 Verileri yenile
+command-toggle-theme = Açık/koyu tema arasında geçiş yap
+command-theme-editor = 🎨 Tema düzenleyicisi
+command-keyboard-shortcuts = ⌨ Klavye kısayolları
+shortcuts-title = Klavye kısayolları
+shortcuts-set = Ata
+shortcuts-clear = Kısayolu kaldır
+shortcuts-press-keys = Ctrl/Cmd ya da Alt ile bir tuşa veya bir F tuşuna basın… (Esc iptal eder)
+shortcuts-reset = Varsayılanlara dön
+
+## Kendiliğinden yenileme
+
+refresh-auto = 🔄 Otomatik yenile
+refresh-off = Kapalı
+refresh-custom = Özel
+refresh-seconds = { $count } sn
+refresh-minutes = { $count } dk
+refresh-seconds-suffix = { " " }sn
+refresh-paused = ⏸ Düzenleme sürerken duraklatıldı
+refresh-updated-seconds = { $count } saniye önce güncellendi
+refresh-updated-minutes = { $count } dakika önce güncellendi
+refresh-updated-hours = { $count } saat önce güncellendi
+
+## Canlı güncellemeler
+
+feed-connecting = ◌ Bağlanıyor…
+feed-connecting-hint = { $url } adresindeki değişiklik akışına bağlanılıyor
+feed-live = ● Canlı
+feed-live-hint = Değişiklikler { $url } adresinden alınıyor
+feed-reconnecting = ● Yeniden bağlanıyor ({ $attempt }. deneme)
+feed-reconnecting-hint = { $url } adresindeki değişiklik akışına ulaşılamıyor: { $message }
+    Sonraki deneme { $seconds } sn sonra.
+feed-closed = Değişiklik akışı bağlantısı kapandı
+feed-timed-out = Sunucu { $seconds } sn boyunca hiçbir şey göndermediği için bağlantı bırakıldı
+feed-unauthorized = Sunucu oturum açılmasını istiyor
+
+## Oturum açma
+
+login-title = 🔑 Oturum aç
+login-server = Veri sunucusu: { $url }
+login-required = Veri sunucusu oturum açmanızı istiyor. Bekleyen istekler oturum açınca devam eder.
+login-rejected = Sunucu kayıtlı kimlik bilgilerini reddetti. Devam etmek için yeniden oturum açın.
+login-method-password = Kullanıcı adı ve parola
+login-method-token = API belirteci
+login-username = Kullanıcı adı
+login-password = Parola
+login-token = Belirteç
+login-token-hint = Bearer belirteci olarak gönderilir
+login-remember = Bu cihazda hatırla
+login-remember-hint = Belirteci uygulama durumuyla birlikte bu bilgisayardaki bir dosyaya şifrelenmeden kaydeder; o dosyayı okuyabilen herkes belirteci kullanabilir. Aksi hâlde uygulama kapanınca unutulur.
+login-remember-hint-web = Belirteci bu tarayıcının yerel depolamasına (localStorage) şifrelenmeden kaydeder; bu sitede çalışan her betik onu okuyabilir. Aksi hâlde sayfa kapanınca unutulur.
+login-invalid = Kullanıcı adı ya da parola yanlış.
+login-sign-in = Oturum aç
+login-cancel = Vazgeç
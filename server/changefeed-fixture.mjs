@@ -0,0 +1,180 @@
+// Canlı güncellemeleri denemek için küçük bir test sunucusu; Northwind veritabanı gerektirmez.
+// Bellekteki bir müşteri tablosunu /customers'tan sunar ve /changes akışına düzenli aralıklarla
+// rastgele ekleme, güncelleme ve silme olayları gönderir. Yeniden bağlanmayı sınamak için
+// bağlantıları belirli aralıklarla koparır ve ardından kısa bir süre 503 ile yanıt verir.
+//
+//   node changefeed-fixture.mjs      (ya da: bun run changefeed-fixture.mjs)
+//   cargo run -- --server-url http://localhost:3100
+//
+// Ortam değişkenleri: PORT (3100), EVENT_MS (2000), DROP_MS (20000, 0 ise koparılmaz), DOWN_MS (5000).
+// AUTH_USERS ("demo:demo") ya da API_TOKENS verilirse index.ts'teki gibi oturum açmak gerekir
+// (POST /login, POST /logout, Authorization: Bearer ..., /changes için ?access_token=...).
+// Oturumların süresi dolmaz.
+// Sayfalama (limit/offset) desteklenir; sıralama ve filtre parametreleri yok sayılır.
+
+import { randomUUID } from "node:crypto";
+import { createServer } from "node:http";
+
+const port = Number(process.env.PORT ?? 3100);
+const eventMs = Number(process.env.EVENT_MS ?? 2000);
+const dropMs = Number(process.env.DROP_MS ?? 20000);
+const downMs = Number(process.env.DOWN_MS ?? 5000);
+const readList = (value) => (value ?? "").split(",").map((entry) => entry.trim()).filter((entry) => entry !== "");
+const authUsers = new Map(readList(process.env.AUTH_USERS).map((entry) => [entry.slice(0, entry.indexOf(":")), entry.slice(entry.indexOf(":") + 1)]));
+const apiTokens = new Set(readList(process.env.API_TOKENS));
+const authEnabled = authUsers.size > 0 || apiTokens.size > 0;
+const sessions = new Set();
+
+const columns = ["CustomerName", "Address", "CustomerID"];
+const customers = Array.from({ length: 30 }, (_, i) => ({
+  CustomerName: `Fixture Customer ${i + 1}`,
+  Address: `${i + 1} Test Street`,
+  CustomerID: i + 1,
+}));
+let nextId = customers.length + 1;
+let revision = 0;
+
+const corsHeaders = {
+  "Access-Control-Allow-Origin": "*",
+  "Access-Control-Allow-Methods": "GET, POST, OPTIONS",
+  "Access-Control-Allow-Headers": "Content-Type, Authorization",
+};
+
+const subscribers = new Set();
+// Bu zamana kadar /changes istekleri 503 ile reddedilir.
+let downUntil = 0;
+
+function broadcast(message) {
+  for (const response of subscribers) {
+    response.write(message);
+  }
+}
+
+function publish(op, key, row = {}) {
+  console.log(`${op} ${key}`);
+  broadcast(`data: ${JSON.stringify({ table: "Customers", op, key, row })}\n\n`);
+}
+
+function randomCustomer() {
+  return customers[Math.floor(Math.random() * customers.length)];
+}
+
+// Tablo 20 ile 40 satır arasında kalacak şekilde rastgele bir değişiklik yapar.
+function randomChange() {
+  revision += 1;
+  const roll = Math.random();
+  if ((roll < 0.2 && customers.length < 40) || customers.length < 20) {
+    const customer = { CustomerName: `New Customer ${nextId}`, Address: `${revision} Fixture Road`, CustomerID: nextId++ };
+    customers.push(customer);
+    publish("insert", customer.CustomerID, customer);
+  } else if (roll < 0.4) {
+    const customer = randomCustomer();
+    customers.splice(customers.indexOf(customer), 1);
+    publish("delete", customer.CustomerID);
+  } else {
+    const customer = randomCustomer();
+    customer.Address = `${revision} Updated Avenue`;
+    publish("update", customer.CustomerID, customer);
+  }
+}
+
+function sendJson(response, body, status = 200) {
+  response.writeHead(status, { "Content-Type": "application/json", ...corsHeaders });
+  response.end(JSON.stringify(body));
+}
+
+function requestToken(request, url) {
+  const header = request.headers.authorization ?? "";
+  return header.startsWith("Bearer ") ? header.slice(7).trim() : (url.searchParams.get("access_token") ?? "");
+}
+
+function authorized(request, url) {
+  const token = requestToken(request, url);
+  return !authEnabled || sessions.has(token) || apiTokens.has(token);
+}
+
+function handleLogin(request, response) {
+  let text = "";
+  request.on("data", (chunk) => (text += chunk));
+  request.on("end", () => {
+    let body = {};
+    try {
+      body = JSON.parse(text);
+    } catch {}
+    if (!body.username || authUsers.get(body.username) !== body.password) {
+      console.log(`rejected login for ${body.username}`);
+      return sendJson(response, { error: "Invalid username or password" }, 401);
+    }
+    const token = randomUUID();
+    sessions.add(token);
+    console.log(`${body.username} signed in`);
+    sendJson(response, { token });
+  });
+}
+
+function handleCustomers(url, response) {
+  const limit = url.searchParams.get("limit");
+  if (limit === null) {
+    return sendJson(response, customers);
+  }
+  const offset = Number(url.searchParams.get("offset") ?? 0);
+  const rows = customers.slice(offset, offset + Number(limit)).map((customer) => columns.map((column) => customer[column]));
+  sendJson(response, { columns, rows, total: customers.length });
+}
+
+function handleChanges(request, response) {
+  if (Date.now() < downUntil) {
+    console.log("rejecting /changes (down)");
+    return sendJson(response, { error: "Change feed is down", details: "Fixture outage" }, 503);
+  }
+  response.writeHead(200, { "Content-Type": "text/event-stream", "Cache-Control": "no-cache", ...corsHeaders });
+  response.write(": connected\n\n");
+  subscribers.add(response);
+  console.log(`subscriber connected (${subscribers.size})`);
+  request.on("close", () => subscribers.delete(response));
+}
+
+createServer((request, response) => {
+  const url = new URL(request.url, `http://localhost:${port}`);
+  if (request.method === "OPTIONS") {
+    response.writeHead(204, corsHeaders);
+    return response.end();
+  }
+  if (url.pathname === "/login" && request.method === "POST") {
+    return handleLogin(request, response);
+  }
+  if (url.pathname === "/logout" && request.method === "POST") {
+    if (sessions.delete(requestToken(request, url))) {
+      console.log("signed out");
+    }
+    response.writeHead(204, corsHeaders);
+    return response.end();
+  }
+  if (!authorized(request, url)) {
+    console.log(`401 ${url.pathname}`);
+    return sendJson(response, { error: "Authentication required" }, 401);
+  }
+  if (url.pathname === "/customers") {
+    return handleCustomers(url, response);
+  }
+  if (url.pathname === "/changes") {
+    return handleChanges(request, response);
+  }
+  response.writeHead(404, corsHeaders);
+  response.end("Not Found");
+}).listen(port, () => {
+  console.log(`Change feed fixture on http://localhost:${port} (customers: /customers, feed: /changes)`);
+});
+
+setInterval(randomChange, eventMs);
+setInterval(() => broadcast(": ping\n\n"), 5000);
+if (dropMs > 0) {
+  setInterval(() => {
+    console.log(`dropping ${subscribers.size} subscriber(s)`);
+    for (const response of subscribers) {
+      response.destroy();
+    }
+    subscribers.clear();
+    downUntil = Date.now() + downMs;
+  }, dropMs);
+}
@@ -20,13 +20,542 @@ if (!existsSync(dbFilePath)) {
 
 const db = new Database(dbFilePath);
 
-console.log("Bun server running on http://localhost:3000");
-console.log("Access customers at http://localhost:3000/customers");
+// Port PORT ortam değişkeniyle değiştirilebilir (ör. staging için).
+const port = Number(process.env.PORT ?? 3000);
+
+// WASM paketi (index.html ve dist/) bu sunucu tarafından da sunulabilir.
+// Bu durumda uygulama sunucu adresini boş bırakarak aynı kökene istek gönderir.
+const webRootPath = process.env.WEB_ROOT ?? "..";
+
+console.log(`Bun server running on http://localhost:${port}`);
+console.log(`Access customers at http://localhost:${port}/customers`);
+console.log(`Change feed at http://localhost:${port}/changes`);
+
+function isStaticPath(pathname: string): boolean {
+  return pathname === "/" || pathname === "/index.html" || pathname.startsWith("/dist/");
+}
+
+async function serveStatic(pathname: string): Promise<Response | null> {
+  if (!isStaticPath(pathname)) {
+    return null;
+  }
+  const relativePath = pathname === "/" ? "/index.html" : pathname;
+  if (relativePath.includes("..")) {
+    return null;
+  }
+  const file = Bun.file(`${webRootPath}${relativePath}`);
+  if (!(await file.exists())) {
+    return null;
+  }
+  return new Response(file);
+}
+
+// Tüm yanıtlara eklenen CORS başlıkları. POST gövdesi JSON olduğunda tarayıcı
+// önce bir OPTIONS (preflight) isteği gönderir; bu yüzden yöntem ve başlıklara da izin verilir.
+const corsHeaders = {
+  "Access-Control-Allow-Origin": "*",
+  "Access-Control-Allow-Methods": "GET, POST, PUT, DELETE, OPTIONS",
+  "Access-Control-Allow-Headers": "Content-Type, Authorization",
+};
+
+// Kimlik doğrulama isteğe bağlıdır. AUTH_USERS ("kullanıcı:parola,kullanıcı2:parola2") ya da
+// API_TOKENS ("belirteç1,belirteç2") verilirse statik dosyalar, /login ve /logout dışındaki tüm istekler
+// `Authorization: Bearer <belirteç>` ister. EventSource başlık gönderemediği için /changes
+// belirteci `?access_token=` parametresinden de kabul eder. /login ile alınan belirteçler
+// SESSION_HOURS (varsayılan 12) saat sonra ya da POST /logout ile geçersiz olur.
+function readList(value: string | undefined): string[] {
+  return (value ?? "")
+    .split(",")
+    .map((entry) => entry.trim())
+    .filter((entry) => entry !== "");
+}
+
+const authUsers = new Map(
+  readList(process.env.AUTH_USERS).map((entry) => {
+    const separator = entry.indexOf(":");
+    return [entry.slice(0, separator), entry.slice(separator + 1)] as const;
+  }),
+);
+const apiTokens = new Set(readList(process.env.API_TOKENS));
+const authEnabled = authUsers.size > 0 || apiTokens.size > 0;
+// POST /login ile verilen belirteçler -> kullanıcı adı ve son geçerlilik anı (ms);
+// sunucu yeniden başlayınca da geçersiz olur.
+const sessionMs = Number(process.env.SESSION_HOURS ?? 12) * 60 * 60 * 1000;
+const sessions = new Map<string, { username: string; expiresAt: number }>();
+
+// Süresi dolmuş oturumu siler; geçerliyse true döner.
+function sessionValid(token: string): boolean {
+  const session = sessions.get(token);
+  if (session && session.expiresAt <= Date.now()) {
+    sessions.delete(token);
+    return false;
+  }
+  return session !== undefined;
+}
+
+if (authEnabled) {
+  console.log(`Authentication required (${authUsers.size} user(s), ${apiTokens.size} API token(s)); sign in at POST /login`);
+}
+
+function jsonResponse(body: unknown, status = 200): Response {
+  return new Response(JSON.stringify(body), {
+    status,
+    headers: { "Content-Type": "application/json", ...corsHeaders },
+  });
+}
+
+function errorResponse(message: string, error: unknown, status = 500): Response {
+  return jsonResponse(
+    {
+      error: message,
+      details: error instanceof Error ? error.message : String(error),
+    },
+    status,
+  );
+}
+
+// Sorgu konsolu için ayrı, salt okunur bir bağlantı: gönderilen SQL veriyi değiştiremez.
+const readonlyDb = new Database(dbFilePath, { readonly: true });
+
+// İstemcinin oturum açma penceresini açmasını sağlayan 401 yanıtı.
+function unauthorized(message: string): Response {
+  return new Response(JSON.stringify({ error: message }), {
+    status: 401,
+    headers: { "Content-Type": "application/json", "WWW-Authenticate": "Bearer", ...corsHeaders },
+  });
+}
+
+function requestToken(req: Request, url: URL): string {
+  const header = req.headers.get("Authorization") ?? "";
+  return header.startsWith("Bearer ")
+    ? header.slice("Bearer ".length).trim()
+    : url.pathname === "/changes"
+      ? (url.searchParams.get("access_token") ?? "")
+      : "";
+}
+
+// İstek yetkiliyse null, değilse 401 yanıtı döndürür.
+function checkAuth(req: Request, url: URL): Response | null {
+  if (!authEnabled) {
+    return null;
+  }
+  const token = requestToken(req, url);
+  if (token !== "" && (sessionValid(token) || apiTokens.has(token))) {
+    return null;
+  }
+  return unauthorized(token === "" ? "Authentication required" : "Invalid or expired token");
+}
+
+// POST {"username": "...", "password": "..."} -> {"token": "..."}
+async function handleLogin(req: Request): Promise<Response> {
+  if (!authEnabled) {
+    return jsonResponse({ error: "Authentication is not enabled on this server" }, 404);
+  }
+  let body: { username?: unknown; password?: unknown };
+  try {
+    body = await req.json();
+  } catch (error) {
+    return errorResponse("Invalid JSON body", error, 400);
+  }
+  const username = typeof body.username === "string" ? body.username : "";
+  const password = typeof body.password === "string" ? body.password : "";
+  if (username === "" || authUsers.get(username) !== password) {
+    return unauthorized("Invalid username or password");
+  }
+  // Kapatılmadan bırakılan oturumlar birikmesin.
+  for (const [token, session] of sessions) {
+    if (session.expiresAt <= Date.now()) {
+      sessions.delete(token);
+    }
+  }
+  const token = crypto.randomUUID();
+  sessions.set(token, { username, expiresAt: Date.now() + sessionMs });
+  console.log(`${username} signed in`);
+  return jsonResponse({ token });
+}
+
+// POST /logout: isteğin belirtecini geçersiz kılar. Belirteç zaten geçersizse de başarılı sayılır.
+function handleLogout(req: Request, url: URL): Response {
+  const token = requestToken(req, url);
+  const session = sessions.get(token);
+  if (session) {
+    sessions.delete(token);
+    console.log(`${session.username} signed out`);
+  }
+  return new Response(null, { status: 204, headers: corsHeaders });
+}
+
+// Blob değerleri JSON'da bayt dizisi olarak gönderilir.
+function toJsonValue(value: unknown): unknown {
+  return value instanceof Uint8Array ? Array.from(value) : value;
+}
+
+// POST /query  {"sql": "SELECT ..."}
+//   -> {"columns": ["..."], "rows": [[...], ...], "elapsedMs": 1.23}
+async function handleQuery(req: Request): Promise<Response> {
+  let sql: unknown;
+  try {
+    ({ sql } = await req.json());
+  } catch (error) {
+    return errorResponse("Request body must be JSON", error, 400);
+  }
+  if (typeof sql !== "string" || sql.trim() === "") {
+    return errorResponse("Missing query", "Expected a JSON body like {\"sql\": \"SELECT ...\"}", 400);
+  }
+  try {
+    const started = performance.now();
+    const statement = readonlyDb.prepare(sql);
+    const rows = statement.values().map((row) => row.map(toJsonValue));
+    const elapsedMs = performance.now() - started;
+    return jsonResponse({ columns: statement.columnNames, rows, elapsedMs });
+  } catch (error) {
+    // Hatalı SQL kullanıcı hatasıdır; sunucu hatası olarak raporlanmaz.
+    return errorResponse("Query failed", error, 400);
+  }
+}
+
+// GET /schema -> {"objects": [{name, kind, columns, indexes, foreignKeys}, ...]}
+// Rust tarafındaki datasource::schema::Schema yapısıyla aynı biçimdedir.
+function readSchema() {
+  const objects = readonlyDb
+    .query(
+      `SELECT name, type FROM sqlite_master
+       WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%'
+       ORDER BY type, name`,
+    )
+    .all() as { name: string; type: string }[];
+
+  return {
+    objects: objects.map(({ name, type }) => {
+      const columns = readonlyDb
+        .query(`SELECT name, type, "notnull", dflt_value, pk FROM pragma_table_info(?1) ORDER BY cid`)
+        .all(name) as { name: string; type: string | null; notnull: number; dflt_value: string | null; pk: number }[];
+      const indexes = (
+        readonlyDb
+          .query(`SELECT name, "unique" FROM pragma_index_list(?1) ORDER BY name`)
+          .all(name) as { name: string; unique: number }[]
+      ).map((index) => ({
+        name: index.name,
+        unique: index.unique === 1,
+        columns: (
+          readonlyDb
+            .query(`SELECT name FROM pragma_index_info(?1) ORDER BY seqno`)
+            .all(index.name) as { name: string | null }[]
+        ).map((column) => column.name ?? "<expression>"),
+      }));
+
+      // Çok sütunlu yabancı anahtarlar aynı id ile birden fazla satır döndürür.
+      const foreignKeys = new Map<number, { columns: string[]; referencedTable: string; referencedColumns: string[] }>();
+      const keyRows = readonlyDb
+        .query(`SELECT id, "table", "from", "to" FROM pragma_foreign_key_list(?1) ORDER BY id, seq`)
+        .all(name) as { id: number; table: string; from: string; to: string | null }[];
+      for (const row of keyRows) {
+        const key = foreignKeys.get(row.id) ?? { columns: [], referencedTable: row.table, referencedColumns: [] };
+        key.columns.push(row.from);
+        if (row.to !== null) {
+          key.referencedColumns.push(row.to);
+        }
+        foreignKeys.set(row.id, key);
+      }
+
+      return {
+        name,
+        kind: type === "view" ? "view" : "table",
+        columns: columns.map((column) => ({
+          name: column.name,
+          declaredType: column.type ?? "",
+          notNull: column.notnull === 1,
+          primaryKey: column.pk,
+          defaultValue: column.dflt_value,
+        })),
+        indexes,
+        foreignKeys: [...foreignKeys.values()],
+      };
+    }),
+  };
+}
+
+// Tek bir sayfada döndürülebilecek en fazla satır.
+const maxPageSize = 100_000;
+
+// limit/offset sorgu parametrelerini okur; limit yoksa sayfalama yapılmaz.
+function readPaging(url: URL): { limit: number; offset: number } | null {
+  const limitParam = url.searchParams.get("limit");
+  if (limitParam === null) {
+    return null;
+  }
+  const limit = Math.min(Math.max(Number.parseInt(limitParam, 10) || 0, 1), maxPageSize);
+  const offset = Math.max(Number.parseInt(url.searchParams.get("offset") ?? "0", 10) || 0, 0);
+  return { limit, offset };
+}
+
+type SqlParam = string | number;
+
+// Sütun filtresi sözdizimi (Rust tarafındaki tablequery::Filter ile aynı):
+//   "abc" içerir, "=abc" eşit, "10..20" / "10.." / "..20" aralık, ">5" ">=5" "<5" "<=5" karşılaştırma
+// Sayılar onluk yazılır ("-1.5", "2e3"; "0x10" ya da "Infinity" sayı değildir) ve aralıkta tam
+// olarak bir ".." bulunur; bunlara uymayan ifadeler "içerir" sayılır.
+function parseFilter(column: string, text: string): { sql: string; params: SqlParam[] } | null {
+  const expr = text.trim();
+  if (expr === "") {
+    return null;
+  }
+  if (expr.startsWith("=")) {
+    return { sql: `CAST(${column} AS TEXT) = ?`, params: [expr.slice(1).trim()] };
+  }
+  const isNumber = (value: string) => /^[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?$/.test(value.trim());
+  const range = expr.split("..");
+  if (range.length === 2 && (isNumber(range[0]) || isNumber(range[1])) &&
+      (range[0].trim() === "" || isNumber(range[0])) && (range[1].trim() === "" || isNumber(range[1]))) {
+    const conditions: string[] = [];
+    const params: SqlParam[] = [];
+    if (isNumber(range[0])) {
+      conditions.push(`${column} >= ?`);
+      params.push(Number(range[0]));
+    }
+    if (isNumber(range[1])) {
+      conditions.push(`${column} <= ?`);
+      params.push(Number(range[1]));
+    }
+    return { sql: conditions.join(" AND "), params };
+  }
+  for (const op of [">=", "<=", ">", "<"]) {
+    if (expr.startsWith(op) && isNumber(expr.slice(op.length))) {
+      return { sql: `${column} ${op} ?`, params: [Number(expr.slice(op.length))] };
+    }
+  }
+  const escaped = expr.replace(/[\\%_]/g, (c) => `\\${c}`);
+  return { sql: `CAST(${column} AS TEXT) LIKE '%' || ? || '%' ESCAPE '\\'`, params: [escaped] };
+}
+
+// sort=Ad:desc,Id:asc ve filter.<Sütun>=<ifade> parametrelerinden WHERE ve ORDER BY üretir.
+// Yalnızca sorgunun kendi sütunlarına izin verilir; değerler her zaman parametre olarak bağlanır.
+// ORDER BY her zaman `keyColumn` ile biter; eşit değerli satırlar sayfalar arasında yer değiştirmez
+// (uygulamanın yerel dosya sayfalaması da aynı kuralı kullanır, bkz. tablequery.rs).
+function readTableQuery(url: URL, columns: string[], keyColumn: string): { where: string; orderBy: string; params: SqlParam[] } {
+  const quote = (name: string) => `"${name.replaceAll('"', '""')}"`;
+  const conditions: string[] = [];
+  const params: SqlParam[] = [];
+  for (const [name, value] of url.searchParams) {
+    if (!name.startsWith("filter.")) {
+      continue;
+    }
+    const column = name.slice("filter.".length);
+    if (!columns.includes(column)) {
+      throw new Error(`Unknown filter column: ${column}`);
+    }
+    const filter = parseFilter(quote(column), value);
+    if (filter) {
+      conditions.push(filter.sql);
+      params.push(...filter.params);
+    }
+  }
+  const sorted: string[] = [];
+  const order = (url.searchParams.get("sort") ?? "")
+    .split(",")
+    .filter((key) => key.trim() !== "")
+    .map((key) => {
+      const [column, direction = "asc"] = key.split(":");
+      if (!columns.includes(column)) {
+        throw new Error(`Unknown sort column: ${column}`);
+      }
+      sorted.push(column);
+      return `${quote(column)} ${direction.toLowerCase() === "desc" ? "DESC" : "ASC"}`;
+    });
+  if (!sorted.includes(keyColumn)) {
+    order.push(`${quote(keyColumn)} ASC`);
+  }
+  return {
+    where: conditions.length > 0 ? ` WHERE ${conditions.join(" AND ")}` : "",
+    orderBy: ` ORDER BY ${order.join(", ")}`,
+    params,
+  };
+}
+
+// Müşteri satırlarına yazma: POST /customers, PUT /customers/:id, DELETE /customers/:id.
+// Düzenlenebilir sütunlar ve sınırlar Rust tarafındaki views/sqlitedata.rs ile aynıdır.
+const customerFieldLimits: Record<string, { required: boolean; maxLength: number }> = {
+  CustomerName: { required: true, maxLength: 100 },
+  Address: { required: false, maxLength: 200 },
+};
+
+// Hatalı alanlar {"error", "details", "fields": {"Sütun": "ileti"}} ile bildirilir;
+// uygulama bu iletileri ilgili hücrelerde gösterir.
+function validateCustomer(body: unknown, partial: boolean): { values: Record<string, string> } | Response {
+  if (typeof body !== "object" || body === null || Array.isArray(body)) {
+    return errorResponse("Request body must be a JSON object", "Expected {\"CustomerName\": \"...\", \"Address\": \"...\"}", 400);
+  }
+  const fields: Record<string, string> = {};
+  const values: Record<string, string> = {};
+  for (const [column, value] of Object.entries(body)) {
+    if (!(column in customerFieldLimits)) {
+      fields[column] = `Unknown or read-only column: ${column}`;
+    } else if (typeof value !== "string") {
+      fields[column] = "Value must be a string";
+    } else {
+      values[column] = value;
+    }
+  }
+  for (const [column, limits] of Object.entries(customerFieldLimits)) {
+    const value = values[column];
+    if (value === undefined) {
+      if (limits.required && !partial) {
+        fields[column] = `${column} is required`;
+      }
+    } else if (limits.required && value.trim() === "") {
+      fields[column] = `${column} is required`;
+    } else if (value.length > limits.maxLength) {
+      fields[column] = `${column} must be at most ${limits.maxLength} characters`;
+    }
+  }
+  if (Object.keys(fields).length > 0) {
+    return jsonResponse(
+      { error: "Validation failed", details: Object.values(fields).join("; "), fields },
+      400,
+    );
+  }
+  return { values };
+}
+
+// Değişiklik akışı (GET /changes): her müşteri yazması bağlı istemcilere Server-Sent Events
+// olarak `data: {"table", "op", "key", "row"}` biçiminde gönderilir (bkz. src/datasource/changefeed.rs).
+const encoder = new TextEncoder();
+const subscribers = new Set<ReadableStreamDefaultController<Uint8Array>>();
+
+function broadcast(message: string) {
+  const bytes = encoder.encode(message);
+  for (const controller of subscribers) {
+    try {
+      controller.enqueue(bytes);
+    } catch {
+      subscribers.delete(controller);
+    }
+  }
+}
+
+function publishChange(op: "insert" | "update" | "delete", key: number, row: unknown = {}) {
+  broadcast(`data: ${JSON.stringify({ table: "Customers", op, key, row })}\n\n`);
+}
+
+// Bun boşta kalan bağlantıları 10 saniyede kapattığı için akışa düzenli olarak yorum satırı yazılır.
+setInterval(() => broadcast(": ping\n\n"), 5_000);
+
+function handleChanges(req: Request): Response {
+  let subscriber: ReadableStreamDefaultController<Uint8Array>;
+  const stream = new ReadableStream<Uint8Array>({
+    start(controller) {
+      subscriber = controller;
+      subscribers.add(controller);
+      // Yanıt başlıkları hemen gönderilsin; istemci bağlantıyı kurulmuş sayar.
+      controller.enqueue(encoder.encode(": connected\n\n"));
+    },
+    cancel() {
+      subscribers.delete(subscriber);
+    },
+  });
+  req.signal.addEventListener("abort", () => subscribers.delete(subscriber));
+  return new Response(stream, {
+    headers: { "Content-Type": "text/event-stream", "Cache-Control": "no-cache", ...corsHeaders },
+  });
+}
+
+function readCustomer(id: number) {
+  return db.query("SELECT * FROM [Customers] WHERE [CustomerID] = ?").get(id);
+}
+
+async function handleCustomerWrite(req: Request, id: number | null): Promise<Response> {
+  try {
+    if (req.method === "DELETE" && id !== null) {
+      const { changes } = db.query("DELETE FROM [Customers] WHERE [CustomerID] = ?").run(id);
+      if (changes === 0) {
+        return errorResponse("Customer not found", `No customer with CustomerID ${id}`, 404);
+      }
+      publishChange("delete", id);
+      return jsonResponse({ deleted: id });
+    }
+
+    let body: unknown;
+    try {
+      body = await req.json();
+    } catch (error) {
+      return errorResponse("Request body must be JSON", error, 400);
+    }
+    const validated = validateCustomer(body, req.method === "PUT");
+    if (validated instanceof Response) {
+      return validated;
+    }
+    const columns = Object.keys(validated.values);
+    const params = Object.values(validated.values);
+
+    if (req.method === "POST" && id === null) {
+      const { lastInsertRowid } = db
+        .query(`INSERT INTO [Customers] (${columns.map((c) => `[${c}]`).join(", ")}) VALUES (${columns.map(() => "?").join(", ")})`)
+        .run(...params);
+      const inserted = readCustomer(Number(lastInsertRowid));
+      publishChange("insert", Number(lastInsertRowid), inserted);
+      return jsonResponse(inserted, 201);
+    }
+    if (req.method === "PUT" && id !== null) {
+      if (columns.length === 0) {
+        return errorResponse("Nothing to update", "The request body has no columns", 400);
+      }
+      const { changes } = db
+        .query(`UPDATE [Customers] SET ${columns.map((c) => `[${c}] = ?`).join(", ")} WHERE [CustomerID] = ?`)
+        .run(...params, id);
+      if (changes === 0) {
+        return errorResponse("Customer not found", `No customer with CustomerID ${id}`, 404);
+      }
+      const updated = readCustomer(id);
+      publishChange("update", id, updated);
+      return jsonResponse(updated);
+    }
+    return errorResponse("Method not allowed", `${req.method} ${new URL(req.url).pathname}`, 405);
+  } catch (error) {
+    console.error("Error writing customer:", error);
+    return errorResponse("Failed to save customer", error);
+  }
+}
 
 Bun.serve({
-  port: 3000,
-  fetch(req) {
+  port,
+  async fetch(req) {
     const url = new URL(req.url);
+    if (req.method === "OPTIONS") {
+      return new Response(null, { status: 204, headers: corsHeaders });
+    }
+    if (url.pathname === "/login" && req.method === "POST") {
+      return handleLogin(req);
+    }
+    if (url.pathname === "/logout" && req.method === "POST") {
+      return handleLogout(req, url);
+    }
+    if (!isStaticPath(url.pathname)) {
+      const denied = checkAuth(req, url);
+      if (denied) {
+        return denied;
+      }
+    }
+    if (url.pathname === "/query" && req.method === "POST") {
+      return handleQuery(req);
+    }
+    if (url.pathname === "/changes") {
+      return handleChanges(req);
+    }
+    if (url.pathname === "/schema") {
+      try {
+        return jsonResponse(readSchema());
+      } catch (error) {
+        console.error("Error reading schema:", error);
+        return errorResponse("Failed to read schema", error);
+      }
+    }
+    if (url.pathname === "/customers" && req.method === "POST") {
+      return handleCustomerWrite(req, null);
+    }
+    const customerPath = url.pathname.match(/^\/customers\/(\d+)$/);
+    if (customerPath) {
+      return handleCustomerWrite(req, Number(customerPath[1]));
+    }
     if (url.pathname === "/customers") {
       try {
         const query = `
@@ -34,35 +563,43 @@ Bun.serve({
             [Customers].[CustomerName], 
             [Customers].[Address], 
             [Customers].[CustomerID]
-          FROM   [Customers] WHERE [Customers].[CustomerID]<8;
+          FROM   [Customers] ORDER BY [Customers].[CustomerID]
         `;
+        // ?limit=..&offset=.. verilirse yalnızca o sayfa ve toplam satır sayısı döner:
+        //   {"columns": [...], "rows": [[...], ...], "total": N}
+        // Parametresiz istekler eskisi gibi nesne dizisi alır.
+        // Sıralama (sort) ve sütun filtreleri (filter.<Sütun>) sayfalamadan önce uygulanır.
+        const paging = readPaging(url);
+        if (paging) {
+          const columns = db.query(query).columnNames;
+          let tableQuery;
+          try {
+            tableQuery = readTableQuery(url, columns, "CustomerID");
+          } catch (error) {
+            return errorResponse("Invalid sort or filter", error, 400);
+          }
+          const filtered = `SELECT * FROM (${query})${tableQuery.where}`;
+          const statement = db.query(`${filtered}${tableQuery.orderBy} LIMIT ? OFFSET ?`);
+          const rows = statement.values(...tableQuery.params, paging.limit, paging.offset);
+          const { total } = db
+            .query(`SELECT COUNT(*) AS total FROM (${filtered})`)
+            .get(...tableQuery.params) as { total: number };
+          return jsonResponse({ columns, rows, total });
+        }
         const customers = db.query(query).all();
-        return new Response(JSON.stringify(customers), {
-          headers: { 
-            "Content-Type": "application/json",
-            "Access-Control-Allow-Origin": "*", // Bu başlık headers nesnesinin içinde olmalı
-        },
-        });
+        return jsonResponse(customers);
       } catch (error) {
         console.error("Error fetching customers:", error);
-        return new Response(
-          JSON.stringify({
-            error: "Failed to retrieve customers",
-            details: error instanceof Error ? error.message : String(error),
-          }),
-          { 
-            status: 500, 
-            headers: { 
-                "Content-Type": "application/json",
-                "Access-Control-Allow-Origin": "*",
-            } 
-        },
-        );
+        return errorResponse("Failed to retrieve customers", error);
       }
     }
+    const staticResponse = await serveStatic(url.pathname);
+    if (staticResponse) {
+      return staticResponse;
+    }
     return new Response("Not Found", { 
         status: 404 ,
-        headers: { "Access-Control-Allow-Origin": "*" },
+        headers: corsHeaders,
     });
   },
 });
\ No newline at end of file
@@ -1,14 +1,51 @@
-use eframe::egui::{Id};
+use eframe::egui::{Id, RichText};
 use eframe::{App, Frame, egui};
+use serde::{Deserialize, Serialize};
 
+use crate::commands::{self, Command, Keybindings, Palette, PaletteOutcome};
+use crate::datasource::auth::{self, Login};
+use crate::datasource::changefeed::ChangeFeed;
+use crate::i18n::{self, t, t_with, Language};
+use crate::login::LoginDialog;
+use crate::theme::{self, Theme, ThemePreset};
+use crate::trash::Trash;
+use crate::views::registry::ViewRegistry;
 use crate::workspace::Workspace;
 use crate::workspace::WorkspaceAction; // WorkspaceAction'ı import et
 
-#[derive(Clone)] // Workspace'e kopyalanabilmesi için
+// Sekme çubuğunda seçilen işlem; sekmeler çizildikten sonra uygulanır.
+enum TabAction {
+    Select(usize),
+    Rename(usize),
+    Duplicate(usize),
+    Close(usize),
+    CloseOthers(usize),
+    Move { from: usize, to: usize },
+}
+
+pub const DEFAULT_SERVER_BASE_URL: &str = "http://localhost:3000";
+
+#[derive(Clone, Serialize, Deserialize)] // Workspace'e kopyalanabilmesi için
+#[serde(default)]
 pub struct AppSettings {
     pub global_rounding: egui::CornerRadius,
-    // Tek bir pencere arka plan rengi ayarı
-    //pub window_background_fill: egui::Color32,
+    // Veri sunucusunun temel adresi, ör. "http://localhost:3000".
+    // WASM paketi veri sunucusunun kendisi tarafından sunuluyorsa boş bırakılabilir
+    // ya da "/api" gibi göreli bir yol verilebilir; istekler aynı kökene gider.
+    pub server_base_url: String,
+    // Komut satırında (--server-url) ya da WASM'da start() ile yalnızca bu oturum için verilen adres.
+    // Kaydedilmez; verilmişse server_base_url'in yerine kullanılır, kayıtlı adres değişmez.
+    #[serde(skip)]
+    pub server_url_override: Option<String>,
+    // Açıksa sunucunun değişiklik akışına abone olunur ve açık tablolar canlı güncellenir.
+    pub live_updates: bool,
+    // Renkler, yazı boyutları, boşluklar ve gölgeler; pencere arka plan rengi de temadadır
+    // ve egui stiline yazıldığı için Frame::window tarafından kendiliğinden kullanılır.
+    pub theme: Theme,
+    // Arayüz dili; üst çubuktaki 🌐 menüsünden değiştirilir.
+    pub language: Language,
+    // Komutlara atanmış klavye kısayolları; ⚙ Settings menüsündeki pencereden değiştirilir.
+    pub keybindings: Keybindings,
 }
 
 impl Default for AppSettings {
@@ -20,36 +57,483 @@ impl Default for AppSettings {
                 sw: 16, // GüneyBatı
                 se: 24, // GüneyDoğu
             },
-            //window_background_fill: egui::Color32::from_rgba_unmultiplied(233, 238, 235, 255), // Varsayılan pencere arka plan rengi
+            server_base_url: DEFAULT_SERVER_BASE_URL.to_string(),
+            server_url_override: None,
+            live_updates: true,
+            theme: Theme::default(),
+            language: Language::default(),
+            keybindings: Keybindings::default(),
         }
     }
 }
+impl AppSettings {
+    // İsteklerin gideceği temel adres: bu oturum için verilmiş adres varsa o, yoksa kayıtlı adres.
+    pub fn base_url(&self) -> &str {
+        self.server_url_override.as_deref().unwrap_or(&self.server_base_url)
+    }
+
+    // Temel adres ile uç nokta yolunu tek bir eğik çizgiyle birleştirir.
+    // "http://host:3000/" + "/customers" -> "http://host:3000/customers"
+    // "" + "/customers" -> "/customers" (aynı köken)
+    pub fn endpoint_url(&self, path: &str) -> String {
+        let base = self.base_url().trim().trim_end_matches('/');
+        let path = path.trim_start_matches('/');
+        format!("{}/{}", base, path)
+    }
+}
+
+// Uygulama durumu eframe depolamasına yazılır:
+// native tarafta diskteki bir dosyaya, WASM tarafında tarayıcının localStorage'ına.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct Application {
     selected_workspace: usize,
     workspaces: Vec<Workspace>,
+    #[serde(skip)]
     show_last_workspace_delete_warning: bool,
     next_workspace_id_counter: usize,
+    // Son verilen Workspace::id; sekme adlarından bağımsız, yalnızca pencere kimlikleri için.
+    last_workspace_id: u64,
+    // Adı sekme çubuğunda düzenlenen çalışma alanının sırası ve düzenlenen ad.
+    #[serde(skip)]
+    renaming_workspace: Option<(usize, String)>,
     settings: AppSettings,
+    // Silinen çalışma alanları; 🗑 menüsünden ya da "Undo" bildiriminden geri alınabilir.
+    trash: Trash,
+    // "Remember me" ile açılmış oturumlar (sunucu adresi -> belirteç). Çalışırken belirteçler
+    // auth.rs'de tutulur; bu alan yalnızca kaydederken doldurulur ve açılışta geri yüklenir.
+    logins: std::collections::BTreeMap<String, Login>,
+    // egui stiline en son uygulanan tema; ayarlardaki temadan farklıysa yeniden uygulanır.
+    #[serde(skip)]
+    applied_theme: Option<Theme>,
+    #[serde(skip)]
+    show_theme_editor: bool,
+    // Açıksa komut paletinin durumu.
+    #[serde(skip)]
+    palette: Option<Palette>,
+    #[serde(skip)]
+    show_shortcuts: bool,
+    // Kısayolu kaydedilmekte olan komut; kayıt sürerken kısayollar çalıştırılmaz.
+    #[serde(skip)]
+    recording_shortcut: Option<Command>,
+    // Sunucunun değişiklik akışına abonelik; üst çubukta bağlantı durumu gösterilir.
+    #[serde(skip)]
+    feed: ChangeFeed,
+    // Açıksa oturum açma penceresi; sunucu 401 döndürünce kendiliğinden açılır.
+    #[serde(skip)]
+    login: Option<LoginDialog>,
+    // Menüde gösterilen ve kayıttan geri yüklenebilen görünüm türleri.
+    #[serde(skip)]
+    registry: ViewRegistry,
+}
+
+impl Application {
+    fn new_workspace_id(&mut self) -> u64 {
+        self.last_workspace_id += 1;
+        self.last_workspace_id
+    }
+
+    // Kimliği olmayan (eski kayıtlardan gelen) ya da çakışan çalışma alanlarına yeni kimlik verir.
+    fn assign_workspace_ids(&mut self) {
+        self.last_workspace_id = self
+            .workspaces
+            .iter()
+            .map(|workspace| workspace.id)
+            .max()
+            .unwrap_or_default()
+            .max(self.last_workspace_id);
+        for i in 0..self.workspaces.len() {
+            let id = self.workspaces[i].id;
+            if id == 0 || self.workspaces[..i].iter().any(|other| other.id == id) {
+                self.workspaces[i].id = self.new_workspace_id();
+            }
+        }
+    }
+
+    // Çalışma alanını çöp kutusuna taşır. Son çalışma alanı kapatılamaz; bunun yerine uyarı gösterilir.
+    fn close_workspace(&mut self, ctx: &egui::Context, i: usize) {
+        if self.workspaces.len() <= 1 {
+            self.show_last_workspace_delete_warning = true;
+            return;
+        }
+        let workspace = self.workspaces.remove(i);
+        self.trash.discard(ctx, vec![(i, workspace)]);
+        if self.selected_workspace > i {
+            self.selected_workspace -= 1;
+        }
+        self.clamp_selected_workspace();
+    }
+
+    // Kayıttan gelen ya da bir silmeden sonra kalan seçim, var olan bir sekmeyi göstermeli.
+    fn clamp_selected_workspace(&mut self) {
+        self.selected_workspace = self.selected_workspace.min(self.workspaces.len().saturating_sub(1));
+    }
+
+    // Çöp kutusundaki çalışma alanlarını eski sıralarına geri koyar ve sonuncusunu seçer.
+    // Her karede çağrılır; geri alınacak bir şey yoksa yeniden adlandırmaya dokunmaz.
+    fn restore_workspaces(&mut self, ids: &[u64]) {
+        if ids.is_empty() {
+            return;
+        }
+        let restored = self.trash.take(ids);
+        if restored.is_empty() {
+            return;
+        }
+        for (index, mut workspace) in restored {
+            // Silindikten sonra verilen kimliklerle çakışmasın.
+            if self.workspaces.iter().any(|other| other.id == workspace.id) {
+                workspace.id = self.new_workspace_id();
+            }
+            let index = index.min(self.workspaces.len());
+            self.workspaces.insert(index, workspace);
+            self.selected_workspace = index;
+        }
+        self.renaming_workspace = None;
+    }
+
+    fn add_workspace(&mut self) {
+        let name = t_with("workspace-default-name", &[("number", self.next_workspace_id_counter.into())]);
+        let mut workspace = Workspace::new_with_name(name);
+        workspace.id = self.new_workspace_id();
+        self.workspaces.push(workspace);
+        self.next_workspace_id_counter += 1;
+        self.selected_workspace = self.workspaces.len().saturating_sub(1);
+    }
+
+    // Komut paletinden ya da bir kısayoldan gelen komutu çalıştırır.
+    fn run_command(&mut self, ctx: &egui::Context, command: Command) {
+        self.clamp_selected_workspace();
+        let count = self.workspaces.len();
+        match command {
+            Command::OpenPalette => self.palette = Some(Palette::default()),
+            Command::SelectWorkspace(id) => {
+                if let Some(i) = self.workspaces.iter().position(|workspace| workspace.id == id) {
+                    self.selected_workspace = i;
+                }
+            }
+            Command::NextWorkspace => self.selected_workspace = (self.selected_workspace + 1) % count,
+            Command::PreviousWorkspace => self.selected_workspace = (self.selected_workspace + count - 1) % count,
+            Command::AddWorkspace => self.add_workspace(),
+            Command::DeleteWorkspace => self.close_workspace(ctx, self.selected_workspace),
+            Command::RestoreWorkspace => {
+                if let Some(id) = self.trash.last_id() {
+                    self.restore_workspaces(&[id]);
+                }
+            }
+            Command::OpenView(kind) => {
+                if let (Some(descriptor), Some(workspace)) = (self.registry.get(&kind), self.workspaces.get_mut(self.selected_workspace)) {
+                    let parent_id = workspace.egui_id();
+                    workspace.open_view(ctx, parent_id, descriptor);
+                }
+            }
+            Command::OrganizeWindows => Workspace::organize_windows(ctx),
+            Command::CloseAllWindows => {
+                if let Some(workspace) = self.workspaces.get_mut(self.selected_workspace) {
+                    workspace.close_all_windows();
+                }
+            }
+            Command::RefreshData => {
+                if let Some(workspace) = self.workspaces.get_mut(self.selected_workspace) {
+                    workspace.refresh_views(ctx, &self.settings);
+                }
+            }
+            Command::ToggleTheme => {
+                let preset = if self.settings.theme.dark { ThemePreset::Light } else { ThemePreset::Dark };
+                self.settings.theme = Theme::preset(preset);
+            }
+            Command::ThemeEditor => self.show_theme_editor = true,
+            Command::KeyboardShortcuts => self.show_shortcuts = true,
+        }
+        self.renaming_workspace = None;
+    }
+
+    // Çalışma alanı sekmeleri: tıklayınca seçilir, çift tıklayınca yeniden adlandırılır,
+    // sürükleyerek sıralanır; sağ tık menüsünde diğer işlemler bulunur.
+    fn workspace_tabs_ui(&mut self, ui: &mut egui::Ui) {
+        let mut action = None;
+        let mut tab_rects = Vec::with_capacity(self.workspaces.len());
+        let mut dragged = None;
+
+        for (i, workspace) in self.workspaces.iter_mut().enumerate() {
+            if let Some((_, name)) = self.renaming_workspace.as_mut().filter(|(index, _)| *index == i) {
+                let response = ui.add(egui::TextEdit::singleline(name).desired_width(120.0));
+                if ui.input(|input| input.key_pressed(egui::Key::Escape)) {
+                    self.renaming_workspace = None;
+                } else if response.lost_focus() {
+                    // Boş ad kabul edilmez; sekme eski adıyla kalır.
+                    if !name.trim().is_empty() {
+                        workspace.name = name.trim().to_string();
+                    }
+                    self.renaming_workspace = None;
+                } else {
+                    response.request_focus();
+                }
+                tab_rects.push(response.rect);
+                continue;
+            }
+
+            let response = ui
+                .selectable_label(self.selected_workspace == i, &workspace.name)
+                .interact(egui::Sense::click_and_drag())
+                .on_hover_text(t("workspace-tab-hint"));
+            if response.double_clicked() {
+                action = Some(TabAction::Rename(i));
+            } else if response.clicked() {
+                action = Some(TabAction::Select(i));
+            }
+            if response.dragged() || response.drag_stopped() {
+                dragged = Some((i, response.drag_stopped()));
+            }
+            response.context_menu(|ui| {
+                if ui.button(t("workspace-tab-rename")).clicked() {
+                    action = Some(TabAction::Rename(i));
+                    ui.close_menu();
+                }
+                if ui.button(t("workspace-tab-duplicate")).clicked() {
+                    action = Some(TabAction::Duplicate(i));
+                    ui.close_menu();
+                }
+                ui.separator();
+                if ui.button(t("workspace-tab-close")).clicked() {
+                    action = Some(TabAction::Close(i));
+                    ui.close_menu();
+                }
+                if ui.button(t("workspace-tab-close-others")).clicked() {
+                    action = Some(TabAction::CloseOthers(i));
+                    ui.close_menu();
+                }
+            });
+            tab_rects.push(response.rect);
+        }
+
+        // Sürüklenen sekme, imlecin üzerinde bulunduğu sekmenin yerine bırakılır.
+        if let Some((from, released)) = dragged {
+            let pointer_x = ui.ctx().pointer_latest_pos().map(|pos| pos.x);
+            let target = pointer_x.map(|x| {
+                tab_rects
+                    .iter()
+                    .position(|rect| x < rect.center().x)
+                    .unwrap_or(tab_rects.len())
+            });
+            if let Some(to) = target {
+                let marker_x = match tab_rects.get(to) {
+                    Some(rect) => rect.left() - 2.0,
+                    None => tab_rects.last().map_or(0.0, |rect| rect.right() + 2.0),
+                };
+                let y_range = tab_rects[from].y_range();
+                ui.painter().vline(marker_x, y_range, ui.visuals().selection.stroke);
+                // `to` bir ekleme noktasıdır; sekmenin kendi sağına bırakılması sırayı değiştirmez.
+                let to = if to > from { to - 1 } else { to };
+                if released && to != from {
+                    action = Some(TabAction::Move { from, to });
+                }
+            }
+        }
+
+        match action {
+            Some(TabAction::Select(i)) => self.selected_workspace = i,
+            Some(TabAction::Rename(i)) => {
+                self.renaming_workspace = Some((i, self.workspaces[i].name.clone()));
+            }
+            Some(TabAction::Duplicate(i)) => {
+                let name = t_with("workspace-copy-name", &[("name", self.workspaces[i].name.clone().into())]);
+                let id = self.new_workspace_id();
+                let copy = self.workspaces[i].duplicate(name, id, &self.registry);
+                self.workspaces.insert(i + 1, copy);
+                self.selected_workspace = i + 1;
+            }
+            Some(TabAction::Close(i)) => self.close_workspace(ui.ctx(), i),
+            Some(TabAction::CloseOthers(i)) => {
+                let kept = self.workspaces.remove(i);
+                let others = std::mem::take(&mut self.workspaces).into_iter().enumerate().map(|(index, workspace)| {
+                    // Diğerleri, tutulan sekmenin önüne ya da arkasına geri dönebilsin diye eski sıralarıyla saklanır.
+                    (if index < i { index } else { index + 1 }, workspace)
+                });
+                self.trash.discard(ui.ctx(), others.collect());
+                self.workspaces = vec![kept];
+                self.selected_workspace = 0;
+            }
+            Some(TabAction::Move { from, to }) => {
+                let selected_id = self.workspaces[self.selected_workspace].id;
+                let workspace = self.workspaces.remove(from);
+                self.workspaces.insert(to, workspace);
+                self.selected_workspace = self
+                    .workspaces
+                    .iter()
+                    .position(|workspace| workspace.id == selected_id)
+                    .unwrap_or_default();
+            }
+            None => {}
+        }
+        if !matches!(action, None | Some(TabAction::Rename(_))) {
+            self.renaming_workspace = None;
+        }
+    }
+
+    // Önceki oturumdan kaydedilmiş durum varsa onu yükler, yoksa varsayılanla başlar.
+    // `server_base_url` verilmişse (native'de komut satırı, WASM'da start() parametresi)
+    // bu oturum boyunca kaydedilmiş sunucu adresinin yerine geçer; kayıtlı adres korunur.
+    pub fn new(cc: &eframe::CreationContext<'_>, server_base_url: Option<String>) -> Self {
+        Self::with_registry(cc, server_base_url, ViewRegistry::with_builtin_views())
+    }
+
+    // Uygulamayı gömen programlar kendi görünümlerini ekledikleri bir kayıtla başlatabilir:
+    // `let mut registry = ViewRegistry::with_builtin_views(); registry.register(...);`
+    pub fn with_registry(cc: &eframe::CreationContext<'_>, server_base_url: Option<String>, registry: ViewRegistry) -> Self {
+        let mut app: Self = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, eframe::APP_KEY))
+            .unwrap_or_default();
+
+        // Bozuk ya da boş bir kayıt, hiç çalışma alanı olmayan bir uygulama bırakmamalı.
+        i18n::set_language(app.settings.language);
+        if app.workspaces.is_empty() {
+            app.workspaces.push(Workspace::new_with_name(t("workspace-welcome-name")));
+        }
+        app.settings.server_url_override = server_base_url;
+        app.assign_workspace_ids();
+        app.clamp_selected_workspace();
+        for workspace in app.workspaces.iter_mut() {
+            workspace.restore_views(&registry);
+        }
+        app.trash.restore_views(&registry);
+        auth::restore(std::mem::take(&mut app.logins));
+        app.registry = registry;
+        app
+    }
 }
 
 impl App for Application {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        for workspace in self.workspaces.iter_mut() {
+            workspace.store_views();
+        }
+        self.trash.store_views();
+        self.logins = auth::remembered();
+        eframe::set_value(storage, eframe::APP_KEY, self);
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+        i18n::set_language(self.settings.language);
+        if self.applied_theme.as_ref() != Some(&self.settings.theme) {
+            self.settings.theme.apply(ctx);
+            self.applied_theme = Some(self.settings.theme.clone());
+        }
+
+        // Değişiklikler gizli çalışma alanlarındaki görünümlere de uygulanır.
+        self.feed.ensure(ctx, &self.settings);
+        let changes = self.feed.poll();
+        if !changes.is_empty() {
+            for workspace in self.workspaces.iter_mut() {
+                workspace.apply_changes(&changes);
+            }
+        }
+
+        if self.palette.is_none() && self.recording_shortcut.is_none() {
+            if let Some(command) = self.settings.keybindings.pressed(ctx) {
+                self.run_command(ctx, command);
+            }
+        }
+
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
-                egui::widgets::global_theme_preference_buttons(ui);
+                ui.menu_button(t("menu-theme"), |ui| {
+                    for preset in ThemePreset::ALL {
+                        if preset == ThemePreset::Custom {
+                            continue;
+                        }
+                        if ui.selectable_label(self.settings.theme.preset == preset, preset.label()).clicked() {
+                            self.settings.theme = Theme::preset(preset);
+                            ui.close_menu();
+                        }
+                    }
+                    ui.separator();
+                    if ui.button(t("menu-theme-editor")).clicked() {
+                        self.show_theme_editor = true;
+                        ui.close_menu();
+                    }
+                });
+
+                ui.menu_button(format!("🌐 {}", self.settings.language.native_name()), |ui| {
+                    for language in Language::ALL {
+                        if ui.selectable_label(self.settings.language == language, language.native_name()).clicked() {
+                            // Aynı karede çizilecek metinler de yeni dilde görünsün.
+                            self.settings.language = language;
+                            i18n::set_language(language);
+                            ui.close_menu();
+                        }
+                    }
+                });
 
                 ui.separator();
 
-                for (i, workspace) in self.workspaces.iter_mut().enumerate() {
-                    if ui
-                        .selectable_label(self.selected_workspace == i, &workspace.name)
-                        .clicked()
-                    {
-                        workspace.reset_confirm_delete();
-                        self.selected_workspace = i;
+                ui.menu_button(t("menu-settings"), |ui| {
+                    ui.label(RichText::new(t("settings-data-server")).strong());
+                    if let Some(url) = &self.settings.server_url_override {
+                        // Oturumluk adres kullanılırken kayıtlı adres düzenlenmez; önce ona dönülür.
+                        let url = if url.trim().is_empty() { t("settings-same-origin") } else { url.clone() };
+                        ui.weak(t_with("settings-server-override", &[("url", url.into())]));
+                        if ui.button(t("settings-use-saved-url")).clicked() {
+                            self.settings.server_url_override = None;
+                        }
+                    } else {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.settings.server_base_url)
+                                .hint_text(t("settings-same-origin"))
+                                .desired_width(240.0),
+                        );
+                        if ui.button(t("settings-reset-default")).clicked() {
+                            self.settings.server_base_url = DEFAULT_SERVER_BASE_URL.to_string();
+                        }
+                    }
+                    let server = auth::server_key(&self.settings);
+                    match auth::login(&server) {
+                        Some(login) => {
+                            ui.horizontal(|ui| {
+                                ui.weak(match &login.user {
+                                    Some(user) => t_with("settings-signed-in-as", &[("user", user.clone().into())]),
+                                    None => t("settings-signed-in-token"),
+                                });
+                                if ui.button(t("settings-sign-out")).clicked() {
+                                    auth::sign_out(ctx, &self.settings, &server);
+                                    ui.close_menu();
+                                }
+                            });
+                        }
+                        None => {
+                            if ui.button(t("settings-sign-in")).clicked() {
+                                self.login = Some(LoginDialog::new(server));
+                                ui.close_menu();
+                            }
+                        }
                     }
+                    ui.checkbox(&mut self.settings.live_updates, t("settings-live-updates"))
+                        .on_hover_text(t("settings-live-updates-hint"));
+                    ui.separator();
+                    if ui.button(t("menu-keyboard-shortcuts")).clicked() {
+                        self.show_shortcuts = true;
+                        ui.close_menu();
+                    }
+                });
+
+                let palette_hint = match self.settings.keybindings.get(&Command::OpenPalette) {
+                    Some(shortcut) => format!("{} ({})", t("command-palette"), ctx.format_shortcut(&shortcut)),
+                    None => t("command-palette"),
+                };
+                if ui.button("🔍").on_hover_text(palette_hint).clicked() {
+                    self.palette = Some(Palette::default());
                 }
 
+                let mut restore = Vec::new();
+                ui.menu_button(t_with("trash-menu", &[("count", self.trash.count().into())]), |ui| {
+                    restore = self.trash.menu_ui(ui);
+                });
+                self.restore_workspaces(&restore);
+
+                ui.separator();
+
+                self.workspace_tabs_ui(ui);
+
                 /*
                 if ui.button("Add workspace").clicked() {
                     self.workspaces.push(Workspace::default());
@@ -58,42 +542,32 @@ impl App for Application {
                 */
 
                 ui.separator();
+
+                // Değişiklik akışının bağlantı durumu sağ kenarda gösterilir.
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    self.feed.status_ui(ui);
+                });
             });
         });
 
-        self.selected_workspace = self
-            .selected_workspace
-            .min(self.workspaces.len().saturating_sub(1));
-
-        // Bir çalışma alanını potansiyel olarak değiştirilebilir şekilde ödünç almadan önce
-        // çalışma alanı sayısını al. Bu uzunluk, son çalışma alanının silinip silinmeyeceğine
-        // karar vermek için kullanılır.
-        let num_workspaces_at_start_of_update = self.workspaces.len();
+        self.clamp_selected_workspace();
 
         match self.workspaces.get_mut(self.selected_workspace) {
             Some(workspace) => {
                 let mut open = true;
-                let action = workspace.ui(Id::new(self.selected_workspace), &mut open, ctx, &self.settings);
+                let action = workspace.ui(workspace.egui_id(), &mut open, ctx, &self.settings, &self.registry);
 
                 if !open {
-                    if num_workspaces_at_start_of_update > 1 {
-                        self.workspaces.remove(self.selected_workspace);
-                        // Çalışma alanı kaldırıldıktan sonra seçili çalışma alanını yeniden ayarla
-                        self.selected_workspace = self
-                            .selected_workspace
-                            .min(self.workspaces.len().saturating_sub(1));
-                    } else {
-                        // Son çalışma alanı silinemez, silme onayını sıfırla
-                        workspace.reset_confirm_delete();
-                        self.show_last_workspace_delete_warning = true;
-                    }
+                    // Son çalışma alanı silinemez; close_workspace uyarıyı gösterir.
+                    self.close_workspace(ctx, self.selected_workspace);
+                    self.renaming_workspace = None;
                 } else {
                     // Sadece çalışma alanı silinmediyse eylemi işle
                     match action {
-                        WorkspaceAction::AddWorkspace => {
-                            let new_workspace_name = format!("Workspace{}", self.next_workspace_id_counter);
-                            self.workspaces.push(Workspace::new_with_name(new_workspace_name));
-                            self.next_workspace_id_counter += 1;
+                        WorkspaceAction::AddWorkspace => self.add_workspace(),
+                        WorkspaceAction::ImportWorkspace(mut imported) => {
+                            imported.id = self.new_workspace_id();
+                            self.workspaces.push(*imported);
                             self.selected_workspace = self.workspaces.len().saturating_sub(1);
                         }
                         WorkspaceAction::None => {}
@@ -107,6 +581,63 @@ impl App for Application {
             }
         }
 
+        // 401 alan bir istek oturum açılmasını bekliyor.
+        if let Some(prompt) = auth::prompt() {
+            if self.login.as_ref().is_none_or(|dialog| dialog.server() != prompt.server) {
+                self.login = Some(LoginDialog::new(prompt.server));
+            }
+        }
+        if let Some(dialog) = self.login.as_mut() {
+            if !dialog.ui(ctx, &self.settings) {
+                self.login = None;
+            }
+        }
+
+        let undo = self.trash.toast_ui(ctx);
+        self.restore_workspaces(&undo);
+
+        egui::Window::new(t("theme-editor-title"))
+            .id(Id::new("theme_editor_window"))
+            .default_width(360.0)
+            .frame(egui::Frame::window(&ctx.style()).corner_radius(self.settings.global_rounding))
+            .open(&mut self.show_theme_editor)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                theme::editor_ui(ui, &mut self.settings.theme, &mut self.settings.global_rounding);
+            });
+
+        egui::Window::new(t("shortcuts-title"))
+            .id(Id::new("keyboard_shortcuts_window"))
+            .default_width(420.0)
+            .frame(egui::Frame::window(&ctx.style()).corner_radius(self.settings.global_rounding))
+            .open(&mut self.show_shortcuts)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                let commands: Vec<(Command, String)> = commands::bindable(&self.registry)
+                    .into_iter()
+                    .map(|command| {
+                        let label = command.label(&self.registry, &self.workspaces);
+                        (command, label)
+                    })
+                    .collect();
+                commands::shortcuts_ui(ui, &mut self.settings.keybindings, &mut self.recording_shortcut, &commands);
+            });
+        if !self.show_shortcuts {
+            self.recording_shortcut = None;
+        }
+
+        if let Some(palette) = self.palette.as_mut() {
+            let entries = commands::palette_entries(&self.registry, &self.workspaces);
+            match palette.ui(ctx, &entries, &self.settings.keybindings) {
+                PaletteOutcome::Open => {}
+                PaletteOutcome::Closed => self.palette = None,
+                PaletteOutcome::Run(command) => {
+                    self.palette = None;
+                    self.run_command(ctx, command);
+                }
+            }
+        }
+
         if self.show_last_workspace_delete_warning {
             let mut window_is_still_open = true; // Pencerenin kendi 'X' butonu için
             let mut close_warning_requested = false;
@@ -137,24 +668,23 @@ impl App for Application {
                 });
 
             // 2. Uyarı penceresini göster (arka planın üzerinde)
-            egui::Window::new("Warning")
+            egui::Window::new(t("warning-title"))
                 .id(Id::new("last_workspace_delete_warning_window"))
                 .title_bar(false) // Başlık çubuğunu kaldırır
                 .order(egui::Order::Tooltip) // Her zaman en üstte olması için Tooltip katmanını kullan
                 .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
-                .frame(egui::Frame::window(&*ctx.style())
+                .frame(egui::Frame::window(&ctx.style())
                     .corner_radius(self.settings.global_rounding)
-                    //.fill(self.settings.window_background_fill)
                     )
                 .collapsible(false)
                 .resizable(false)
                 .open(&mut window_is_still_open)
                 .show(ctx, |ui_win| {
                     ui_win.label(""); // Üstte biraz boşluk bırakmak için
-                    ui_win.label("The last remaining workspace cannot be deleted.");
+                    ui_win.label(t("last-workspace-warning"));
                     ui_win.add_space(10.0); // Etiket ile buton arasına biraz boşluk
                     ui_win.with_layout(egui::Layout::top_down(egui::Align::Max), |ui_button_area| {
-                        if ui_button_area.button("Ok").clicked() {
+                        if ui_button_area.button(t("ok")).clicked() {
                             close_warning_requested = true;
                         }
                     });
@@ -171,12 +701,66 @@ impl Default for Application {
     fn default() -> Self {
         Self {
             selected_workspace: 0,
-            workspaces: vec![Workspace::new_with_name("Welcome".to_string())],
+            workspaces: vec![Workspace::new_with_name(t("workspace-welcome-name"))],
             show_last_workspace_delete_warning: false,
             // İlk "Workspace1" için sayaç 1'den başlar.
-            // "Welcome" özel bir durum olduğu için sayacı etkilemez.
+            // Karşılama çalışma alanı özel bir durum olduğu için sayacı etkilemez.
             next_workspace_id_counter: 1,
+            last_workspace_id: 0,
+            renaming_workspace: None,
             settings: AppSettings::default(),
+            trash: Trash::default(),
+            logins: Default::default(),
+            applied_theme: None,
+            show_theme_editor: false,
+            palette: None,
+            show_shortcuts: false,
+            recording_shortcut: None,
+            feed: ChangeFeed::default(),
+            login: None,
+            registry: ViewRegistry::with_builtin_views(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(base: &str) -> AppSettings {
+        AppSettings { server_base_url: base.to_string(), ..AppSettings::default() }
+    }
+
+    #[test]
+    fn endpoint_url_joins_with_a_single_slash() {
+        assert_eq!(settings("http://host:3000").endpoint_url("/customers"), "http://host:3000/customers");
+        assert_eq!(settings("http://host:3000/").endpoint_url("/customers"), "http://host:3000/customers");
+        assert_eq!(settings("http://host:3000//").endpoint_url("customers"), "http://host:3000/customers");
+        assert_eq!(settings("  http://host:3000/api/  ").endpoint_url("/query"), "http://host:3000/api/query");
+    }
+
+    #[test]
+    fn endpoint_url_with_empty_or_relative_base_stays_same_origin() {
+        assert_eq!(settings("").endpoint_url("/customers"), "/customers");
+        assert_eq!(settings("/").endpoint_url("customers"), "/customers");
+        assert_eq!(settings("/api").endpoint_url("/schema"), "/api/schema");
+    }
+
+    #[test]
+    fn override_takes_precedence_without_replacing_saved_url() {
+        let mut settings = settings("http://saved:3000");
+        settings.server_url_override = Some("http://staging:4000/".to_string());
+        assert_eq!(settings.endpoint_url("/customers"), "http://staging:4000/customers");
+
+        // Geçersiz kılma kaydedilmez; kayıttan okunan ayarlar kayıtlı adresi kullanır.
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(!json.contains("staging"));
+        let restored: AppSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.server_url_override, None);
+        assert_eq!(restored.endpoint_url("/customers"), "http://saved:3000/customers");
+
+        // Boş geçersiz kılma da (aynı köken) kayıtlı adresin yerine geçer.
+        settings.server_url_override = Some(String::new());
+        assert_eq!(settings.endpoint_url("/customers"), "/customers");
+    }
+}
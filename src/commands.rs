@@ -0,0 +1,424 @@
+// Komut paleti (Ctrl+K) ve klavye kısayolları. Uygulamadaki eylemlerin her biri bir `Command`tır:
+// palet bunları bulanık aramayla listeler, kısayol haritası AppSettings ile saklanır ve
+// "Keyboard shortcuts" penceresinden değiştirilebilir. Komutları Application çalıştırır.
+
+use eframe::egui::{self, Event, Id, Key, KeyboardShortcut, Modifiers, RichText};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{t, t_or, t_with};
+use crate::views::registry::ViewRegistry;
+use crate::workspace::Workspace;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Command {
+    OpenPalette,
+    // Workspace::id ile; sekmeler yeniden sıralansa da aynı çalışma alanını seçer.
+    SelectWorkspace(u64),
+    NextWorkspace,
+    PreviousWorkspace,
+    AddWorkspace,
+    DeleteWorkspace,
+    // Çöp kutusuna en son giden çalışma alanını geri getirir.
+    RestoreWorkspace,
+    // ViewDescriptor::kind ile; kayıtlı olmayan türler için bir şey yapmaz.
+    OpenView(String),
+    OrganizeWindows,
+    CloseAllWindows,
+    RefreshData,
+    ToggleTheme,
+    ThemeEditor,
+    KeyboardShortcuts,
+}
+
+impl Command {
+    // Görünüm açma ve çalışma alanı seçme dışındaki komutlar.
+    const FIXED: [Command; 12] = [
+        Command::OpenPalette,
+        Command::NextWorkspace,
+        Command::PreviousWorkspace,
+        Command::AddWorkspace,
+        Command::DeleteWorkspace,
+        Command::RestoreWorkspace,
+        Command::OrganizeWindows,
+        Command::CloseAllWindows,
+        Command::RefreshData,
+        Command::ToggleTheme,
+        Command::ThemeEditor,
+        Command::KeyboardShortcuts,
+    ];
+
+    pub fn label(&self, registry: &ViewRegistry, workspaces: &[Workspace]) -> String {
+        match self {
+            Command::OpenPalette => t("command-palette"),
+            Command::SelectWorkspace(id) => {
+                let name = workspaces.iter().find(|workspace| workspace.id == *id).map_or_else(String::new, |workspace| workspace.name.clone());
+                t_with("command-select-workspace", &[("name", name.into())])
+            }
+            Command::NextWorkspace => t("command-next-workspace"),
+            Command::PreviousWorkspace => t("command-previous-workspace"),
+            Command::AddWorkspace => t("command-add-workspace"),
+            Command::DeleteWorkspace => t("command-delete-workspace"),
+            Command::RestoreWorkspace => t("command-restore-workspace"),
+            Command::OpenView(kind) => {
+                let name = match registry.get(kind) {
+                    Some(descriptor) => format!("{} {}", descriptor.icon, t_or(&format!("view-{}", kind), descriptor.display_name)),
+                    None => kind.clone(),
+                };
+                t_with("command-open-view", &[("view", name.into())])
+            }
+            Command::OrganizeWindows => t("command-organize-windows"),
+            Command::CloseAllWindows => t("command-close-all-windows"),
+            Command::RefreshData => t("command-refresh-data"),
+            Command::ToggleTheme => t("command-toggle-theme"),
+            Command::ThemeEditor => t("command-theme-editor"),
+            Command::KeyboardShortcuts => t("command-keyboard-shortcuts"),
+        }
+    }
+}
+
+// Kısayol atanabilen komutlar: sabit komutlar ve menüde görünen her görünüm türü için bir "Open".
+pub fn bindable(registry: &ViewRegistry) -> Vec<Command> {
+    let mut commands = Command::FIXED.to_vec();
+    for (_, descriptors) in registry.menu() {
+        commands.extend(descriptors.iter().map(|descriptor| Command::OpenView(descriptor.kind.to_string())));
+    }
+    commands
+}
+
+// Paletteki satırlar: önce çalışma alanlarına geçiş, sonra kısayol atanabilen komutlar.
+pub fn palette_entries(registry: &ViewRegistry, workspaces: &[Workspace]) -> Vec<(Command, String)> {
+    workspaces
+        .iter()
+        .map(|workspace| Command::SelectWorkspace(workspace.id))
+        .chain(bindable(registry))
+        .map(|command| {
+            let label = command.label(registry, workspaces);
+            (command, label)
+        })
+        .collect()
+}
+
+// Komutlara atanmış kısayollar. Bir kısayol en fazla bir komuta bağlıdır.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Keybindings {
+    bindings: Vec<(Command, KeyboardShortcut)>,
+}
+
+impl Default for Keybindings {
+    // Tarayıcının kendi kısayollarıyla (Ctrl+R, Ctrl+T, Ctrl+N...) çakışmamaları için
+    // paletten başka varsayılanlar Alt ile başlar.
+    fn default() -> Self {
+        let shortcut = KeyboardShortcut::new;
+        Self {
+            bindings: vec![
+                (Command::OpenPalette, shortcut(Modifiers::COMMAND, Key::K)),
+                (Command::NextWorkspace, shortcut(Modifiers::ALT, Key::PageDown)),
+                (Command::PreviousWorkspace, shortcut(Modifiers::ALT, Key::PageUp)),
+                (Command::AddWorkspace, shortcut(Modifiers::ALT, Key::N)),
+                (Command::OrganizeWindows, shortcut(Modifiers::ALT, Key::O)),
+                (Command::RefreshData, shortcut(Modifiers::ALT, Key::R)),
+                (Command::ToggleTheme, shortcut(Modifiers::ALT, Key::T)),
+            ],
+        }
+    }
+}
+
+impl Keybindings {
+    pub fn get(&self, command: &Command) -> Option<KeyboardShortcut> {
+        self.bindings.iter().find(|(bound, _)| bound == command).map(|(_, shortcut)| *shortcut)
+    }
+
+    // Kısayolu başka bir komut kullanıyorsa o komuttan kaldırılır. `None` kısayolu siler.
+    pub fn set(&mut self, command: &Command, shortcut: Option<KeyboardShortcut>) {
+        self.bindings.retain(|(bound, existing)| bound != command && Some(*existing) != shortcut);
+        if let Some(shortcut) = shortcut {
+            self.bindings.push((command.clone(), shortcut));
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    // Bu karede basılan kısayolun komutu; tuş olayı tüketilir. egui kısayolları mantıksal olarak
+    // eşleştirdiği için (Ctrl+Shift+K, Ctrl+K ile de eşleşir) çok değiştiricili olanlara önce bakılır.
+    // Bir metin kutusuna yazılırken yalnızca Ctrl/Cmd'li kısayollar ve F tuşları çalışır; bazı
+    // klavyelerde Alt ile karakter yazıldığından diğerleri metin kutusuna bırakılır.
+    pub fn pressed(&self, ctx: &egui::Context) -> Option<Command> {
+        let typing = ctx.wants_keyboard_input();
+        let mut bindings: Vec<&(Command, KeyboardShortcut)> = self
+            .bindings
+            .iter()
+            .filter(|(_, shortcut)| !typing || has_command(shortcut.modifiers) || is_function_key(shortcut.logical_key))
+            .collect();
+        bindings.sort_by_key(|(_, shortcut)| std::cmp::Reverse(modifier_count(shortcut.modifiers)));
+        ctx.input_mut(|input| {
+            bindings
+                .into_iter()
+                .find(|(_, shortcut)| input.consume_shortcut(shortcut))
+                .map(|(command, _)| command.clone())
+        })
+    }
+}
+
+fn has_command(modifiers: Modifiers) -> bool {
+    modifiers.command || modifiers.ctrl || modifiers.mac_cmd
+}
+
+fn is_function_key(key: Key) -> bool {
+    key.name().strip_prefix('F').is_some_and(|number| number.parse::<u8>().is_ok())
+}
+
+// Değiştiricisiz ya da yalnızca Shift'li tuşlar yazı yazarken de basıldığından kısayol olamaz;
+// F tuşları bunun dışındadır.
+fn is_bindable(shortcut: &KeyboardShortcut) -> bool {
+    shortcut.modifiers.alt || has_command(shortcut.modifiers) || is_function_key(shortcut.logical_key)
+}
+
+fn modifier_count(modifiers: Modifiers) -> usize {
+    [modifiers.alt, modifiers.shift, has_command(modifiers)]
+        .into_iter()
+        .filter(|pressed| *pressed)
+        .count()
+}
+
+// Kaydedilen kısayol macOS ve diğer sistemlerde aynı çalışsın diye Ctrl/Cmd "command" olarak saklanır.
+fn portable(modifiers: Modifiers) -> Modifiers {
+    if modifiers.command {
+        Modifiers { ctrl: false, mac_cmd: false, ..modifiers }
+    } else {
+        modifiers
+    }
+}
+
+// Sorgudaki harfler metinde aynı sırayla geçiyorsa bir puan döner, geçmiyorsa None.
+// Art arda gelen ve kelime başına denk gelen harfler daha yüksek puan alır; boşluklar ve
+// büyük/küçük harf ayrımı yok sayılır.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous = None;
+    for wanted in query.chars().flat_map(char::to_lowercase).filter(|c| !c.is_whitespace()) {
+        let found = (position..text.len()).find(|&i| text[i] == wanted)?;
+        score += 1;
+        if found > 0 && previous == Some(found - 1) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        score -= (found - position).min(3) as i32;
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+pub enum PaletteOutcome {
+    Open,
+    Closed,
+    Run(Command),
+}
+
+// Açık paletin durumu; palet her açılışta boş sorguyla başlar.
+#[derive(Default)]
+pub struct Palette {
+    query: String,
+    selected: usize,
+}
+
+impl Palette {
+    // ↑/↓ seçimi değiştirir, Enter ya da tıklama komutu çalıştırır, Esc ya da dışarı tıklama kapatır.
+    pub fn ui(&mut self, ctx: &egui::Context, entries: &[(Command, String)], keybindings: &Keybindings) -> PaletteOutcome {
+        let mut outcome = PaletteOutcome::Open;
+        let id = Id::new("command_palette");
+        let area = egui::Modal::default_area(id).anchor(egui::Align2::CENTER_TOP, [0.0, 60.0]);
+        let modal = egui::Modal::new(id).area(area).show(ctx, |ui| {
+            ui.set_width(440.0);
+            // Tek satırlık metin kutusu Enter'da odağı bırakır; tuşlar kutudan önce alınır.
+            let (down, up, enter) = ui.input_mut(|i| {
+                (
+                    i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                    i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                    i.consume_key(Modifiers::NONE, Key::Enter),
+                )
+            });
+            let edit = ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text(t("palette-hint"))
+                    .desired_width(f32::INFINITY),
+            );
+            edit.request_focus();
+            if edit.changed() {
+                self.selected = 0;
+            }
+
+            let mut matches: Vec<(i32, &(Command, String))> = entries
+                .iter()
+                .filter_map(|entry| fuzzy_score(&self.query, &entry.1).map(|score| (score, entry)))
+                .collect();
+            matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            if down {
+                self.selected += 1;
+            }
+            if up {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            self.selected = self.selected.min(matches.len().saturating_sub(1));
+            if let (true, Some((_, (command, _)))) = (enter, matches.get(self.selected)) {
+                outcome = PaletteOutcome::Run(command.clone());
+            }
+
+            ui.separator();
+            egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                if matches.is_empty() {
+                    ui.weak(t("palette-no-match"));
+                }
+                for (i, (_, (command, label))) in matches.iter().enumerate() {
+                    let response = ui
+                        .horizontal(|ui| {
+                            let response = ui.selectable_label(i == self.selected, label);
+                            if let Some(shortcut) = keybindings.get(command) {
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    ui.weak(ui.ctx().format_shortcut(&shortcut));
+                                });
+                            }
+                            response
+                        })
+                        .inner;
+                    if i == self.selected && (up || down) {
+                        response.scroll_to_me(None);
+                    }
+                    if response.clicked() {
+                        outcome = PaletteOutcome::Run(command.clone());
+                    }
+                }
+            });
+        });
+        if matches!(outcome, PaletteOutcome::Open) && modal.should_close() {
+            outcome = PaletteOutcome::Closed;
+        }
+        outcome
+    }
+}
+
+// Kısayollar penceresinin içeriği. "Set" tıklandıktan sonra basılan ilk geçerli tuş birleşimi
+// kaydedilir (bkz. `is_bindable`); Esc kaydı iptal eder.
+pub fn shortcuts_ui(ui: &mut egui::Ui, keybindings: &mut Keybindings, recording: &mut Option<Command>, commands: &[(Command, String)]) {
+    if let Some(command) = recording.clone() {
+        let pressed = ui.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::Key { key, pressed: true, modifiers, .. } => Some((*key, *modifiers)),
+                _ => None,
+            })
+        });
+        if let Some((key, modifiers)) = pressed {
+            let shortcut = KeyboardShortcut::new(portable(modifiers), key);
+            if key == Key::Escape {
+                *recording = None;
+            } else if is_bindable(&shortcut) {
+                keybindings.set(&command, Some(shortcut));
+                *recording = None;
+            }
+        }
+    }
+
+    egui::Grid::new("keyboard_shortcuts").num_columns(3).striped(true).show(ui, |ui| {
+        for (command, label) in commands {
+            ui.label(label);
+            if recording.as_ref() == Some(command) {
+                ui.label(RichText::new(t("shortcuts-press-keys")).italics());
+            } else {
+                match keybindings.get(command) {
+                    Some(shortcut) => ui.monospace(ui.ctx().format_shortcut(&shortcut)),
+                    None => ui.weak("—"),
+                };
+            }
+            ui.horizontal(|ui| {
+                if ui.small_button(t("shortcuts-set")).clicked() {
+                    *recording = Some(command.clone());
+                }
+                if keybindings.get(command).is_some() && ui.small_button("✖").on_hover_text(t("shortcuts-clear")).clicked() {
+                    keybindings.set(command, None);
+                }
+            });
+            ui.end_row();
+        }
+    });
+    ui.separator();
+    if ui.button(t("shortcuts-reset")).clicked() {
+        keybindings.reset();
+        *recording = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortcut(modifiers: Modifiers, key: Key) -> KeyboardShortcut {
+        KeyboardShortcut::new(modifiers, key)
+    }
+
+    #[test]
+    fn fuzzy_score_ranks_consecutive_and_word_start_matches_higher() {
+        // Art arda gelen harfler dağınık eşleşmeden, kelime başındakiler kelime içindekilerden yüksek puan alır.
+        assert!(fuzzy_score("ow", "Owner view") > fuzzy_score("ow", "Organize windows"));
+        assert!(fuzzy_score("ord", "Open: Orders") > fuzzy_score("ord", "Open: Product records"));
+        assert!(fuzzy_score("w", "Close all windows") > fuzzy_score("w", "Toggle theme now"));
+        assert!(fuzzy_score("next ws", "Next workspace") > fuzzy_score("next ws", "Open: Network stats"));
+        // Büyük/küçük harf ve boşluklar yok sayılır.
+        assert_eq!(fuzzy_score("THEME", "Toggle theme"), fuzzy_score("theme", "Toggle theme"));
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_score_rejects_missing_or_out_of_order_letters() {
+        assert_eq!(fuzzy_score("xyz", "Toggle theme"), None);
+        assert_eq!(fuzzy_score("eht", "theme"), None);
+        assert_eq!(fuzzy_score("themes", "theme"), None);
+    }
+
+    #[test]
+    fn set_moves_a_shortcut_from_its_previous_command() {
+        let mut keybindings = Keybindings::default();
+        let alt_n = shortcut(Modifiers::ALT, Key::N);
+        assert_eq!(keybindings.get(&Command::AddWorkspace), Some(alt_n));
+
+        keybindings.set(&Command::ThemeEditor, Some(alt_n));
+        assert_eq!(keybindings.get(&Command::ThemeEditor), Some(alt_n));
+        assert_eq!(keybindings.get(&Command::AddWorkspace), None);
+
+        // Komutun eski kısayolu yenisiyle değişir.
+        let alt_e = shortcut(Modifiers::ALT, Key::E);
+        keybindings.set(&Command::ThemeEditor, Some(alt_e));
+        assert_eq!(keybindings.get(&Command::ThemeEditor), Some(alt_e));
+        assert_eq!(keybindings.bindings.iter().filter(|(command, _)| *command == Command::ThemeEditor).count(), 1);
+
+        keybindings.set(&Command::ThemeEditor, None);
+        assert_eq!(keybindings.get(&Command::ThemeEditor), None);
+    }
+
+    #[test]
+    fn reset_restores_the_default_bindings() {
+        let mut keybindings = Keybindings::default();
+        keybindings.set(&Command::OpenPalette, None);
+        keybindings.set(&Command::ThemeEditor, Some(shortcut(Modifiers::ALT, Key::T)));
+        assert_ne!(keybindings, Keybindings::default());
+
+        keybindings.reset();
+        assert_eq!(keybindings, Keybindings::default());
+        assert_eq!(keybindings.get(&Command::ToggleTheme), Some(shortcut(Modifiers::ALT, Key::T)));
+    }
+
+    #[test]
+    fn shortcuts_need_a_modifier_unless_they_are_function_keys() {
+        assert!(!is_bindable(&shortcut(Modifiers::NONE, Key::A)));
+        assert!(!is_bindable(&shortcut(Modifiers::SHIFT, Key::A)));
+        assert!(is_bindable(&shortcut(Modifiers::ALT, Key::A)));
+        assert!(is_bindable(&shortcut(Modifiers::COMMAND, Key::A)));
+        assert!(is_bindable(&shortcut(Modifiers::NONE, Key::F5)));
+        assert!(is_bindable(&shortcut(Modifiers::SHIFT, Key::F12)));
+    }
+}
@@ -0,0 +1,251 @@
+// Veri sunucusu oturumları. Her bağlantının (sunucu temel adresinin) kendi belirteci vardır ve
+// o sunucuya giden her isteğe `Authorization: Bearer <belirteç>` olarak eklenir. Sunucu 401
+// döndürürse istek başarısız sayılmaz: uygulama oturum açma penceresini açar, istek pencere
+// kapanana kadar bekler ve giriş yapılırsa yeni belirteçle yeniden gönderilir.
+// Durum uygulama geneldir; arka plan iş parçacıkları da aynı belirteçleri okur.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, MutexGuard};
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use super::{http, FetchError, LOGIN_ENDPOINT, LOGOUT_ENDPOINT};
+use crate::app::AppSettings;
+use crate::task::Task;
+
+#[cfg(target_arch = "wasm32")]
+use {std::cell::RefCell, wasm_bindgen_futures::JsFuture};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Login {
+    pub token: String,
+    // Kullanıcı adı ve parolayla girildiyse kullanıcı adı; API belirteciyle girildiyse boş.
+    #[serde(default)]
+    pub user: Option<String>,
+    // false ise belirteç yalnızca bu oturumda tutulur ve uygulama durumuyla kaydedilmez.
+    #[serde(default)]
+    pub remember: bool,
+}
+
+// Oturum açılmasını bekleyen bağlantı. `rejected` ise elde bir belirteç vardı ama sunucu onu reddetti.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Prompt {
+    pub server: String,
+    pub rejected: bool,
+}
+
+struct AuthState {
+    logins: BTreeMap<String, Login>,
+    prompt: Option<Prompt>,
+    // Oturum açma penceresi kapatılan bağlantılar; buralarda 401 alan istekler beklemeden başarısız olur,
+    // böylece kendiliğinden yenilenen görünümler pencereyi tekrar tekrar açmaz.
+    dismissed: BTreeSet<String>,
+    // Her giriş ve çıkışta artar; değişiklik akışı bununla yeni belirteçle yeniden bağlanır.
+    generation: u64,
+}
+
+static STATE: Mutex<AuthState> = Mutex::new(AuthState {
+    logins: BTreeMap::new(),
+    prompt: None,
+    dismissed: BTreeSet::new(),
+    generation: 0,
+});
+
+// Oturum durumu değişince oturum açmayı bekleyen istekler uyandırılır.
+#[cfg(not(target_arch = "wasm32"))]
+static CHANGED: std::sync::Condvar = std::sync::Condvar::new();
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static WAITERS: RefCell<Vec<js_sys::Function>> = const { RefCell::new(Vec::new()) };
+}
+
+fn state() -> MutexGuard<'static, AuthState> {
+    STATE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn notify() {
+    #[cfg(not(target_arch = "wasm32"))]
+    CHANGED.notify_all();
+
+    #[cfg(target_arch = "wasm32")]
+    for resolve in WAITERS.with(|waiters| std::mem::take(&mut *waiters.borrow_mut())) {
+        let _ = resolve.call0(&eframe::wasm_bindgen::JsValue::NULL);
+    }
+}
+
+// Belirteçlerin hangi bağlantıya ait olduğu; sondaki eğik çizgi ve boşluklar aynı sunucuyu değiştirmez.
+pub fn server_key(settings: &AppSettings) -> String {
+    settings.base_url().trim().trim_end_matches('/').to_string()
+}
+
+pub fn token(server: &str) -> Option<String> {
+    state().logins.get(server).map(|login| login.token.clone())
+}
+
+pub fn login(server: &str) -> Option<Login> {
+    state().logins.get(server).cloned()
+}
+
+pub fn generation() -> u64 {
+    state().generation
+}
+
+pub fn sign_in(server: &str, login: Login) {
+    let mut state = state();
+    state.logins.insert(server.to_string(), login);
+    state.dismissed.remove(server);
+    if state.prompt.as_ref().is_some_and(|prompt| prompt.server == server) {
+        state.prompt = None;
+    }
+    state.generation += 1;
+    drop(state);
+    notify();
+}
+
+// Oturum uygulamada hemen kapanır; belirteç POST /logout ile sunucuda da geçersiz kılınır.
+// Yanıt beklenmez: sunucuya ulaşılamazsa belirtecin süresi sunucuda kendiliğinden dolar.
+pub fn sign_out(ctx: &egui::Context, settings: &AppSettings, server: &str) {
+    let mut state = state();
+    let removed = state.logins.remove(server);
+    if removed.is_some() {
+        state.generation += 1;
+    }
+    drop(state);
+    notify();
+    let Some(login) = removed else {
+        return;
+    };
+    let url = server_endpoint_url(settings, server, LOGOUT_ENDPOINT);
+    let body = serde_json::json!({});
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = Task::spawn_blocking(ctx, move || http::post_json(&url, &body, Some(&login.token)));
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = Task::spawn_async(ctx, async move { http::post_json(url, body, Some(login.token)).await });
+    }
+}
+
+// Açılması gereken oturum açma penceresi; her karede sorulur.
+pub fn prompt() -> Option<Prompt> {
+    state().prompt.clone()
+}
+
+// Oturum açma penceresi girişsiz kapatıldı; bekleyen istekler 401 hatasıyla sonuçlanır.
+pub fn dismiss(server: &str) {
+    let mut state = state();
+    state.dismissed.insert(server.to_string());
+    if state.prompt.as_ref().is_some_and(|prompt| prompt.server == server) {
+        state.prompt = None;
+    }
+    drop(state);
+    notify();
+}
+
+// Hata panelinde "Sign in" tıklandığında: sonraki 401 yeniden pencere açar.
+pub fn allow_prompts() {
+    state().dismissed.clear();
+}
+
+// Uygulama durumuyla kaydedilecek ("Remember me" seçilmiş) oturumlar.
+pub fn remembered() -> BTreeMap<String, Login> {
+    state()
+        .logins
+        .iter()
+        .filter(|(_, login)| login.remember)
+        .map(|(server, login)| (server.clone(), login.clone()))
+        .collect()
+}
+
+// Kaydedilmiş oturumları açılışta geri yükler.
+pub fn restore(logins: BTreeMap<String, Login>) {
+    let mut state = state();
+    state.logins.extend(logins);
+    state.generation += 1;
+}
+
+// `failed` belirteciyle 401 alan bir istek için: kullanılacak yeni belirteç, isteğin hatayla
+// bitmesi gerekiyorsa Some(None), oturum açılması bekleniyorsa None.
+fn check(state: &mut AuthState, server: &str, failed: Option<&str>) -> Option<Option<String>> {
+    if let Some(login) = state.logins.get(server) {
+        // Başka bir istek beklerken oturum açılmış olabilir.
+        if Some(login.token.as_str()) != failed {
+            return Some(Some(login.token.clone()));
+        }
+    }
+    if state.dismissed.contains(server) {
+        return Some(None);
+    }
+    state.prompt = Some(Prompt { server: server.to_string(), rejected: failed.is_some() });
+    None
+}
+
+// Arka plan iş parçacığında çalışır: oturum açılana ya da pencere kapatılana kadar bekler.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn wait_for_login(ctx: &egui::Context, server: &str, failed: Option<&str>) -> Option<String> {
+    let mut state = state();
+    loop {
+        if let Some(token) = check(&mut state, server, failed) {
+            return token;
+        }
+        ctx.request_repaint();
+        state = CHANGED.wait(state).unwrap_or_else(|e| e.into_inner());
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn wait_for_login(ctx: &egui::Context, server: &str, failed: Option<&str>) -> Option<String> {
+    loop {
+        let checked = check(&mut state(), server, failed);
+        if let Some(token) = checked {
+            return token;
+        }
+        ctx.request_repaint();
+        let changed = js_sys::Promise::new(&mut |resolve, _| WAITERS.with(|waiters| waiters.borrow_mut().push(resolve)));
+        let _ = JsFuture::from(changed).await;
+    }
+}
+
+// Oturum isteklerinin adresi diğer istekler gibi endpoint_url ile kurulur; ayarlardaki adres
+// sonradan değiştiyse de oturumun ait olduğu `server` bağlantısına gidilir.
+fn server_endpoint_url(settings: &AppSettings, server: &str, endpoint: &str) -> String {
+    AppSettings { server_url_override: Some(server.to_string()), ..settings.clone() }.endpoint_url(endpoint)
+}
+
+// Kullanıcı adı ve parolayı `server` bağlantısında POST /login ile bir belirteçle değiştirir.
+// Sunucu {"token": "..."} döndürür; yanlış bilgilerde 401 gelir.
+pub fn request_token(
+    ctx: &egui::Context,
+    settings: &AppSettings,
+    server: &str,
+    username: &str,
+    password: &str,
+) -> Task<Result<String, FetchError>> {
+    let url = server_endpoint_url(settings, server, LOGIN_ENDPOINT);
+    let body = serde_json::json!({ "username": username, "password": password });
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Task::spawn_blocking(ctx, move || http::post_json(&url, &body, None).and_then(|text| parse_token(&text)))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        Task::spawn_async(ctx, async move { http::post_json(url, body, None).await.and_then(|text| parse_token(&text)) })
+    }
+}
+
+fn parse_token(text: &str) -> Result<String, FetchError> {
+    let value: serde_json::Value = serde_json::from_str(text)
+        .map_err(|e| FetchError::InvalidJson { message: format!("Failed to parse login response: {}", e) })?;
+    value
+        .get("token")
+        .and_then(|token| token.as_str())
+        .map(String::from)
+        .ok_or_else(|| FetchError::SchemaMismatch { message: "The login response has no \"token\" field".to_string() })
+}
@@ -0,0 +1,629 @@
+// Sunucunun değişiklik akışı: GET /changes (server/index.ts) Server-Sent Events ile her
+// satır eklemesini, güncellemesini ve silmesini `data: {"table", "op", "key", "row"}` olarak gönderir.
+// Native tarafta arka plan iş parçacığı akışı reqwest ile satır satır okur; WASM tarafında
+// tarayıcının EventSource'u kullanılır. Bağlantı koparsa artan bekleme süreleriyle yeniden bağlanılır.
+// Oturum belirteci native tarafta Authorization başlığıyla gönderilir; EventSource başlık
+// eklemeye izin vermediği için tarayıcıda `?access_token=` sorgu parametresi kullanılır.
+// Sunucu 401 döndürürse diğer istekler gibi oturum açılması beklenir (bkz. auth.rs).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::Duration;
+
+use eframe::egui;
+use serde::Deserialize;
+
+use super::{auth, CHANGES_ENDPOINT};
+use crate::app::AppSettings;
+use crate::i18n::{t, t_with};
+
+#[cfg(target_arch = "wasm32")]
+use {
+    eframe::wasm_bindgen::{closure::Closure, JsCast},
+    std::{cell::RefCell, rc::Rc},
+    wasm_bindgen_futures::JsFuture,
+};
+
+// Yeniden bağlanma beklemesi 1, 2, 4, ... saniye olarak artar ve burada durur.
+const MAX_BACKOFF_SECS: u64 = 30;
+
+// Sunucu 5 saniyede bir canlılık yoklaması (": ping") gönderir. Bu süre boyunca hiçbir satır
+// gelmezse bağlantı yarı açık kalmış (ör. ağ değişmiş) sayılır ve yeniden bağlanılır.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeOp {
+    Insert,
+    Update,
+    Delete,
+}
+
+// Bir tablodaki tek satırın değişikliği. `row` ekleme ve güncellemede satırın yeni hâlidir;
+// silmede boştur.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChangeEvent {
+    pub table: String,
+    pub op: ChangeOp,
+    pub key: i64,
+    #[serde(default)]
+    pub row: serde_json::Map<String, serde_json::Value>,
+}
+
+// Bir karede gelen değişiklikler. `missed` ise bağlantı bir süre kopmuştur; aradaki
+// değişiklikler kaçırılmış olabileceğinden görünümler verilerini yeniden yüklemelidir.
+#[derive(Default)]
+pub struct ChangeBatch {
+    pub events: Vec<ChangeEvent>,
+    pub missed: bool,
+}
+
+impl ChangeBatch {
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty() && !self.missed
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum FeedState {
+    // Canlı güncellemeler ayarlardan kapatılmış.
+    #[default]
+    Off,
+    Connecting,
+    Connected,
+    // Bağlantı kurulamadı ya da koptu; `attempt`. deneme `delay_secs` saniye sonra yapılır.
+    Reconnecting { attempt: u32, delay_secs: u64, reason: FeedError },
+}
+
+// Bağlantının neden koptuğu. Arka plan iş parçacığından gönderilir; dil ayarı arayüz iş
+// parçacığına yerel olduğu için metne `status_ui` içinde çevrilir.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedError {
+    // Sunucu akışı kapattı.
+    Closed,
+    // IDLE_TIMEOUT boyunca hiçbir satır gelmedi.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))] // Tarayıcıda EventSource kendisi izler
+    TimedOut,
+    // Sunucu 401 döndürdü ve oturum açılmadı.
+    Unauthorized,
+    // Bağlanılamadı ya da sunucu hata döndürdü; bağlantı katmanının (İngilizce) iletisi.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    Failed(String),
+}
+
+impl FeedError {
+    fn message(&self) -> String {
+        match self {
+            FeedError::Closed => t("feed-closed"),
+            FeedError::TimedOut => t_with("feed-timed-out", &[("seconds", IDLE_TIMEOUT.as_secs().into())]),
+            FeedError::Unauthorized => t("feed-unauthorized"),
+            FeedError::Failed(message) => message.clone(),
+        }
+    }
+}
+
+enum FeedMessage {
+    State(FeedState),
+    Event(ChangeEvent),
+}
+
+// Uygulama genelinde tek bir abonelik; adres ya da ayar değişince yeniden kurulur.
+#[derive(Default)]
+pub struct ChangeFeed {
+    url: Option<String>,
+    // Bağlanırken geçerli olan auth::generation(); oturum açılınca ya da kapanınca yeniden bağlanılır.
+    auth_generation: u64,
+    state: FeedState,
+    receiver: Option<Receiver<FeedMessage>>,
+    stop: Arc<AtomicBool>,
+    // Bağlantı kurulamadıysa ya da koptuysa true; kurulunca görünümlere kaçırılmış olabilecek
+    // değişiklikler bildirilir.
+    lost: bool,
+    // Kapatırken bekleyen EventSource hemen kapatılabilsin diye.
+    #[cfg(target_arch = "wasm32")]
+    current: Rc<RefCell<Option<web_sys::EventSource>>>,
+}
+
+impl Drop for ChangeFeed {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+impl ChangeFeed {
+    // Her karede çağrılır; ayarlara göre aboneliği başlatır, durdurur ya da yeni adrese taşır.
+    pub fn ensure(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        let url = settings.live_updates.then(|| settings.endpoint_url(CHANGES_ENDPOINT));
+        let auth_generation = auth::generation();
+        if url == self.url && (url.is_none() || auth_generation == self.auth_generation) {
+            return;
+        }
+        // Sunucu adresi yazılırken her tuşta yeniden bağlanılmaz; yazma bitince bağlanılır.
+        if self.url.is_some() && url.is_some() && ctx.wants_keyboard_input() {
+            return;
+        }
+        self.disconnect();
+        self.url = url.clone();
+        self.auth_generation = auth_generation;
+        let Some(url) = url else {
+            self.state = FeedState::Off;
+            return;
+        };
+        let (sender, receiver) = mpsc::channel();
+        self.receiver = Some(receiver);
+        self.stop = Arc::new(AtomicBool::new(false));
+        self.state = FeedState::Connecting;
+        self.lost = false;
+        let server = auth::server_key(settings);
+        let token = auth::token(&server);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let stop = self.stop.clone();
+            let ctx = ctx.clone();
+            std::thread::spawn(move || run_blocking(&ctx, &url, &server, token, &sender, &stop));
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let stop = self.stop.clone();
+            let current = self.current.clone();
+            let ctx = ctx.clone();
+            wasm_bindgen_futures::spawn_local(async move { run_web(&ctx, &url, &server, token, &sender, &stop, &current).await });
+        }
+    }
+
+    fn disconnect(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        self.receiver = None;
+        #[cfg(target_arch = "wasm32")]
+        if let Some(source) = self.current.borrow_mut().take() {
+            source.close();
+            // Bağlantının kopmasını bekleyen döngü uyansın ve durduğunu görsün.
+            if let Ok(event) = web_sys::Event::new("error") {
+                let _ = source.dispatch_event(&event);
+            }
+        }
+    }
+
+    // Son kareden bu yana gelen değişiklikleri döndürür.
+    pub fn poll(&mut self) -> ChangeBatch {
+        let mut batch = ChangeBatch::default();
+        let Some(receiver) = &self.receiver else {
+            return batch;
+        };
+        while let Ok(message) = receiver.try_recv() {
+            match message {
+                FeedMessage::Event(event) => batch.events.push(event),
+                FeedMessage::State(state) => {
+                    match state {
+                        FeedState::Connected => batch.missed |= std::mem::take(&mut self.lost),
+                        FeedState::Reconnecting { .. } => self.lost = true,
+                        _ => {}
+                    }
+                    self.state = state;
+                }
+            }
+        }
+        batch
+    }
+
+    // Üst çubuktaki bağlantı göstergesi; canlı güncellemeler kapalıysa hiçbir şey çizmez.
+    pub fn status_ui(&self, ui: &mut egui::Ui) {
+        let url = self.url.clone().unwrap_or_default();
+        let (color, text, hover) = match &self.state {
+            FeedState::Off => return,
+            FeedState::Connecting => (ui.visuals().weak_text_color(), t("feed-connecting"), t_with("feed-connecting-hint", &[("url", url.into())])),
+            FeedState::Connected => (egui::Color32::from_rgb(100, 200, 100), t("feed-live"), t_with("feed-live-hint", &[("url", url.into())])),
+            FeedState::Reconnecting { attempt, delay_secs, reason } => (
+                ui.visuals().warn_fg_color,
+                t_with("feed-reconnecting", &[("attempt", (*attempt).into())]),
+                t_with(
+                    "feed-reconnecting-hint",
+                    &[("url", url.into()), ("seconds", (*delay_secs).into()), ("message", reason.message().into())],
+                ),
+            ),
+        };
+        ui.label(egui::RichText::new(text).color(color)).on_hover_text(hover);
+    }
+}
+
+fn backoff_secs(attempt: u32) -> u64 {
+    (1u64 << attempt.saturating_sub(1).min(6)).min(MAX_BACKOFF_SECS)
+}
+
+fn parse_event(data: &str) -> Option<ChangeEvent> {
+    serde_json::from_str(data).ok()
+}
+
+// Server-Sent Events satırlarını olaylara çevirir: `data:` satırları birleştirilir, boş satır
+// olayı bitirir; `:` ile başlayan satırlar (sunucunun canlılık yoklamaları) yok sayılır.
+// Satır sonu CRLF de olabilir.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct EventParser {
+    data: Vec<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl EventParser {
+    fn push(&mut self, line: &str) -> Option<String> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.is_empty() {
+            return (!self.data.is_empty()).then(|| std::mem::take(&mut self.data).join("\n"));
+        }
+        if let Some(data) = line.strip_prefix("data:") {
+            self.data.push(data.strip_prefix(' ').unwrap_or(data).to_string());
+        }
+        None
+    }
+}
+
+// Arka plan iş parçacığında çalışır; `stop` işaretlenene kadar yeniden bağlanır.
+#[cfg(not(target_arch = "wasm32"))]
+fn run_blocking(ctx: &egui::Context, url: &str, server: &str, mut token: Option<String>, sender: &Sender<FeedMessage>, stop: &AtomicBool) {
+    let send = |message: FeedMessage| {
+        let _ = sender.send(message);
+        ctx.request_repaint();
+    };
+    let mut attempt = 0;
+    while !stop.load(Ordering::Relaxed) {
+        let reason = match stream_blocking(url, token.as_deref(), IDLE_TIMEOUT, stop, &send, &mut attempt) {
+            Ok(()) => return,
+            // Oturum açılana ya da pencere kapatılana kadar beklenir; giriş yapılınca yeni
+            // belirteçle hemen yeniden bağlanılır.
+            Err(FeedError::Unauthorized) => match auth::wait_for_login(ctx, server, token.as_deref()) {
+                Some(new_token) => {
+                    token = Some(new_token);
+                    continue;
+                }
+                None => FeedError::Unauthorized,
+            },
+            Err(reason) => reason,
+        };
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        attempt += 1;
+        let delay_secs = backoff_secs(attempt);
+        send(FeedMessage::State(FeedState::Reconnecting { attempt, delay_secs, reason }));
+        // Durdurma isteği beklemenin sonunu beklemesin diye kısa adımlarla uyunur.
+        let deadline = web_time::Instant::now() + Duration::from_secs(delay_secs);
+        while web_time::Instant::now() < deadline {
+            if stop.load(Ordering::Relaxed) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        send(FeedMessage::State(FeedState::Connecting));
+    }
+}
+
+// Akış sürdükçe döner; yalnızca durdurulunca Ok, bağlantı kopunca nedeniyle Err döndürür.
+#[cfg(not(target_arch = "wasm32"))]
+fn stream_blocking(
+    url: &str,
+    token: Option<&str>,
+    idle_timeout: Duration,
+    stop: &AtomicBool,
+    send: &impl Fn(FeedMessage),
+    attempt: &mut u32,
+) -> Result<(), FeedError> {
+    // Blocking istemcide zaman aşımı isteğin toplam süresine değil, yanıt başlıklarının ve
+    // gövdeden yapılan her okumanın beklenmesine uygulanır; akışta boşta kalma süresi olur.
+    let client = reqwest::blocking::Client::builder()
+        .connect_timeout(Duration::from_secs(10))
+        .timeout(idle_timeout)
+        .build()
+        .map_err(|e| FeedError::Failed(e.to_string()))?;
+    let mut request = client.get(url).header("Accept", "text/event-stream");
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().map_err(|e| FeedError::Failed(e.to_string()))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(FeedError::Unauthorized);
+    }
+    if !status.is_success() {
+        let error = super::FetchError::from_status(url, status.as_u16(), &response.text().unwrap_or_default());
+        return Err(FeedError::Failed(error.to_string()));
+    }
+    *attempt = 0;
+    send(FeedMessage::State(FeedState::Connected));
+    read_events(std::io::BufReader::new(response), stop, send)
+}
+
+// Akışı satır satır okuyup olayları gönderir. Bir olay birden fazla okumaya bölünerek
+// gelebilir; satırları BufRead birleştirir.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_events(reader: impl std::io::BufRead, stop: &AtomicBool, send: &impl Fn(FeedMessage)) -> Result<(), FeedError> {
+    let mut parser = EventParser::default();
+    for line in reader.lines() {
+        if stop.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let line = line.map_err(|e| if is_timeout(&e) { FeedError::TimedOut } else { FeedError::Closed })?;
+        if let Some(event) = parser.push(&line).as_deref().and_then(parse_event) {
+            send(FeedMessage::Event(event));
+        }
+    }
+    Err(FeedError::Closed)
+}
+
+// reqwest okuma zaman aşımını io::Error içine sarılmış kendi hatasıyla bildirir.
+#[cfg(not(target_arch = "wasm32"))]
+fn is_timeout(error: &std::io::Error) -> bool {
+    error.kind() == std::io::ErrorKind::TimedOut
+        || error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<reqwest::Error>())
+            .is_some_and(reqwest::Error::is_timeout)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn with_token(url: &str, token: Option<&str>) -> String {
+    match token {
+        Some(token) => format!("{}?access_token={}", url, form_urlencoded::byte_serialize(token.as_bytes()).collect::<String>()),
+        None => url.to_string(),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn run_web(
+    ctx: &egui::Context,
+    url: &str,
+    server: &str,
+    mut token: Option<String>,
+    sender: &Sender<FeedMessage>,
+    stop: &AtomicBool,
+    current: &Rc<RefCell<Option<web_sys::EventSource>>>,
+) {
+    let send = |message: FeedMessage| {
+        let _ = sender.send(message);
+        ctx.request_repaint();
+    };
+    let mut attempt = 0;
+    while !stop.load(Ordering::Relaxed) {
+        let source_url = with_token(url, token.as_deref());
+        let opened = stream_web(ctx, &source_url, sender, current).await;
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        // EventSource yanıtın durum kodunu göstermez; bağlantı hiç açılamadıysa ayrıca sorulur.
+        let reason = if !opened && status_web(&source_url).await == Some(401) {
+            match auth::wait_for_login(ctx, server, token.as_deref()).await {
+                Some(new_token) => {
+                    token = Some(new_token);
+                    continue;
+                }
+                None => FeedError::Unauthorized,
+            }
+        } else {
+            FeedError::Closed
+        };
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        attempt = if opened { 1 } else { attempt + 1 };
+        let delay_secs = backoff_secs(attempt);
+        send(FeedMessage::State(FeedState::Reconnecting { attempt, delay_secs, reason }));
+        let sleep = js_sys::Promise::new(&mut |resolve, _| {
+            if let Some(window) = web_sys::window() {
+                let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, (delay_secs * 1000) as i32);
+            }
+        });
+        let _ = JsFuture::from(sleep).await;
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        send(FeedMessage::State(FeedState::Connecting));
+    }
+}
+
+// Bir EventSource açar ve kopana kadar bekler. Tarayıcının kendi yeniden bağlanması yerine
+// bağlantı kapatılır; böylece bekleme süresi ve durum göstergesi tek yerden yönetilir.
+// Bağlantının bir kez açılıp açılmadığını döndürür.
+#[cfg(target_arch = "wasm32")]
+async fn stream_web(
+    ctx: &egui::Context,
+    url: &str,
+    sender: &Sender<FeedMessage>,
+    current: &Rc<RefCell<Option<web_sys::EventSource>>>,
+) -> bool {
+    let Ok(source) = web_sys::EventSource::new(url) else {
+        return false;
+    };
+    let opened = Rc::new(std::cell::Cell::new(false));
+
+    let on_open = {
+        let (sender, ctx, opened) = (sender.clone(), ctx.clone(), opened.clone());
+        Closure::<dyn FnMut()>::new(move || {
+            opened.set(true);
+            let _ = sender.send(FeedMessage::State(FeedState::Connected));
+            ctx.request_repaint();
+        })
+    };
+    let on_message = {
+        let (sender, ctx) = (sender.clone(), ctx.clone());
+        Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |message: web_sys::MessageEvent| {
+            if let Some(event) = message.data().as_string().as_deref().and_then(parse_event) {
+                let _ = sender.send(FeedMessage::Event(event));
+                ctx.request_repaint();
+            }
+        })
+    };
+    source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    let failed = js_sys::Promise::new(&mut |resolve, _| source.set_onerror(Some(&resolve)));
+    *current.borrow_mut() = Some(source.clone());
+
+    let _ = JsFuture::from(failed).await;
+    source.close();
+    current.borrow_mut().take();
+    opened.get()
+}
+
+// Akış adresini fetch ile ister ve yalnızca durum kodunu okur; başarılı yanıtın süresiz
+// akan gövdesi beklenmeden istek iptal edilir. Sunucuya ulaşılamazsa None döner.
+#[cfg(target_arch = "wasm32")]
+async fn status_web(url: &str) -> Option<u16> {
+    let controller = web_sys::AbortController::new().ok()?;
+    let opts = web_sys::RequestInit::new();
+    opts.set_method("GET");
+    opts.set_signal(Some(&controller.signal()));
+    let response = JsFuture::from(web_sys::window()?.fetch_with_str_and_init(url, &opts)).await.ok();
+    controller.abort();
+    response?.dyn_into::<web_sys::Response>().ok().map(|response| response.status())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io::{BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    fn event_json(key: i64) -> String {
+        format!(r#"{{"table": "Customers", "op": "update", "key": {}, "row": {{"CustomerName": "Ada"}}}}"#, key)
+    }
+
+    #[test]
+    fn parser_joins_multi_line_data() {
+        let mut parser = EventParser::default();
+        assert_eq!(parser.push("data: first"), None);
+        assert_eq!(parser.push("data:second"), None);
+        assert_eq!(parser.push("data:  indented"), None);
+        assert_eq!(parser.push("").as_deref(), Some("first\nsecond\n indented"));
+        // Olay bitince tampon boşalır.
+        assert_eq!(parser.push(""), None);
+    }
+
+    #[test]
+    fn parser_ignores_comments_and_other_fields() {
+        let mut parser = EventParser::default();
+        assert_eq!(parser.push(": ping"), None);
+        assert_eq!(parser.push(""), None);
+        for line in ["event: change", "id: 7", "retry: 1000", ": ping", "data: x"] {
+            assert_eq!(parser.push(line), None);
+        }
+        assert_eq!(parser.push("").as_deref(), Some("x"));
+    }
+
+    #[test]
+    fn parser_accepts_crlf() {
+        let mut parser = EventParser::default();
+        assert_eq!(parser.push("data: a\r"), None);
+        assert_eq!(parser.push("data: b\r"), None);
+        assert_eq!(parser.push("\r").as_deref(), Some("a\nb"));
+    }
+
+    // Okumaları birkaç baytlık parçalara böler.
+    struct Chunked<'a> {
+        bytes: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.chunk.min(buf.len()).min(self.bytes.len());
+            buf[..n].copy_from_slice(&self.bytes[..n]);
+            self.bytes = &self.bytes[n..];
+            Ok(n)
+        }
+    }
+
+    fn collect_keys(messages: &RefCell<Vec<FeedMessage>>) -> Vec<i64> {
+        messages
+            .borrow()
+            .iter()
+            .filter_map(|message| match message {
+                FeedMessage::Event(event) => Some(event.key),
+                FeedMessage::State(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn events_split_across_reads_are_reassembled() {
+        let stream = format!("data: {}\r\n: ping\r\n\r\ndata: {}\n\nevent: x\ndata: not json\n\n", event_json(1), event_json(2));
+        for chunk in [1, 3, 7, 64] {
+            let messages = RefCell::new(Vec::new());
+            let reader = BufReader::with_capacity(4, Chunked { bytes: stream.as_bytes(), chunk });
+            let result = read_events(reader, &AtomicBool::new(false), &|message| messages.borrow_mut().push(message));
+            assert_eq!(result, Err(FeedError::Closed));
+            assert_eq!(collect_keys(&messages), vec![1, 2], "chunk size {}", chunk);
+        }
+    }
+
+    #[test]
+    fn stopped_reader_returns_ok() {
+        let stream = format!("data: {}\n\n", event_json(1));
+        let reader = BufReader::new(stream.as_bytes());
+        assert_eq!(read_events(reader, &AtomicBool::new(true), &|_| {}), Ok(()));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_limit() {
+        let delays: Vec<u64> = (0..=8).map(backoff_secs).collect();
+        assert_eq!(delays, vec![1, 1, 2, 4, 8, 16, 30, 30, 30]);
+        assert_eq!(backoff_secs(u32::MAX), MAX_BACKOFF_SECS);
+    }
+
+    // Tek bir bağlantıyı kabul eden, isteği okuyup `response` yazan ve `hold` boyunca bağlantıyı
+    // açık tutan sunucu; adresini döndürür.
+    fn serve_once(response: String, hold: Duration) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/changes", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut byte = [0u8; 1];
+            while !request.ends_with(b"\r\n\r\n") && stream.read(&mut byte).unwrap_or(0) == 1 {
+                request.push(byte[0]);
+            }
+            let _ = stream.write_all(response.as_bytes());
+            let _ = stream.flush();
+            std::thread::sleep(hold);
+        });
+        url
+    }
+
+    fn stream_once(url: &str, idle_timeout: Duration) -> (Result<(), FeedError>, Vec<FeedMessage>) {
+        let messages = RefCell::new(Vec::new());
+        let mut attempt = 3;
+        let result = stream_blocking(url, Some("secret"), idle_timeout, &AtomicBool::new(false), &|message| messages.borrow_mut().push(message), &mut attempt);
+        (result, messages.into_inner())
+    }
+
+    #[test]
+    fn silent_connection_times_out() {
+        let body = format!("data: {}\n\n", event_json(5));
+        let url = serve_once(format!("HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\n\r\n{}", body), Duration::from_secs(5));
+        let started = std::time::Instant::now();
+        let (result, messages) = stream_once(&url, Duration::from_millis(300));
+        assert_eq!(result, Err(FeedError::TimedOut));
+        assert!(started.elapsed() < Duration::from_secs(4));
+        assert!(matches!(messages.first(), Some(FeedMessage::State(FeedState::Connected))));
+        assert_eq!(collect_keys(&RefCell::new(messages)), vec![5]);
+    }
+
+    #[test]
+    fn status_401_is_unauthorized() {
+        let url = serve_once("HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n".to_string(), Duration::ZERO);
+        let (result, messages) = stream_once(&url, Duration::from_secs(5));
+        assert_eq!(result, Err(FeedError::Unauthorized));
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn other_error_statuses_keep_the_server_message() {
+        let body = r#"{"error": "Change feed is disabled"}"#;
+        let response = format!("HTTP/1.1 503 Service Unavailable\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+        let url = serve_once(response, Duration::ZERO);
+        let (result, _) = stream_once(&url, Duration::from_secs(5));
+        assert!(matches!(result, Err(FeedError::Failed(message)) if message.contains("Change feed is disabled")));
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+
+// Veri kaynağından veri çekerken oluşabilecek hatalar.
+// Görünümler bu türü doğrudan saklar; hata paneli başlığı, açıklamayı ve
+// sunucunun gönderdiği ayrıntıyı ayrı ayrı gösterebilir.
+#[derive(Clone, Debug)]
+pub enum FetchError {
+    // Sunucuya bağlanılamadı (sunucu çalışmıyor, adres yanlış, CORS reddi...).
+    ConnectionRefused { url: String, message: String },
+    // Sunucu zamanında yanıt vermedi.
+    Timeout { url: String },
+    // Sunucu başarısız bir durum kodu döndürdü. server/index.ts hata durumunda
+    // {"error": "...", "details": "..."} gövdesi gönderir; varsa bunlar saklanır.
+    // Doğrulama hatalarında gövde ayrıca {"fields": {"Sütun": "ileti"}} içerebilir.
+    HttpStatus { url: String, status: u16, error: Option<String>, details: Option<String>, fields: Vec<(String, String)> },
+    // Sunucu 401 döndürdü: belirteç yok, süresi dolmuş ya da reddedilmiş ve oturum açılmadı.
+    Unauthorized { url: String, error: Option<String> },
+    // Yanıt geçerli bir JSON değil.
+    InvalidJson { message: String },
+    // Yanıt JSON ama beklenen biçimde değil (eksik sütunlar, yanlış tür...).
+    SchemaMismatch { message: String },
+    // Yerel SQLite dosyası açılamadı ya da sorgu başarısız oldu.
+    #[cfg_attr(not(all(feature = "local-sqlite", not(target_arch = "wasm32"))), allow(dead_code))] // Yalnızca yerel kaynak üretir
+    Database { message: String },
+    // İstek hiç gönderilemedi (geçersiz adres, desteklenmeyen kaynak...).
+    Request { message: String },
+}
+
+impl FetchError {
+    pub fn title(&self) -> &'static str {
+        match self {
+            FetchError::ConnectionRefused { .. } => "Cannot connect to the data server",
+            FetchError::Timeout { .. } => "The data server did not respond in time",
+            FetchError::HttpStatus { .. } => "The data server returned an error",
+            FetchError::Unauthorized { .. } => "Sign-in required",
+            FetchError::InvalidJson { .. } => "The response is not valid JSON",
+            FetchError::SchemaMismatch { .. } => "The response has an unexpected shape",
+            FetchError::Database { .. } => "Database error",
+            FetchError::Request { .. } => "The request could not be sent",
+        }
+    }
+
+    // Hata panelindeki başlığın katalog anahtarı; tanılama metni İngilizce `title` ile kalır.
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            FetchError::ConnectionRefused { .. } => "error-connection-refused",
+            FetchError::Timeout { .. } => "error-timeout",
+            FetchError::HttpStatus { .. } => "error-http-status",
+            FetchError::Unauthorized { .. } => "error-unauthorized",
+            FetchError::InvalidJson { .. } => "error-invalid-json",
+            FetchError::SchemaMismatch { .. } => "error-schema-mismatch",
+            FetchError::Database { .. } => "error-database",
+            FetchError::Request { .. } => "error-request",
+        }
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(self, FetchError::Unauthorized { .. })
+    }
+
+    // Sunucunun hata gövdesindeki `details` alanı (ör. SQLite hata iletisi).
+    pub fn server_details(&self) -> Option<&str> {
+        match self {
+            FetchError::HttpStatus { details, .. } => details.as_deref(),
+            _ => None,
+        }
+    }
+
+    // Sunucunun sütun bazında bildirdiği doğrulama hataları (sütun adı, ileti).
+    pub fn field_errors(&self) -> &[(String, String)] {
+        match self {
+            FetchError::HttpStatus { fields, .. } => fields,
+            _ => &[],
+        }
+    }
+
+    // Hata bildirimine yapıştırılabilecek, kopyalanabilir tanılama metni.
+    pub fn diagnostics(&self, source: &str) -> String {
+        let mut lines = vec![
+            format!("error: {}", self.title()),
+            format!("message: {}", self),
+            format!("source: {}", source),
+        ];
+        if let FetchError::Unauthorized { error, .. } = self {
+            lines.push("status: 401".to_string());
+            if let Some(error) = error {
+                lines.push(format!("server error: {}", error));
+            }
+        }
+        if let FetchError::HttpStatus { status, error, details, .. } = self {
+            lines.push(format!("status: {}", status));
+            if let Some(error) = error {
+                lines.push(format!("server error: {}", error));
+            }
+            if let Some(details) = details {
+                lines.push(format!("server details: {}", details));
+            }
+        }
+        lines.push(format!("app version: {}", env!("CARGO_PKG_VERSION")));
+        lines.push(format!("target: {}", std::env::consts::ARCH));
+        lines.join("\n")
+    }
+
+    // Başarısız bir HTTP yanıtının gövdesinden {error, details, fields} alanlarını okur.
+    // 401 yanıtları Unauthorized olur; istek oturum açıldıktan sonra yeniden gönderilebilir.
+    pub fn from_status(url: &str, status: u16, body: &str) -> Self {
+        let parsed = serde_json::from_str::<serde_json::Value>(body).ok();
+        let field = |name: &str| {
+            parsed
+                .as_ref()
+                .and_then(|value| value.get(name))
+                .and_then(|value| value.as_str())
+                .map(String::from)
+        };
+        if status == 401 {
+            return FetchError::Unauthorized { url: url.to_string(), error: field("error") };
+        }
+        let details = field("details").or_else(|| {
+            // JSON olmayan kısa gövdeler (ör. "Not Found") de yararlı bir ayrıntıdır.
+            let body = body.trim();
+            (parsed.is_none() && !body.is_empty() && body.len() <= 500).then(|| body.to_string())
+        });
+        let fields = parsed
+            .as_ref()
+            .and_then(|value| value.get("fields"))
+            .and_then(|value| value.as_object())
+            .map(|fields| {
+                fields
+                    .iter()
+                    .map(|(column, message)| (column.clone(), message.as_str().map(String::from).unwrap_or_else(|| message.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        FetchError::HttpStatus { url: url.to_string(), status, error: field("error"), details, fields }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::ConnectionRefused { url, message } => write!(f, "Could not connect to {}: {}", url, message),
+            FetchError::Timeout { url } => write!(f, "Request to {} timed out", url),
+            FetchError::HttpStatus { url, status, error, .. } => match error {
+                Some(error) => write!(f, "{} (HTTP {} from {})", error, status, url),
+                None => write!(f, "Request failed with status {} ({})", status, url),
+            },
+            FetchError::Unauthorized { url, error } => match error {
+                Some(error) => write!(f, "{} (HTTP 401 from {})", error, url),
+                None => write!(f, "The server at {} requires signing in (HTTP 401)", url),
+            },
+            FetchError::InvalidJson { message } => write!(f, "{}", message),
+            FetchError::SchemaMismatch { message } => write!(f, "{}", message),
+            FetchError::Database { message } => write!(f, "{}", message),
+            FetchError::Request { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "http://localhost:3000/api/query";
+
+    #[test]
+    fn json_body_fills_error_and_details() {
+        let error = FetchError::from_status(URL, 500, r#"{"error": "Query failed", "details": "no such table: Foo"}"#);
+        match &error {
+            FetchError::HttpStatus { url, status, error, details, fields } => {
+                assert_eq!(url, URL);
+                assert_eq!(*status, 500);
+                assert_eq!(error.as_deref(), Some("Query failed"));
+                assert_eq!(details.as_deref(), Some("no such table: Foo"));
+                assert!(fields.is_empty());
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(error.title_key(), "error-http-status");
+        assert_eq!(error.server_details(), Some("no such table: Foo"));
+        assert_eq!(error.to_string(), format!("Query failed (HTTP 500 from {})", URL));
+    }
+
+    #[test]
+    fn fields_keep_order_and_stringify_non_strings() {
+        let body = r#"{"error": "Validation failed", "fields": {"CustomerName": "is required", "Age": 18}}"#;
+        let error = FetchError::from_status(URL, 400, body);
+        assert_eq!(
+            error.field_errors(),
+            &[("CustomerName".to_string(), "is required".to_string()), ("Age".to_string(), "18".to_string())]
+        );
+        // JSON gövdede `details` yoksa gövdenin kendisi ayrıntı sayılmaz.
+        assert_eq!(error.server_details(), None);
+    }
+
+    #[test]
+    fn short_plain_body_becomes_details() {
+        let error = FetchError::from_status(URL, 404, "  Not Found\n");
+        assert_eq!(error.server_details(), Some("Not Found"));
+        assert_eq!(error.to_string(), format!("Request failed with status 404 ({})", URL));
+    }
+
+    #[test]
+    fn long_or_empty_plain_body_is_ignored() {
+        let long = "x".repeat(501);
+        assert_eq!(FetchError::from_status(URL, 502, &long).server_details(), None);
+        assert_eq!(FetchError::from_status(URL, 502, "").server_details(), None);
+        assert_eq!(FetchError::from_status(URL, 502, " \n ").server_details(), None);
+    }
+
+    #[test]
+    fn status_401_is_unauthorized() {
+        let error = FetchError::from_status(URL, 401, r#"{"error": "Token expired"}"#);
+        assert!(error.is_unauthorized());
+        assert_eq!(error.title_key(), "error-unauthorized");
+        assert!(matches!(&error, FetchError::Unauthorized { error: Some(message), .. } if message == "Token expired"));
+        assert!(error.field_errors().is_empty());
+
+        let error = FetchError::from_status(URL, 401, "Unauthorized");
+        assert!(matches!(&error, FetchError::Unauthorized { error: None, .. }));
+        assert_eq!(error.to_string(), format!("The server at {} requires signing in (HTTP 401)", URL));
+    }
+
+    #[test]
+    fn other_client_errors_are_not_unauthorized() {
+        for status in [400, 403, 404, 409] {
+            let error = FetchError::from_status(URL, status, "{}");
+            assert!(!error.is_unauthorized(), "status {}", status);
+            assert!(matches!(error, FetchError::HttpStatus { status: s, .. } if s == status));
+        }
+    }
+}
@@ -0,0 +1,192 @@
+// Veri sunucusuna (server/index.ts) yapılan HTTP istekleri.
+// Native tarafta reqwest (arka plan iş parçacığında), WASM tarafında tarayıcının Fetch API'si (asenkron) kullanılır.
+// `token` verilirse isteğe `Authorization: Bearer <token>` başlığı eklenir (bkz. auth.rs).
+
+use super::FetchError;
+
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest; // Native HTTP istekleri için
+
+#[cfg(target_arch = "wasm32")]
+use {
+    eframe::wasm_bindgen::{closure::Closure, JsCast, JsValue}, // eframe üzerinden JsCast
+    wasm_bindgen_futures::JsFuture,
+    web_sys::{AbortController, Request, RequestInit, RequestMode, Response},
+};
+
+// Yanıt için beklenecek en uzun süre.
+const REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+// Native tarafta aynı köken kavramı yoktur; göreli adresler tam bir adres gerektirir.
+#[cfg(not(target_arch = "wasm32"))]
+fn check_absolute(url: &str) -> Result<(), FetchError> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(())
+    } else {
+        Err(FetchError::Request {
+            message: format!("Server URL must be absolute on native builds (got '{}'). Set it under Settings.", url),
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn client() -> Result<reqwest::blocking::Client, FetchError> {
+    reqwest::blocking::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| FetchError::Request { message: format!("Failed to create HTTP client: {}", e) })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn authorize(request: reqwest::blocking::RequestBuilder, token: Option<&str>) -> reqwest::blocking::RequestBuilder {
+    match token {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_response(url: &str, result: reqwest::Result<reqwest::blocking::Response>) -> Result<String, FetchError> {
+    match result {
+        Ok(response) => {
+            let status = response.status();
+            let text = response.text();
+            if status.is_success() {
+                text.map_err(|e| FetchError::Request { message: format!("Failed to read response text: {}", e) })
+            } else {
+                Err(FetchError::from_status(url, status.as_u16(), &text.unwrap_or_default()))
+            }
+        }
+        Err(e) if e.is_timeout() => Err(FetchError::Timeout { url: url.to_string() }),
+        Err(e) if e.is_connect() => Err(FetchError::ConnectionRefused { url: url.to_string(), message: e.to_string() }),
+        Err(e) => Err(FetchError::Request { message: format!("HTTP request failed: {}", e) }),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_text(url: &str, token: Option<&str>) -> Result<String, FetchError> {
+    check_absolute(url)?;
+    read_response(url, authorize(client()?.get(url), token).send())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn post_json(url: &str, body: &serde_json::Value, token: Option<&str>) -> Result<String, FetchError> {
+    check_absolute(url)?;
+    read_response(url, authorize(client()?.post(url).json(body), token).send())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn put_json(url: &str, body: &serde_json::Value, token: Option<&str>) -> Result<String, FetchError> {
+    check_absolute(url)?;
+    read_response(url, authorize(client()?.put(url).json(body), token).send())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn delete(url: &str, token: Option<&str>) -> Result<String, FetchError> {
+    check_absolute(url)?;
+    read_response(url, authorize(client()?.delete(url), token).send())
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn send(url: &str, opts: &RequestInit, json_body: bool, token: Option<&str>) -> Result<String, FetchError> {
+    let request_error = |message: String| FetchError::Request { message };
+    opts.set_mode(RequestMode::Cors); // CORS gerekli olabilir
+
+    // Fetch API'nin kendi zaman aşımı yoktur; istek REQUEST_TIMEOUT dolunca iptal edilir.
+    let controller = AbortController::new().map_err(|e| request_error(format!("Failed to create AbortController: {:?}", e)))?;
+    opts.set_signal(Some(&controller.signal()));
+
+    // Göreli adresler tarayıcı tarafından sayfanın köküne göre çözümlenir.
+    let request = Request::new_with_str_and_init(url, opts)
+        .map_err(|e| request_error(format!("Failed to create request: {:?}", e)))?;
+    if json_body {
+        request
+            .headers()
+            .set("Content-Type", "application/json")
+            .map_err(|e| request_error(format!("Failed to set request header: {:?}", e)))?;
+    }
+    if let Some(token) = token {
+        request
+            .headers()
+            .set("Authorization", &format!("Bearer {}", token))
+            .map_err(|e| request_error(format!("Failed to set request header: {:?}", e)))?;
+    }
+
+    let window = web_sys::window().ok_or_else(|| request_error("Failed to get window object".to_string()))?;
+    let abort = Closure::<dyn FnMut()>::once({
+        let controller = controller.clone();
+        move || controller.abort()
+    });
+    let timer = window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(abort.as_ref().unchecked_ref(), REQUEST_TIMEOUT.as_millis() as i32)
+        .ok();
+    let result = read(url, &window, &request, &controller).await;
+    // İstek bittiyse zamanlayıcı kaldırılır; `abort` ancak bundan sonra bırakılabilir.
+    if let Some(timer) = timer {
+        window.clear_timeout_with_handle(timer);
+    }
+    drop(abort);
+    result
+}
+
+// İsteği gönderip yanıt gövdesini okur. Zamanlayıcı isteği iptal ettiyse hata Timeout olur.
+#[cfg(target_arch = "wasm32")]
+async fn read(url: &str, window: &web_sys::Window, request: &Request, controller: &AbortController) -> Result<String, FetchError> {
+    let request_error = |message: String| FetchError::Request { message };
+    let timed_out = || controller.signal().aborted();
+    // Tarayıcı, bağlantı reddi ile CORS reddini ayırt etmez; ikisi de TypeError olarak gelir.
+    let resp_value = JsFuture::from(window.fetch_with_request(request)).await.map_err(|e| match timed_out() {
+        true => FetchError::Timeout { url: url.to_string() },
+        false => FetchError::ConnectionRefused { url: url.to_string(), message: format!("{:?}", e) },
+    })?;
+
+    // Yanıtın Response türünde olduğundan emin ol
+    let resp: Response = resp_value
+        .dyn_into()
+        .map_err(|e| request_error(format!("Failed to cast to Response: {:?}", e)))?;
+
+    let text = JsFuture::from(resp.text().map_err(|e| request_error(format!("Failed to get text from response: {:?}", e)))?)
+        .await
+        .map_err(|e| match timed_out() {
+            true => FetchError::Timeout { url: url.to_string() },
+            false => request_error(format!("Failed to convert text promise: {:?}", e)),
+        })?
+        .as_string()
+        .ok_or_else(|| request_error("Response text was not a string".to_string()))?;
+
+    if resp.ok() { // status 200-299
+        Ok(text)
+    } else {
+        Err(FetchError::from_status(url, resp.status(), &text))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn get_text(url: String, token: Option<String>) -> Result<String, FetchError> {
+    let opts = RequestInit::new(); // Derleyici uyarısına göre 'mut' kaldırıldı
+    opts.set_method("GET");
+    send(&url, &opts, false, token.as_deref()).await
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn post_json(url: String, body: serde_json::Value, token: Option<String>) -> Result<String, FetchError> {
+    let opts = RequestInit::new();
+    opts.set_method("POST");
+    opts.set_body(&JsValue::from_str(&body.to_string()));
+    send(&url, &opts, true, token.as_deref()).await
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn put_json(url: String, body: serde_json::Value, token: Option<String>) -> Result<String, FetchError> {
+    let opts = RequestInit::new();
+    opts.set_method("PUT");
+    opts.set_body(&JsValue::from_str(&body.to_string()));
+    send(&url, &opts, true, token.as_deref()).await
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn delete(url: String, token: Option<String>) -> Result<String, FetchError> {
+    let opts = RequestInit::new();
+    opts.set_method("DELETE");
+    send(&url, &opts, false, token.as_deref()).await
+}
@@ -0,0 +1,454 @@
+// Bun sunucusu olmadan, SQLite dosyasını uygulama içinde sorgulayan veri kaynağı.
+// Sonuçlar sunucunun döndürdüğü biçimle aynı JSON'a çevrilir (sütun adı -> değer
+// nesnelerinden oluşan bir dizi), böylece görünümler iki kaynağı aynı şekilde işler.
+
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags};
+use serde_json::{Map, Value};
+
+use super::schema::{quote_identifier, ColumnInfo, ForeignKeyInfo, IndexInfo, ObjectKind, Schema, SchemaObject};
+use super::{EditableTable, Paging, QueryResult, RowChange};
+
+// Dosya yoksa yeni ve boş bir veritabanı oluşturmak yerine hata verilir.
+fn open(path: &str) -> Result<Connection, String> {
+    Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| format!("Failed to open database '{}': {}", path, e))
+}
+
+fn value_to_json(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(i) => Value::from(i),
+        ValueRef::Real(f) => Value::from(f),
+        ValueRef::Text(t) => Value::from(String::from_utf8_lossy(t).into_owned()),
+        ValueRef::Blob(b) => Value::from(b.to_vec()),
+    }
+}
+
+pub fn query_rows(path: &str, sql: &str) -> Result<Vec<Value>, String> {
+    let conn = open(path)?;
+    let mut statement = conn
+        .prepare(sql)
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    let column_names: Vec<String> = statement
+        .column_names()
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let mut rows = statement
+        .query([])
+        .map_err(|e| format!("Query failed: {}", e))?;
+    let mut result = Vec::new();
+    while let Some(row) = rows.next().map_err(|e| format!("Failed to read row: {}", e))? {
+        let mut object = Map::with_capacity(column_names.len());
+        for (i, name) in column_names.iter().enumerate() {
+            let value = row
+                .get_ref(i)
+                .map_err(|e| format!("Failed to read column '{}': {}", name, e))?;
+            object.insert(name.clone(), value_to_json(value));
+        }
+        result.push(Value::Object(object));
+    }
+    Ok(result)
+}
+
+// Sorgu sonucunu sunucu yanıtıyla aynı biçimde JSON metni olarak döndürür.
+pub fn query_json(path: &str, sql: &str) -> Result<String, String> {
+    let rows = query_rows(path, sql)?;
+    serde_json::to_string(&rows).map_err(|e| format!("Failed to serialize rows: {}", e))
+}
+
+// Sorgu konsolu için: yalnızca veriyi değiştirmeyen ifadelere izin verilir.
+pub fn query_result(path: &str, sql: &str) -> Result<QueryResult, String> {
+    let conn = open(path)?;
+    collect_result(&conn, sql, &[])
+}
+
+// Sorgunun yalnızca istenen sayfasını (LIMIT/OFFSET) ve filtrelerden geçen toplam satır
+// sayısını döndürür. Sıralama ve filtreler sayfalamadan önce uygulanır.
+pub fn query_page(path: &str, sql: &str, paging: Paging) -> Result<QueryResult, String> {
+    let conn = open(path)?;
+    let (filtered_sql, params) = paging.query.to_sql(sql, paging.key_column);
+    let total: u64 = conn
+        .query_row(
+            &format!("SELECT COUNT(*) FROM ({})", filtered_sql),
+            rusqlite::params_from_iter(params.iter()),
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to count rows: {}", e))?;
+    let paged_sql = format!("{} LIMIT {} OFFSET {}", filtered_sql, paging.limit, paging.offset);
+    let mut result = collect_result(&conn, &paged_sql, &params)?;
+    result.total = Some(total);
+    Ok(result)
+}
+
+fn collect_result(conn: &Connection, sql: &str, params: &[rusqlite::types::Value]) -> Result<QueryResult, String> {
+    let started = std::time::Instant::now();
+    let mut statement = conn
+        .prepare(sql)
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    if !statement.readonly() {
+        return Err("Only read-only statements (SELECT, WITH ...) can be run from the query console.".to_string());
+    }
+    let columns: Vec<String> = statement
+        .column_names()
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let mut rows = statement
+        .query(rusqlite::params_from_iter(params.iter()))
+        .map_err(|e| format!("Query failed: {}", e))?;
+    let mut result_rows = Vec::new();
+    while let Some(row) = rows.next().map_err(|e| format!("Failed to read row: {}", e))? {
+        let mut values = Vec::with_capacity(columns.len());
+        for (i, name) in columns.iter().enumerate() {
+            let value = row
+                .get_ref(i)
+                .map_err(|e| format!("Failed to read column '{}': {}", name, e))?;
+            values.push(value_to_json(value));
+        }
+        result_rows.push(values);
+    }
+    Ok(QueryResult {
+        columns,
+        rows: result_rows,
+        elapsed_ms: Some(started.elapsed().as_secs_f64() * 1000.0),
+        total: None,
+    })
+}
+
+fn json_to_sql(value: &Value) -> rusqlite::types::Value {
+    use rusqlite::types::Value as SqlValue;
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(*b as i64),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => SqlValue::Integer(i),
+            None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => SqlValue::Text(s.clone()),
+        other => SqlValue::Text(other.to_string()),
+    }
+}
+
+// Sunucudaki POST/PUT/DELETE uç noktalarının karşılığı. Yazılan satırı (silmede
+// {"deleted": anahtar}) sunucunun yanıtıyla aynı biçimde döndürür.
+pub fn write_row(path: &str, table: EditableTable, change: RowChange) -> Result<Value, String> {
+    let conn = open(path)?;
+    let write_error = |e: rusqlite::Error| format!("Failed to write to '{}': {}", table.table, e);
+    let table_name = quote_identifier(table.table);
+    let key_column = quote_identifier(table.key_column);
+    let key = match change {
+        RowChange::Insert { values } => {
+            let columns: Vec<String> = values.keys().map(|name| quote_identifier(name)).collect();
+            let placeholders: Vec<String> = (1..=values.len()).map(|i| format!("?{}", i)).collect();
+            let params: Vec<_> = values.values().map(json_to_sql).collect();
+            conn.execute(
+                &format!("INSERT INTO {} ({}) VALUES ({})", table_name, columns.join(", "), placeholders.join(", ")),
+                rusqlite::params_from_iter(params.iter()),
+            )
+            .map_err(write_error)?;
+            conn.last_insert_rowid()
+        }
+        RowChange::Update { key, values } => {
+            let assignments: Vec<String> = values
+                .keys()
+                .enumerate()
+                .map(|(i, name)| format!("{} = ?{}", quote_identifier(name), i + 1))
+                .collect();
+            let mut params: Vec<_> = values.values().map(json_to_sql).collect();
+            params.push(rusqlite::types::Value::Integer(key));
+            let changed = conn
+                .execute(
+                    &format!("UPDATE {} SET {} WHERE {} = ?{}", table_name, assignments.join(", "), key_column, params.len()),
+                    rusqlite::params_from_iter(params.iter()),
+                )
+                .map_err(write_error)?;
+            if changed == 0 {
+                return Err(format!("No row in '{}' with {} = {}", table.table, table.key_column, key));
+            }
+            key
+        }
+        RowChange::Delete { key } => {
+            let changed = conn
+                .execute(&format!("DELETE FROM {} WHERE {} = ?1", table_name, key_column), [key])
+                .map_err(write_error)?;
+            if changed == 0 {
+                return Err(format!("No row in '{}' with {} = {}", table.table, table.key_column, key));
+            }
+            return Ok(serde_json::json!({ "deleted": key }));
+        }
+    };
+    let mut statement = conn
+        .prepare(&format!("SELECT * FROM {} WHERE {} = ?1", table_name, key_column))
+        .map_err(write_error)?;
+    let column_names: Vec<String> = statement.column_names().into_iter().map(String::from).collect();
+    statement
+        .query_row([key], |row| {
+            let mut object = Map::with_capacity(column_names.len());
+            for (i, name) in column_names.iter().enumerate() {
+                object.insert(name.clone(), value_to_json(row.get_ref(i)?));
+            }
+            Ok(Value::Object(object))
+        })
+        .map_err(write_error)
+}
+
+// sqlite_master ve PRAGMA tablo fonksiyonlarıyla tabloları, görünümleri ve ilişkilerini okur.
+pub fn schema(path: &str) -> Result<Schema, String> {
+    let conn = open(path)?;
+    let sql_error = |e: rusqlite::Error| format!("Failed to read schema: {}", e);
+
+    let mut objects_statement = conn
+        .prepare(
+            "SELECT name, type FROM sqlite_master \
+             WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%' \
+             ORDER BY type, name",
+        )
+        .map_err(sql_error)?;
+    let objects: Vec<(String, String)> = objects_statement
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(sql_error)?
+        .collect::<Result<_, _>>()
+        .map_err(sql_error)?;
+
+    let mut columns_statement = conn
+        .prepare("SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?1) ORDER BY cid")
+        .map_err(sql_error)?;
+    let mut index_list_statement = conn
+        .prepare("SELECT name, \"unique\" FROM pragma_index_list(?1) ORDER BY name")
+        .map_err(sql_error)?;
+    let mut index_columns_statement = conn
+        .prepare("SELECT name FROM pragma_index_info(?1) ORDER BY seqno")
+        .map_err(sql_error)?;
+    let mut foreign_keys_statement = conn
+        .prepare("SELECT id, \"table\", \"from\", \"to\" FROM pragma_foreign_key_list(?1) ORDER BY id, seq")
+        .map_err(sql_error)?;
+
+    let mut result = Schema::default();
+    for (name, kind) in objects {
+        let columns = columns_statement
+            .query_map([&name], |row| {
+                Ok(ColumnInfo {
+                    name: row.get(0)?,
+                    declared_type: row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+                    not_null: row.get(2)?,
+                    primary_key: row.get(4)?,
+                    default_value: row.get(3)?,
+                })
+            })
+            .map_err(sql_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(sql_error)?;
+
+        let index_list: Vec<(String, bool)> = index_list_statement
+            .query_map([&name], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(sql_error)?
+            .collect::<Result<_, _>>()
+            .map_err(sql_error)?;
+        let mut indexes = Vec::with_capacity(index_list.len());
+        for (index_name, unique) in index_list {
+            let columns = index_columns_statement
+                .query_map([&index_name], |row| row.get::<_, Option<String>>(0))
+                .map_err(sql_error)?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(sql_error)?
+                .into_iter()
+                // İfade tabanlı indekslerde sütun adı yoktur.
+                .map(|column| column.unwrap_or_else(|| "<expression>".to_string()))
+                .collect();
+            indexes.push(IndexInfo { name: index_name, unique, columns });
+        }
+
+        // Birden çok sütunlu yabancı anahtarlar aynı id ile birden fazla satır döndürür.
+        let mut foreign_keys: Vec<(i64, ForeignKeyInfo)> = Vec::new();
+        let mut rows = foreign_keys_statement.query([&name]).map_err(sql_error)?;
+        while let Some(row) = rows.next().map_err(sql_error)? {
+            let id: i64 = row.get(0).map_err(sql_error)?;
+            let from: String = row.get(2).map_err(sql_error)?;
+            let to: Option<String> = row.get(3).map_err(sql_error)?;
+            match foreign_keys.last_mut() {
+                Some((last_id, key)) if *last_id == id => {
+                    key.columns.push(from);
+                    key.referenced_columns.extend(to);
+                }
+                _ => foreign_keys.push((
+                    id,
+                    ForeignKeyInfo {
+                        columns: vec![from],
+                        referenced_table: row.get(1).map_err(sql_error)?,
+                        referenced_columns: to.into_iter().collect(),
+                    },
+                )),
+            }
+        }
+
+        result.objects.push(SchemaObject {
+            name,
+            kind: if kind == "view" { ObjectKind::View } else { ObjectKind::Table },
+            columns,
+            indexes,
+            foreign_keys: foreign_keys.into_iter().map(|(_, key)| key).collect(),
+        });
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::tablequery::SortKey;
+    use crate::datasource::{TableQuery, CUSTOMERS_TABLE};
+
+    // Her test kendi geçici dosyasını kullanır; fonksiyonlar veritabanını yoluyla açtığı için
+    // bellekteki bir veritabanı yetmez.
+    struct TempDb(std::path::PathBuf);
+
+    impl TempDb {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("emartident-local-{}-{}.db", name, std::process::id()));
+            let _ = std::fs::remove_file(&path);
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE Customers (CustomerID INTEGER PRIMARY KEY, CustomerName TEXT NOT NULL, Address TEXT, Photo BLOB);
+                 INSERT INTO Customers (CustomerName, Address) VALUES ('Alfreds', 'Obere Str. 57'), ('Ana Trujillo', NULL), ('Antonio', 'Mataderos 2312');",
+            )
+            .unwrap();
+            Self(path)
+        }
+
+        fn path(&self) -> &str {
+            self.0.to_str().unwrap()
+        }
+
+        fn count(&self) -> i64 {
+            Connection::open(&self.0).unwrap().query_row("SELECT COUNT(*) FROM Customers", [], |row| row.get(0)).unwrap()
+        }
+    }
+
+    impl Drop for TempDb {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn values(pairs: &[(&str, Value)]) -> Map<String, Value> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.clone())).collect()
+    }
+
+    #[test]
+    fn query_result_keeps_column_order_and_value_types() {
+        let db = TempDb::new("query");
+        let result = query_result(db.path(), "SELECT CustomerID, CustomerName, Address, 1.5 AS Ratio, x'0102' AS Bytes FROM Customers ORDER BY CustomerID").unwrap();
+        assert_eq!(result.columns, ["CustomerID", "CustomerName", "Address", "Ratio", "Bytes"]);
+        assert_eq!(result.rows.len(), 3);
+        assert_eq!(result.rows[0], vec![Value::from(1), Value::from("Alfreds"), Value::from("Obere Str. 57"), Value::from(1.5), serde_json::json!([1, 2])]);
+        assert_eq!(result.rows[1][2], Value::Null);
+        assert!(result.elapsed_ms.is_some());
+        assert_eq!(result.total, None);
+    }
+
+    #[test]
+    fn query_result_accepts_with_and_empty_results() {
+        let db = TempDb::new("with");
+        let result = query_result(db.path(), "WITH named AS (SELECT CustomerName FROM Customers WHERE Address IS NULL) SELECT * FROM named").unwrap();
+        assert_eq!(result.rows, vec![vec![Value::from("Ana Trujillo")]]);
+        let empty = query_result(db.path(), "SELECT CustomerName FROM Customers WHERE 0").unwrap();
+        assert_eq!(empty.columns, ["CustomerName"]);
+        assert!(empty.rows.is_empty());
+    }
+
+    #[test]
+    fn query_result_rejects_statements_that_modify_data() {
+        let db = TempDb::new("readonly");
+        for sql in [
+            "DELETE FROM Customers",
+            "UPDATE Customers SET CustomerName = 'x'",
+            "INSERT INTO Customers (CustomerName) VALUES ('x')",
+            "DROP TABLE Customers",
+            "CREATE TABLE Other (id INTEGER)",
+        ] {
+            let error = query_result(db.path(), sql).unwrap_err();
+            assert!(error.contains("read-only"), "{}: {}", sql, error);
+        }
+        assert_eq!(db.count(), 3);
+        assert!(query_result(db.path(), "SELEKT 1").unwrap_err().contains("prepare"));
+    }
+
+    #[test]
+    fn missing_file_is_an_error_and_is_not_created() {
+        let path = std::env::temp_dir().join(format!("emartident-local-missing-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        assert!(query_result(path.to_str().unwrap(), "SELECT 1").is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn query_page_returns_the_page_and_total() {
+        let db = TempDb::new("page");
+        let paging = Paging { limit: 2, offset: 1, query: TableQuery::default(), key_column: Some("CustomerID") };
+        let result = query_page(db.path(), "SELECT CustomerID, CustomerName FROM Customers ORDER BY CustomerID", paging).unwrap();
+        assert_eq!(result.total, Some(3));
+        assert_eq!(result.rows, vec![vec![Value::from(2), Value::from("Ana Trujillo")], vec![Value::from(3), Value::from("Antonio")]]);
+    }
+
+    #[test]
+    fn query_page_breaks_sort_ties_with_the_key_column() {
+        let db = TempDb::new("ties");
+        Connection::open(&db.0)
+            .unwrap()
+            .execute_batch("UPDATE Customers SET Address = 'Same'; INSERT INTO Customers (CustomerName, Address) VALUES ('Around the Horn', 'Other');")
+            .unwrap();
+        let query = TableQuery { sort: vec![SortKey { column: "Address".to_string(), descending: true }], ..Default::default() };
+        // Temel sorgu satırları ters sırada verse de (LIMIT, SQLite'ın iç sırayı atmasını önler)
+        // eşit adresler anahtara göre artan sırada sayfalanır.
+        let sql = "SELECT CustomerID, Address FROM Customers ORDER BY CustomerID DESC LIMIT -1";
+        let keys: Vec<Value> = (0..4)
+            .map(|offset| {
+                let paging = Paging { limit: 1, offset, query: query.clone(), key_column: Some("CustomerID") };
+                query_page(db.path(), sql, paging).unwrap().rows[0][0].clone()
+            })
+            .collect();
+        assert_eq!(keys, [1, 2, 3, 4].map(Value::from));
+    }
+
+    #[test]
+    fn write_row_inserts_updates_and_deletes() {
+        let db = TempDb::new("write");
+        let inserted = write_row(
+            db.path(),
+            CUSTOMERS_TABLE,
+            RowChange::Insert { values: values(&[("CustomerName", Value::from("Berglunds")), ("Address", Value::from("Berguvsvägen 8"))]) },
+        )
+        .unwrap();
+        assert_eq!(inserted["CustomerID"], Value::from(4));
+        assert_eq!(inserted["CustomerName"], Value::from("Berglunds"));
+        assert_eq!(db.count(), 4);
+
+        let updated = write_row(db.path(), CUSTOMERS_TABLE, RowChange::Update { key: 2, values: values(&[("Address", Value::from("Avda. 2222"))]) }).unwrap();
+        assert_eq!(updated["Address"], Value::from("Avda. 2222"));
+        // Gönderilmeyen sütunlar değişmez.
+        assert_eq!(updated["CustomerName"], Value::from("Ana Trujillo"));
+
+        let deleted = write_row(db.path(), CUSTOMERS_TABLE, RowChange::Delete { key: 1 }).unwrap();
+        assert_eq!(deleted, serde_json::json!({ "deleted": 1 }));
+        assert_eq!(db.count(), 3);
+    }
+
+    #[test]
+    fn write_row_reports_missing_rows_and_constraint_errors() {
+        let db = TempDb::new("write-errors");
+        let missing_update = write_row(db.path(), CUSTOMERS_TABLE, RowChange::Update { key: 99, values: values(&[("Address", Value::from("x"))]) });
+        assert!(missing_update.unwrap_err().contains("No row"));
+        let missing_delete = write_row(db.path(), CUSTOMERS_TABLE, RowChange::Delete { key: 99 });
+        assert!(missing_delete.unwrap_err().contains("No row"));
+        let not_null = write_row(db.path(), CUSTOMERS_TABLE, RowChange::Insert { values: values(&[("CustomerName", Value::Null)]) });
+        assert!(not_null.unwrap_err().contains("Failed to write to 'Customers'"));
+        assert_eq!(db.count(), 3);
+    }
+}
@@ -0,0 +1,320 @@
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+pub mod auth;
+pub mod changefeed;
+pub mod http;
+#[cfg(all(feature = "local-sqlite", not(target_arch = "wasm32")))]
+pub mod local;
+mod error;
+mod result;
+pub mod schema;
+mod tablequery;
+
+pub use error::FetchError;
+pub use result::{cell_text, QueryResult};
+pub use tablequery::TableQuery;
+
+use crate::app::AppSettings;
+use crate::i18n::t;
+use crate::task::Task;
+
+pub const CUSTOMERS_ENDPOINT: &str = "/customers";
+// Genel sorgu uç noktası: POST {"sql": "..."} -> {"columns": [...], "rows": [[...]], "elapsedMs": n}
+pub const QUERY_ENDPOINT: &str = "/query";
+// Şema uç noktası: GET -> schema::Schema JSON'u
+pub const SCHEMA_ENDPOINT: &str = "/schema";
+// Değişiklik akışı: GET -> text/event-stream, her olay bir changefeed::ChangeEvent JSON'u
+pub const CHANGES_ENDPOINT: &str = "/changes";
+// Oturum açma: POST {"username": "...", "password": "..."} -> {"token": "..."}
+pub const LOGIN_ENDPOINT: &str = "/login";
+// Oturumu kapatırken belirteci sunucuda geçersiz kılar.
+pub const LOGOUT_ENDPOINT: &str = "/logout";
+// server/index.ts içindeki /customers sorgusunun aynısı; yerel kaynak da aynı veriyi döndürür.
+// Sayfalar arasında satırların yer değiştirmemesi için sıralama sabittir.
+pub const CUSTOMERS_QUERY: &str = "SELECT [Customers].[CustomerName], [Customers].[Address], [Customers].[CustomerID] FROM [Customers] ORDER BY [Customers].[CustomerID]";
+
+// Satırları düzenlenebilen bir tablo: HTTP'de `endpoint` (POST) ve `endpoint/<anahtar>` (PUT, DELETE),
+// yerel dosyada `table` üzerinde `key_column` ile eşleşen satıra INSERT/UPDATE/DELETE.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EditableTable {
+    pub endpoint: &'static str,
+    pub table: &'static str,
+    pub key_column: &'static str,
+}
+
+pub const CUSTOMERS_TABLE: EditableTable = EditableTable {
+    endpoint: CUSTOMERS_ENDPOINT,
+    table: "Customers",
+    key_column: "CustomerID",
+};
+
+// Tek bir satıra yapılacak yazma işlemi. `values` sütun adı -> yeni değer eşlemesidir.
+#[derive(Clone, PartialEq, Debug)]
+pub enum RowChange {
+    Insert { values: serde_json::Map<String, serde_json::Value> },
+    Update { key: i64, values: serde_json::Map<String, serde_json::Value> },
+    Delete { key: i64 },
+}
+
+// Depo kökünden `cargo run` ile çalıştırıldığında örnek veritabanını bulur.
+pub const DEFAULT_LOCAL_DB_PATH: &str = "server/db/Northwind.db";
+
+// Sunucu tarafı sayfalama: HTTP'de `?limit=..&offset=..` sorgu parametrelerine,
+// yerel dosyada LIMIT/OFFSET'e çevrilir. Sayfalı yanıtlar {"columns", "rows", "total"} biçimindedir.
+// Sayfalar sunucuda oluşturulduğu için sıralama ve filtreler de sayfayla birlikte gönderilir.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Paging {
+    pub limit: usize,
+    pub offset: usize,
+    pub query: TableQuery,
+    // Yerel dosyada ORDER BY'ın son anahtarı: eşit değerli satırlar sayfalar arasında yer
+    // değiştirmesin diye. Sunucu bunun için kendi anahtar sütununu kullanır.
+    pub key_column: Option<&'static str>,
+}
+
+// Bir görünümün verisini nereden aldığı.
+// LocalFile her derlemede (de)serileştirilebilir; böylece `local-sqlite` özelliği olmadan
+// derlenen bir sürüm kaydedilmiş durumu okuyabilir ve yalnızca sorgu sırasında hata verir.
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum DataSource {
+    #[default]
+    HttpServer,
+    LocalFile { path: String },
+}
+
+impl DataSource {
+    // Bu derlemede yerel SQLite dosyalarının açılıp açılamayacağı.
+    pub const LOCAL_AVAILABLE: bool = cfg!(all(feature = "local-sqlite", not(target_arch = "wasm32")));
+
+    pub fn label(&self) -> String {
+        t(match self {
+            DataSource::HttpServer => "source-http",
+            DataSource::LocalFile { .. } => "source-local",
+        })
+    }
+
+    // Kullanıcıya nereden veri çekildiğini göstermek için kısa açıklama.
+    pub fn describe(&self, settings: &AppSettings, endpoint: &str) -> String {
+        match self {
+            DataSource::HttpServer => settings.endpoint_url(endpoint),
+            DataSource::LocalFile { path } => path.clone(),
+        }
+    }
+
+    // Kaynak seçici; seçim ya da dosya yolu değiştiyse true döner.
+    pub fn picker_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            ui.label(t("source"));
+            let is_http = matches!(self, DataSource::HttpServer);
+            if ui.selectable_label(is_http, DataSource::HttpServer.label()).clicked() && !is_http {
+                *self = DataSource::HttpServer;
+                changed = true;
+            }
+            let local_label = ui.add_enabled(
+                Self::LOCAL_AVAILABLE,
+                egui::SelectableLabel::new(!is_http, t("source-local")),
+            );
+            let local_label = if Self::LOCAL_AVAILABLE {
+                local_label
+            } else {
+                local_label.on_disabled_hover_text(t("source-local-unavailable"))
+            };
+            if local_label.clicked() && is_http {
+                *self = DataSource::LocalFile { path: DEFAULT_LOCAL_DB_PATH.to_string() };
+                changed = true;
+            }
+        });
+        if let DataSource::LocalFile { path } = self {
+            ui.horizontal(|ui| {
+                ui.label(t("source-database-file"));
+                if ui.text_edit_singleline(path).lost_focus() {
+                    changed = true;
+                }
+            });
+        }
+        changed
+    }
+
+    // Sabit bir uç noktadan (HTTP) ya da eşdeğer SQL sorgusundan (yerel dosya) veriyi
+    // JSON metni olarak getirir. `paging` verilirse yalnızca o sayfa ve toplam satır sayısı döner.
+    pub fn fetch(&self, ctx: &egui::Context, settings: &AppSettings, endpoint: &str, sql: &str, paging: Option<Paging>) -> Task<Result<String, FetchError>> {
+        self.start(ctx, settings, SourceRequest::Get { endpoint: endpoint.to_string(), local_sql: sql.to_string(), paging })
+    }
+
+    // Serbest bir SELECT ifadesini çalıştırır ve sonucu QueryResult JSON'u olarak döndürür.
+    pub fn run_query(&self, ctx: &egui::Context, settings: &AppSettings, sql: &str) -> Task<Result<String, FetchError>> {
+        self.start(ctx, settings, SourceRequest::Query { sql: sql.to_string() })
+    }
+
+    // Tablo, görünüm, sütun, indeks ve yabancı anahtar bilgisini schema::Schema JSON'u olarak döndürür.
+    pub fn fetch_schema(&self, ctx: &egui::Context, settings: &AppSettings) -> Task<Result<String, FetchError>> {
+        self.start(ctx, settings, SourceRequest::Schema)
+    }
+
+    // Bir satırı ekler, günceller ya da siler. Başarılı olursa yazılan satır
+    // (silmede {"deleted": anahtar}) JSON nesnesi olarak döner.
+    pub fn write_row(&self, ctx: &egui::Context, settings: &AppSettings, table: EditableTable, change: RowChange) -> Task<Result<String, FetchError>> {
+        self.start(ctx, settings, SourceRequest::Write { table, change })
+    }
+
+    // HTTP isteklerine bağlantının belirteci eklenir; 401 alınırsa oturum açılması beklenir ve
+    // istek yeni belirteçle yeniden gönderilir (bkz. auth.rs).
+    fn start(&self, ctx: &egui::Context, settings: &AppSettings, request: SourceRequest) -> Task<Result<String, FetchError>> {
+        let url = request.url(settings);
+        let server = auth::server_key(settings);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let source = self.clone();
+            let waiter = ctx.clone();
+            Task::spawn_blocking(ctx, move || source.execute_blocking(&waiter, &server, &url, request))
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        match self {
+            DataSource::HttpServer => {
+                let waiter = ctx.clone();
+                Task::spawn_async(ctx, async move {
+                    let mut token = auth::token(&server);
+                    loop {
+                        let result = match (request.method(), request.body()) {
+                            (HttpMethod::Put, Some(body)) => http::put_json(url.clone(), body, token.clone()).await,
+                            (HttpMethod::Delete, _) => http::delete(url.clone(), token.clone()).await,
+                            (_, Some(body)) => http::post_json(url.clone(), body, token.clone()).await,
+                            (_, None) => http::get_text(url.clone(), token.clone()).await,
+                        };
+                        match result {
+                            Err(error) if error.is_unauthorized() => match auth::wait_for_login(&waiter, &server, token.as_deref()).await {
+                                Some(new_token) => token = Some(new_token),
+                                None => return Err(error),
+                            },
+                            result => return result,
+                        }
+                    }
+                })
+            }
+            DataSource::LocalFile { .. } => Task::ready(Err(FetchError::Request {
+                message: "Local SQLite files cannot be opened in the browser.".to_string(),
+            })),
+        }
+    }
+
+    // Arka plan iş parçacığında çalışır.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn execute_blocking(&self, ctx: &egui::Context, server: &str, url: &str, request: SourceRequest) -> Result<String, FetchError> {
+        match self {
+            DataSource::HttpServer => {
+                let mut token = auth::token(server);
+                loop {
+                    let result = match (request.method(), request.body()) {
+                        (HttpMethod::Put, Some(body)) => http::put_json(url, &body, token.as_deref()),
+                        (HttpMethod::Delete, _) => http::delete(url, token.as_deref()),
+                        (_, Some(body)) => http::post_json(url, &body, token.as_deref()),
+                        (_, None) => http::get_text(url, token.as_deref()),
+                    };
+                    match result {
+                        Err(error) if error.is_unauthorized() => match auth::wait_for_login(ctx, server, token.as_deref()) {
+                            Some(new_token) => token = Some(new_token),
+                            None => return Err(error),
+                        },
+                        result => return result,
+                    }
+                }
+            }
+            #[cfg(feature = "local-sqlite")]
+            DataSource::LocalFile { path } => {
+                let database_error = |message: String| FetchError::Database { message };
+                let serialize_error = |e: serde_json::Error| FetchError::InvalidJson { message: format!("Failed to serialize result: {}", e) };
+                match request {
+                    SourceRequest::Get { local_sql, paging: None, .. } => local::query_json(path, &local_sql).map_err(database_error),
+                    SourceRequest::Get { local_sql, paging: Some(paging), .. } => {
+                        let result = local::query_page(path, &local_sql, paging).map_err(database_error)?;
+                        serde_json::to_string(&result).map_err(serialize_error)
+                    }
+                    SourceRequest::Query { sql } => {
+                        let result = local::query_result(path, &sql).map_err(database_error)?;
+                        serde_json::to_string(&result).map_err(serialize_error)
+                    }
+                    SourceRequest::Schema => {
+                        let schema = local::schema(path).map_err(database_error)?;
+                        serde_json::to_string(&schema).map_err(serialize_error)
+                    }
+                    SourceRequest::Write { table, change } => {
+                        let row = local::write_row(path, table, change).map_err(database_error)?;
+                        serde_json::to_string(&row).map_err(serialize_error)
+                    }
+                }
+            }
+            #[cfg(not(feature = "local-sqlite"))]
+            DataSource::LocalFile { .. } => {
+                let _ = request;
+                Err(FetchError::Request {
+                    message: "Local SQLite files are not supported: this build was compiled without the `local-sqlite` feature.".to_string(),
+                })
+            }
+        }
+    }
+}
+
+// Bir veri kaynağına yapılan istek; HTTP'de bir uç noktaya, yerel dosyada SQL'e karşılık gelir.
+enum SourceRequest {
+    #[cfg_attr(not(all(feature = "local-sqlite", not(target_arch = "wasm32"))), allow(dead_code))] // local_sql yalnızca yerel kaynakta okunur
+    Get { endpoint: String, local_sql: String, paging: Option<Paging> },
+    Query { sql: String },
+    Schema,
+    Write { table: EditableTable, change: RowChange },
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl SourceRequest {
+    fn url(&self, settings: &AppSettings) -> String {
+        match self {
+            SourceRequest::Get { endpoint, paging: None, .. } => settings.endpoint_url(endpoint),
+            SourceRequest::Get { endpoint, paging: Some(paging), .. } => {
+                let mut query = form_urlencoded::Serializer::new(String::new());
+                query
+                    .append_pair("limit", &paging.limit.to_string())
+                    .append_pair("offset", &paging.offset.to_string());
+                for (name, value) in paging.query.to_query_params() {
+                    query.append_pair(&name, &value);
+                }
+                format!("{}?{}", settings.endpoint_url(endpoint), query.finish())
+            }
+            SourceRequest::Query { .. } => settings.endpoint_url(QUERY_ENDPOINT),
+            SourceRequest::Schema => settings.endpoint_url(SCHEMA_ENDPOINT),
+            SourceRequest::Write { table, change: RowChange::Insert { .. } } => settings.endpoint_url(table.endpoint),
+            SourceRequest::Write { table, change: RowChange::Update { key, .. } | RowChange::Delete { key } } => {
+                settings.endpoint_url(&format!("{}/{}", table.endpoint, key))
+            }
+        }
+    }
+
+    fn method(&self) -> HttpMethod {
+        match self {
+            SourceRequest::Get { .. } | SourceRequest::Schema => HttpMethod::Get,
+            SourceRequest::Query { .. } | SourceRequest::Write { change: RowChange::Insert { .. }, .. } => HttpMethod::Post,
+            SourceRequest::Write { change: RowChange::Update { .. }, .. } => HttpMethod::Put,
+            SourceRequest::Write { change: RowChange::Delete { .. }, .. } => HttpMethod::Delete,
+        }
+    }
+
+    // POST/PUT ile gönderilecek JSON gövdesi; GET ve DELETE istekleri için None.
+    fn body(&self) -> Option<serde_json::Value> {
+        match self {
+            SourceRequest::Query { sql } => Some(serde_json::json!({ "sql": sql })),
+            SourceRequest::Write { change: RowChange::Insert { values } | RowChange::Update { values, .. }, .. } => {
+                Some(serde_json::Value::Object(values.clone()))
+            }
+            SourceRequest::Get { .. } | SourceRequest::Schema | SourceRequest::Write { change: RowChange::Delete { .. }, .. } => None,
+        }
+    }
+}
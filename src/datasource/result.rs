@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// Herhangi bir sorgunun sonucu için genel satır gösterimi.
+// Sütunlar sonuçla birlikte gelir; her satır `columns` ile aynı sırada değerler içerir.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct QueryResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+    // Sorgunun veri kaynağında çalışma süresi (sunucu veya yerel dosya), biliniyorsa.
+    #[serde(default, rename = "elapsedMs", skip_serializing_if = "Option::is_none")]
+    pub elapsed_ms: Option<f64>,
+    // Sayfalı isteklerde, sayfalamadan önceki toplam satır sayısı.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+}
+
+impl QueryResult {
+    // İki biçimi de kabul eder:
+    //  - `/query` uç noktasının döndürdüğü {"columns": [...], "rows": [[...], ...]} nesnesi
+    //  - `/customers` gibi uç noktaların döndürdüğü [{"Sütun": değer, ...}, ...] dizisi
+    //    (sütun sırası ilk satırdaki anahtar sırasından alınır)
+    pub fn from_json(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Array(objects) => Self::from_objects(objects),
+            Value::Object(_) => serde_json::from_value(value)
+                .map_err(|e| format!("Response is not a query result: {}", e)),
+            _ => Err("Response is neither an array of rows nor a query result object".to_string()),
+        }
+    }
+
+    fn from_objects(objects: Vec<Value>) -> Result<Self, String> {
+        let mut columns: Vec<String> = Vec::new();
+        let mut rows = Vec::with_capacity(objects.len());
+        for object in objects {
+            let Value::Object(mut map) = object else {
+                return Err("Expected every row to be a JSON object".to_string());
+            };
+            // Sonraki satırlarda ilk kez görülen anahtarlar yeni sütun olarak eklenir.
+            for key in map.keys() {
+                if !columns.iter().any(|c| c == key) {
+                    columns.push(key.clone());
+                }
+            }
+            rows.push(
+                columns
+                    .iter()
+                    .map(|c| map.remove(c).unwrap_or(Value::Null))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        // Sonradan eklenen sütunlar daha önceki satırlarda eksik kalır; Null ile tamamla.
+        for row in rows.iter_mut() {
+            row.resize(columns.len(), Value::Null);
+        }
+        Ok(Self { columns, rows, elapsed_ms: None, total: None })
+    }
+
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c == name)
+    }
+
+    // Beklenen sütunların hepsi yoksa eksik olanların listesini döndürür.
+    pub fn require_columns(&self, names: &[&str]) -> Result<(), String> {
+        let missing: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|name| self.column_index(name).is_none())
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Missing column(s): {}", missing.join(", ")))
+        }
+    }
+}
+
+// Tablolarda gösterim için bir hücre değerini metne çevirir.
+pub fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+// Veritabanı şeması. HTTP üzerinden `GET /schema` uç noktası bu yapıyı JSON olarak döndürür,
+// yerel kaynak ise aynı bilgiyi PRAGMA sorgularıyla doğrudan dosyadan okur.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schema {
+    pub objects: Vec<SchemaObject>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectKind {
+    Table,
+    View,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaObject {
+    pub name: String,
+    pub kind: ObjectKind,
+    #[serde(default)]
+    pub columns: Vec<ColumnInfo>,
+    #[serde(default)]
+    pub indexes: Vec<IndexInfo>,
+    #[serde(default)]
+    pub foreign_keys: Vec<ForeignKeyInfo>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnInfo {
+    pub name: String,
+    #[serde(default)]
+    pub declared_type: String,
+    #[serde(default)]
+    pub not_null: bool,
+    // Birincil anahtardaki sırası (1'den başlar); birincil anahtarın parçası değilse 0.
+    #[serde(default)]
+    pub primary_key: u32,
+    #[serde(default)]
+    pub default_value: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexInfo {
+    pub name: String,
+    #[serde(default)]
+    pub unique: bool,
+    #[serde(default)]
+    pub columns: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForeignKeyInfo {
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+}
+
+// Tablo ve sütun adlarını SQL içinde güvenle kullanmak için çift tırnakla çevreler.
+pub fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
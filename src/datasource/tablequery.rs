@@ -0,0 +1,416 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{cell_text, QueryResult};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortKey {
+    pub column: String,
+    pub descending: bool,
+}
+
+// Bir tablonun sıralama ve sütun filtreleri.
+// Sonucun tamamı bellekteyse `apply` ile istemcide uygulanır; sayfalı isteklerde ise
+// `to_query_params` ile sunucuya, yerel dosyada `to_sql` ile SQLite'a gönderilir.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableQuery {
+    // Öncelik sırasına göre sıralama anahtarları.
+    pub sort: Vec<SortKey>,
+    // Sütun adı -> filtre ifadesi (bkz. Filter::parse).
+    pub filters: BTreeMap<String, String>,
+}
+
+// Bir sütun filtresinin çözümlenmiş hali.
+//   "abc"      -> içerir (büyük/küçük harf duyarsız)
+//   "=abc"     -> tam eşit
+//   "10..20"   -> 10 ile 20 arası (uçlar dahil); "10.." ve "..20" de geçerlidir
+//   ">5", ">=5", "<5", "<=5" -> sayısal karşılaştırma
+// Sayılar onluk yazılır ("-1.5", "2e3"); aralıkta tam olarak bir ".." bulunur. Bunlara
+// uymayan ifadeler "içerir" sayılır. server/index.ts içindeki parseFilter aynı kuralları uygular.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    Contains(String),
+    Equals(String),
+    Range { min: Option<Bound>, max: Option<Bound> },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bound {
+    pub value: f64,
+    pub inclusive: bool,
+}
+
+impl Filter {
+    pub fn parse(text: &str) -> Option<Filter> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        if let Some(rest) = text.strip_prefix('=') {
+            return Some(Filter::Equals(rest.trim().to_string()));
+        }
+        if let [min, max] = text.split("..").collect::<Vec<_>>()[..] {
+            let parse_side = |side: &str| -> Option<Option<Bound>> {
+                if side.trim().is_empty() {
+                    Some(None)
+                } else {
+                    parse_number(side).map(|value| Some(Bound { value, inclusive: true }))
+                }
+            };
+            if let (Some(min), Some(max)) = (parse_side(min), parse_side(max)) {
+                if min.is_some() || max.is_some() {
+                    return Some(Filter::Range { min, max });
+                }
+            }
+        }
+        for (prefix, is_min, inclusive) in [(">=", true, true), ("<=", false, true), (">", true, false), ("<", false, false)] {
+            if let Some(number) = text.strip_prefix(prefix).and_then(parse_number) {
+                let bound = Some(Bound { value: number, inclusive });
+                return Some(if is_min {
+                    Filter::Range { min: bound, max: None }
+                } else {
+                    Filter::Range { min: None, max: bound }
+                });
+            }
+        }
+        Some(Filter::Contains(text.to_string()))
+    }
+
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            Filter::Contains(needle) => {
+                !value.is_null() && cell_text(value).to_lowercase().contains(&needle.to_lowercase())
+            }
+            Filter::Equals(expected) => !value.is_null() && cell_text(value) == *expected,
+            Filter::Range { min, max } => {
+                let Some(number) = as_number(value) else {
+                    return false;
+                };
+                let above_min = min.is_none_or(|b| if b.inclusive { number >= b.value } else { number > b.value });
+                let below_max = max.is_none_or(|b| if b.inclusive { number <= b.value } else { number < b.value });
+                above_min && below_max
+            }
+        }
+    }
+}
+
+// Filtre ifadesindeki sayı. Rust'ın f64 ayrıştırıcısı "inf" ve "NaN" da kabul ettiğinden
+// sonlu olmayanlar elenir; böylece JavaScript'in Number.isFinite denetimiyle aynı sonucu verir.
+fn parse_number(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+// SQLite'ın sıralamasına benzer: NULL < sayılar < metin < diğerleri.
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    fn rank(value: &Value) -> u8 {
+        match value {
+            Value::Null => 0,
+            Value::Bool(_) | Value::Number(_) => 1,
+            Value::String(_) => 2,
+            _ => 3,
+        }
+    }
+    match (a, b) {
+        (Value::Number(_) | Value::Bool(_), Value::Number(_) | Value::Bool(_)) => {
+            let to_f64 = |v: &Value| v.as_f64().or_else(|| v.as_bool().map(|b| b as u8 as f64)).unwrap_or(0.0);
+            to_f64(a).total_cmp(&to_f64(b))
+        }
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        _ => rank(a).cmp(&rank(b)).then_with(|| cell_text(a).cmp(&cell_text(b))),
+    }
+}
+
+impl TableQuery {
+    pub fn is_empty(&self) -> bool {
+        self.sort.is_empty() && self.filters.values().all(|f| f.trim().is_empty())
+    }
+
+    // Sütunun sıralamadaki önceliği (0'dan) ve yönü.
+    pub fn sort_state(&self, column: &str) -> Option<(usize, bool)> {
+        self.sort
+            .iter()
+            .position(|key| key.column == column)
+            .map(|i| (i, self.sort[i].descending))
+    }
+
+    // Başlık tıklaması: artan -> azalan -> sıralamasız.
+    // `additive` (Shift+tıklama) diğer sütunların sıralamasını korur.
+    pub fn cycle_sort(&mut self, column: &str, additive: bool) {
+        let next = match self.sort_state(column) {
+            None => Some(false),
+            Some((_, false)) => Some(true),
+            Some((_, true)) => None,
+        };
+        if additive {
+            match (self.sort.iter().position(|key| key.column == column), next) {
+                (Some(i), Some(descending)) => self.sort[i].descending = descending,
+                (Some(i), None) => {
+                    self.sort.remove(i);
+                }
+                (None, Some(descending)) => self.sort.push(SortKey { column: column.to_string(), descending }),
+                (None, None) => {}
+            }
+        } else {
+            self.sort = next
+                .map(|descending| vec![SortKey { column: column.to_string(), descending }])
+                .unwrap_or_default();
+        }
+    }
+
+    fn parsed_filters(&self) -> Vec<(&str, Filter)> {
+        self.filters
+            .iter()
+            .filter_map(|(column, text)| Filter::parse(text).map(|filter| (column.as_str(), filter)))
+            .collect()
+    }
+
+    // İstemci tarafı: filtreden geçen satırların indekslerini sıralanmış olarak döndürür.
+    pub fn apply(&self, result: &QueryResult) -> Vec<usize> {
+        let filters: Vec<(usize, Filter)> = self
+            .parsed_filters()
+            .into_iter()
+            .filter_map(|(column, filter)| result.column_index(column).map(|i| (i, filter)))
+            .collect();
+        let mut indices: Vec<usize> = (0..result.rows.len())
+            .filter(|&row| filters.iter().all(|(col, filter)| filter.matches(&result.rows[row][*col])))
+            .collect();
+
+        let sort: Vec<(usize, bool)> = self
+            .sort
+            .iter()
+            .filter_map(|key| result.column_index(&key.column).map(|i| (i, key.descending)))
+            .collect();
+        if !sort.is_empty() {
+            // Kararlı sıralama: eşit satırlar sunucudan geldiği sırada kalır.
+            indices.sort_by(|&a, &b| {
+                sort.iter()
+                    .map(|&(col, descending)| {
+                        let ordering = compare_values(&result.rows[a][col], &result.rows[b][col]);
+                        if descending { ordering.reverse() } else { ordering }
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+        indices
+    }
+
+    // Sunucu tarafı: `sort=Ad:desc,Id:asc` ve sütun başına `filter.<Sütun>=<ifade>` parametreleri.
+    pub fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        if !self.sort.is_empty() {
+            let sort = self
+                .sort
+                .iter()
+                .map(|key| format!("{}:{}", key.column, if key.descending { "desc" } else { "asc" }))
+                .collect::<Vec<_>>()
+                .join(",");
+            params.push(("sort".to_string(), sort));
+        }
+        for (column, text) in &self.filters {
+            if !text.trim().is_empty() {
+                params.push((format!("filter.{}", column), text.trim().to_string()));
+            }
+        }
+        params
+    }
+
+    // Yerel dosya: temel sorguyu saran WHERE ve ORDER BY cümleleri ile bağlanacak parametreler.
+    // Sütun adları tırnaklanır, değerler her zaman parametre olarak bağlanır. `key_column`
+    // verilirse sıralamanın son anahtarıdır; sunucu da sayfaları aynı şekilde sıralar.
+    #[cfg(all(feature = "local-sqlite", not(target_arch = "wasm32")))]
+    pub fn to_sql(&self, base_sql: &str, key_column: Option<&str>) -> (String, Vec<rusqlite::types::Value>) {
+        use super::schema::quote_identifier;
+        use rusqlite::types::Value as SqlValue;
+
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        for (column, filter) in self.parsed_filters() {
+            let column = quote_identifier(column);
+            match filter {
+                Filter::Contains(needle) => {
+                    let escaped = needle.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+                    conditions.push(format!("CAST({} AS TEXT) LIKE '%' || ? || '%' ESCAPE '\\'", column));
+                    params.push(SqlValue::Text(escaped));
+                }
+                Filter::Equals(expected) => {
+                    conditions.push(format!("CAST({} AS TEXT) = ?", column));
+                    params.push(SqlValue::Text(expected));
+                }
+                Filter::Range { min, max } => {
+                    for (bound, greater) in [(min, true), (max, false)] {
+                        if let Some(bound) = bound {
+                            let op = match (greater, bound.inclusive) {
+                                (true, true) => ">=",
+                                (true, false) => ">",
+                                (false, true) => "<=",
+                                (false, false) => "<",
+                            };
+                            conditions.push(format!("{} {} ?", column, op));
+                            params.push(SqlValue::Real(bound.value));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut sql = format!("SELECT * FROM ({})", base_sql);
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        let mut order: Vec<String> = self
+            .sort
+            .iter()
+            .map(|key| format!("{} {}", quote_identifier(&key.column), if key.descending { "DESC" } else { "ASC" }))
+            .collect();
+        if let Some(key_column) = key_column.filter(|key_column| !self.sort.iter().any(|key| key.column == *key_column)) {
+            order.push(format!("{} ASC", quote_identifier(key_column)));
+        }
+        if !order.is_empty() {
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&order.join(", "));
+        }
+        (sql, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn bound(value: f64, inclusive: bool) -> Option<Bound> {
+        Some(Bound { value, inclusive })
+    }
+
+    fn range(min: Option<Bound>, max: Option<Bound>) -> Option<Filter> {
+        Some(Filter::Range { min, max })
+    }
+
+    fn contains(text: &str) -> Option<Filter> {
+        Some(Filter::Contains(text.to_string()))
+    }
+
+    #[test]
+    fn parses_each_filter_form() {
+        assert_eq!(Filter::parse("  "), None);
+        assert_eq!(Filter::parse(" Ali "), contains("Ali"));
+        assert_eq!(Filter::parse("= Ankara "), Some(Filter::Equals("Ankara".to_string())));
+        assert_eq!(Filter::parse("10..20"), range(bound(10.0, true), bound(20.0, true)));
+        assert_eq!(Filter::parse("-1.5 .. 2e3"), range(bound(-1.5, true), bound(2000.0, true)));
+        assert_eq!(Filter::parse("10.."), range(bound(10.0, true), None));
+        assert_eq!(Filter::parse("..20"), range(None, bound(20.0, true)));
+        assert_eq!(Filter::parse(">5"), range(bound(5.0, false), None));
+        assert_eq!(Filter::parse(">= 5"), range(bound(5.0, true), None));
+        assert_eq!(Filter::parse("<5"), range(None, bound(5.0, false)));
+        assert_eq!(Filter::parse("<=5"), range(None, bound(5.0, true)));
+    }
+
+    // server/index.ts parseFilter ile aynı kararlar: bunların hepsi "içerir" filtresidir.
+    #[test]
+    fn malformed_numbers_and_ranges_are_contains() {
+        for text in ["..", "1..2..3", "1..2..", "a..b", "1...", "inf..", "..NaN", ">inf", "<", ">=abc", "0x10..20"] {
+            assert_eq!(Filter::parse(text), contains(text), "{}", text);
+        }
+    }
+
+    #[test]
+    fn filters_match_values() {
+        assert!(Filter::parse("ali").unwrap().matches(&json!("Ali Veli")));
+        assert!(!Filter::parse("ali").unwrap().matches(&Value::Null));
+        assert!(Filter::parse("=42").unwrap().matches(&json!(42)));
+        assert!(!Filter::parse("=4").unwrap().matches(&json!(42)));
+        let between = Filter::parse("10..20").unwrap();
+        assert!(between.matches(&json!(10)) && between.matches(&json!("20")) && !between.matches(&json!(20.5)));
+        assert!(!between.matches(&json!("abc")));
+        assert!(!Filter::parse(">5").unwrap().matches(&json!(5)));
+    }
+
+    fn people() -> QueryResult {
+        QueryResult {
+            columns: vec!["Name".to_string(), "City".to_string(), "Age".to_string()],
+            rows: vec![
+                vec![json!("Ayşe"), json!("Ankara"), json!(34)],
+                vec![json!("Bora"), json!("İzmir"), json!(27)],
+                vec![json!("Can"), json!("Ankara"), Value::Null],
+                vec![json!("Deniz"), json!("Bursa"), json!(27)],
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn apply_filters_and_sorts_stably() {
+        let result = people();
+        let mut query = TableQuery::default();
+        assert_eq!(query.apply(&result), vec![0, 1, 2, 3]);
+
+        query.filters.insert("City".to_string(), "ank".to_string());
+        assert_eq!(query.apply(&result), vec![0, 2]);
+
+        query.filters.clear();
+        query.sort = vec![SortKey { column: "Age".to_string(), descending: false }];
+        // NULL önce gelir; eşit yaşlar geldikleri sırada kalır.
+        assert_eq!(query.apply(&result), vec![2, 1, 3, 0]);
+
+        query.sort = vec![
+            SortKey { column: "Age".to_string(), descending: true },
+            SortKey { column: "Name".to_string(), descending: true },
+        ];
+        assert_eq!(query.apply(&result), vec![0, 3, 1, 2]);
+
+        query.filters.insert("Age".to_string(), "..30".to_string());
+        assert_eq!(query.apply(&result), vec![3, 1]);
+    }
+
+    #[test]
+    fn apply_ignores_unknown_columns() {
+        let mut query = TableQuery::default();
+        query.filters.insert("Missing".to_string(), "x".to_string());
+        query.sort.push(SortKey { column: "Missing".to_string(), descending: true });
+        assert_eq!(query.apply(&people()), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn cycle_sort_goes_ascending_descending_off() {
+        let mut query = TableQuery::default();
+        query.cycle_sort("Name", false);
+        assert_eq!(query.sort_state("Name"), Some((0, false)));
+        query.cycle_sort("Name", false);
+        assert_eq!(query.sort_state("Name"), Some((0, true)));
+        query.cycle_sort("Name", false);
+        assert!(query.sort.is_empty());
+    }
+
+    #[test]
+    fn cycle_sort_additive_keeps_other_columns() {
+        let mut query = TableQuery::default();
+        query.cycle_sort("City", false);
+        query.cycle_sort("Age", true);
+        assert_eq!(query.sort_state("City"), Some((0, false)));
+        assert_eq!(query.sort_state("Age"), Some((1, false)));
+
+        query.cycle_sort("City", true);
+        assert_eq!(query.sort_state("City"), Some((0, true)));
+        query.cycle_sort("City", true);
+        assert_eq!(query.sort_state("City"), None);
+        assert_eq!(query.sort_state("Age"), Some((0, false)));
+
+        // Shift'siz tıklama diğer sıralamaları bırakır.
+        query.cycle_sort("Name", false);
+        assert_eq!(query.sort, vec![SortKey { column: "Name".to_string(), descending: false }]);
+    }
+}
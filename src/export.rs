@@ -0,0 +1,357 @@
+// Tablo sonuçlarını CSV, JSON ya da XLSX olarak dışa aktarır.
+// Native tarafta dosya kaydetme penceresi açılır, WASM tarafında dosya tarayıcıdan indirilir.
+// Dosya kaydetme/açma yardımcıları çalışma alanı dosyaları için de kullanılır.
+// Yalnızca verilen satır indeksleri (görünümdeki sıralama ve filtrelerle) yazılır.
+
+use eframe::egui;
+use serde_json::{Map, Value};
+
+use crate::datasource::{cell_text, QueryResult};
+use crate::i18n::{t, t_with};
+use crate::task::Task;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [ExportFormat::Csv, ExportFormat::Json, ExportFormat::Xlsx];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Xlsx => "Excel (XLSX)",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Json => "application/json",
+            ExportFormat::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+
+    pub fn encode(self, result: &QueryResult, rows: &[usize]) -> Result<Vec<u8>, String> {
+        match self {
+            ExportFormat::Csv => Ok(to_csv(result, rows).into_bytes()),
+            ExportFormat::Json => to_json(result, rows),
+            ExportFormat::Xlsx => to_xlsx(result, rows),
+        }
+    }
+}
+
+// Ayırıcı, tırnak ya da satır sonu içeren alanlar RFC 4180'e göre tırnak içine alınır.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn to_csv(result: &QueryResult, rows: &[usize]) -> String {
+    let mut csv = String::new();
+    let header: Vec<String> = result.columns.iter().map(|column| csv_field(column)).collect();
+    csv.push_str(&header.join(","));
+    csv.push_str("\r\n");
+    for &row in rows {
+        // NULL boş alan olarak yazılır.
+        let fields: Vec<String> = result.rows[row]
+            .iter()
+            .map(|value| if value.is_null() { String::new() } else { csv_field(&cell_text(value)) })
+            .collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+// Sunucunun eski biçimiyle aynı: sütun adı -> değer nesnelerinden oluşan bir dizi.
+fn to_json(result: &QueryResult, rows: &[usize]) -> Result<Vec<u8>, String> {
+    let objects: Vec<Value> = rows
+        .iter()
+        .map(|&row| {
+            let object: Map<String, Value> = result
+                .columns
+                .iter()
+                .cloned()
+                .zip(result.rows[row].iter().cloned())
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+    serde_json::to_vec_pretty(&objects).map_err(|e| format!("Failed to serialize rows: {}", e))
+}
+
+fn to_xlsx(result: &QueryResult, rows: &[usize]) -> Result<Vec<u8>, String> {
+    use rust_xlsxwriter::{Format, Workbook};
+
+    let xlsx_error = |e: rust_xlsxwriter::XlsxError| format!("Failed to write XLSX: {}", e);
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    let header_format = Format::new().set_bold();
+    for (col, name) in result.columns.iter().enumerate() {
+        sheet
+            .write_string_with_format(0, col as u16, name, &header_format)
+            .map_err(xlsx_error)?;
+    }
+    for (i, &row) in rows.iter().enumerate() {
+        let sheet_row = i as u32 + 1;
+        for (col, value) in result.rows[row].iter().enumerate() {
+            let col = col as u16;
+            // Sayılar ve mantıksal değerler Excel'de hesaplanabilsin diye metne çevrilmez.
+            match value {
+                Value::Null => {}
+                Value::Bool(b) => {
+                    sheet.write_boolean(sheet_row, col, *b).map_err(xlsx_error)?;
+                }
+                Value::Number(n) => match n.as_f64() {
+                    Some(n) => {
+                        sheet.write_number(sheet_row, col, n).map_err(xlsx_error)?;
+                    }
+                    None => {
+                        sheet.write_string(sheet_row, col, n.to_string()).map_err(xlsx_error)?;
+                    }
+                },
+                other => {
+                    sheet.write_string(sheet_row, col, cell_text(other)).map_err(xlsx_error)?;
+                }
+            }
+        }
+    }
+    sheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+    sheet.autofit();
+    workbook.save_to_buffer().map_err(xlsx_error)
+}
+
+// Dosya adında sorun çıkarabilecek karakterleri ayıklar ("Table: Orders" -> "Table_ Orders").
+pub(crate) fn file_name(title: &str, extension: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_') { c } else { '_' })
+        .collect();
+    let stem = stem.trim();
+    let stem = if stem.is_empty() { "export" } else { stem };
+    format!("{}.{}", stem, extension)
+}
+
+// Kaydetme penceresini açar ve dosyayı yazar. Kullanıcı vazgeçerse Ok(None) döner.
+// MIME türü yalnızca tarayıcı indirmesinde kullanılır.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn save_file(file_name: &str, label: &str, extension: &str, _mime_type: &str, bytes: &[u8]) -> Result<Option<String>, String> {
+    let Some(path) = rfd::FileDialog::new()
+        .set_file_name(file_name)
+        .add_filter(label, &[extension])
+        .save_file()
+    else {
+        return Ok(None);
+    };
+    std::fs::write(&path, bytes).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    Ok(Some(path.display().to_string()))
+}
+
+// Veriyi bir Blob'a koyar ve geçici bir <a download> bağlantısına tıklayarak indirir.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn save_file(file_name: &str, _label: &str, _extension: &str, mime_type: &str, bytes: &[u8]) -> Result<Option<String>, String> {
+    use eframe::wasm_bindgen::JsCast;
+    use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+    let js_error = |e: eframe::wasm_bindgen::JsValue| format!("Download failed: {:?}", e);
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options).map_err(js_error)?;
+    let url = Url::create_object_url_with_blob(&blob).map_err(js_error)?;
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| "Download failed: no document".to_string())?;
+    let anchor: HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| "Download failed: could not create link".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    // Bazı tarayıcılar indirmeyi tıklamadan sonra başlatır; adres hemen geri alınırsa indirme düşer.
+    let revoke = eframe::wasm_bindgen::closure::Closure::once_into_js(move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    web_sys::window()
+        .ok_or_else(|| "Download failed: no window".to_string())?
+        .set_timeout_with_callback_and_timeout_and_arguments_0(revoke.unchecked_ref(), 1000)
+        .map_err(js_error)?;
+    Ok(Some(file_name.to_string()))
+}
+
+// Seçilen metin dosyasının adı ve içeriği; kullanıcı vazgeçerse None.
+pub(crate) type OpenedFile = Result<Option<(String, String)>, String>;
+
+// Dosya açma penceresini gösterir. Native tarafta pencere kapanana kadar bekler,
+// WASM tarafında gizli bir <input type="file"> kullanıldığı için sonuç daha sonra gelir.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn open_file(_ctx: &egui::Context, label: &str, extension: &str) -> Task<OpenedFile> {
+    let opened = rfd::FileDialog::new().add_filter(label, &[extension]).pick_file().map(|path| {
+        std::fs::read_to_string(&path)
+            .map(|text| (path.display().to_string(), text))
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))
+    });
+    Task::ready(opened.transpose())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn open_file(ctx: &egui::Context, _label: &str, extension: &str) -> Task<OpenedFile> {
+    use eframe::wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use web_sys::HtmlInputElement;
+
+    let js_error = |e: JsValue| format!("Opening the file failed: {:?}", e);
+    let input = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("input").ok())
+        .and_then(|element| element.dyn_into::<HtmlInputElement>().ok());
+    let Some(input) = input else {
+        return Task::ready(Err("Opening the file failed: no document".to_string()));
+    };
+    input.set_type("file");
+    input.set_accept(&format!(".{}", extension));
+
+    // Dosya seçildiğinde ya da pencere kapatıldığında çözülen bir söz (Promise).
+    let chosen = js_sys::Promise::new(&mut |resolve, _reject| {
+        let on_change = Closure::once_into_js({
+            let resolve = resolve.clone();
+            move || {
+                let _ = resolve.call0(&JsValue::NULL);
+            }
+        });
+        let on_cancel = Closure::once_into_js(move || {
+            let _ = resolve.call0(&JsValue::NULL);
+        });
+        input.set_onchange(Some(on_change.unchecked_ref()));
+        // `cancel` olayının web-sys'te ayrı bir ayarlayıcısı olmadığı sürümler için dinleyici olarak eklenir.
+        let _ = input.add_event_listener_with_callback("cancel", on_cancel.unchecked_ref());
+    });
+    input.click();
+
+    Task::spawn_async(ctx, async move {
+        wasm_bindgen_futures::JsFuture::from(chosen).await.map_err(js_error)?;
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return Ok(None);
+        };
+        let text = wasm_bindgen_futures::JsFuture::from(file.text()).await.map_err(js_error)?;
+        Ok(Some((file.name(), text.as_string().unwrap_or_default())))
+    })
+}
+
+// Tablo görünümlerindeki "Export" menüsü; son dışa aktarmanın sonucunu yanında gösterir.
+#[derive(Default)]
+pub struct ExportMenu {
+    status: Option<Result<String, String>>,
+}
+
+impl ExportMenu {
+    // `rows` yalnızca bir biçim seçildiğinde çağrılır; dışa aktarılacak satırların sırasını verir.
+    pub fn ui(&mut self, ui: &mut egui::Ui, title: &str, result: &QueryResult, rows: impl FnOnce() -> Vec<usize>) {
+        let mut chosen = None;
+        ui.add_enabled_ui(!result.columns.is_empty(), |ui| {
+            ui.menu_button(t("export-menu"), |ui| {
+                for format in ExportFormat::ALL {
+                    if ui.button(format.label()).clicked() {
+                        chosen = Some(format);
+                        ui.close_menu();
+                    }
+                }
+            });
+        });
+
+        if let Some(format) = chosen {
+            let rows = rows();
+            let saved = format
+                .encode(result, &rows)
+                .and_then(|bytes| {
+                    save_file(&file_name(title, format.extension()), format.label(), format.extension(), format.mime_type(), &bytes)
+                });
+            self.status = match saved {
+                Ok(Some(target)) => Some(Ok(t_with("export-done", &[("count", rows.len().into()), ("target", target.into())]))),
+                Ok(None) => None,
+                Err(e) => Some(Err(e)),
+            };
+        }
+
+        match &self.status {
+            Some(Ok(message)) => {
+                ui.weak(message);
+            }
+            Some(Err(message)) => {
+                ui.colored_label(ui.visuals().error_fg_color, message);
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn result() -> QueryResult {
+        QueryResult {
+            columns: vec!["Name".to_string(), "Note, extra".to_string(), "Age".to_string()],
+            rows: vec![
+                vec![json!("Ayşe"), json!("says \"hi\""), json!(34)],
+                vec![json!("Bora"), json!("line 1\nline 2"), Value::Null],
+                vec![json!("Can"), Value::Null, json!(27)],
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(csv_field("a\r\nb"), "\"a\r\nb\"");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn to_csv_writes_crlf_rows_in_the_given_order() {
+        let csv = to_csv(&result(), &[2, 0, 1]);
+        assert_eq!(
+            csv,
+            "Name,\"Note, extra\",Age\r\n\
+             Can,,27\r\n\
+             Ayşe,\"says \"\"hi\"\"\",34\r\n\
+             Bora,\"line 1\nline 2\",\r\n"
+        );
+        // Yalnızca istenen satırlar yazılır.
+        assert_eq!(to_csv(&result(), &[]), "Name,\"Note, extra\",Age\r\n");
+    }
+
+    #[test]
+    fn to_json_keeps_column_order_and_nulls() {
+        let bytes = to_json(&result(), &[1]).unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value, json!([{ "Name": "Bora", "Note, extra": "line 1\nline 2", "Age": null }]));
+        let keys: Vec<&String> = value[0].as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["Name", "Note, extra", "Age"]);
+        assert_eq!(to_json(&result(), &[]).unwrap(), b"[]");
+    }
+}
@@ -0,0 +1,227 @@
+// Arayüz metinleri için ileti kataloğu (Fluent, `locales/*.ftl`) ve yerel ayara göre
+// sayı/tarih biçimlendirme. Kataloglar derlemeye gömülür; dil üst çubuktan çalışma anında
+// değiştirilir ve AppSettings ile saklanır.
+//
+// Eksik anahtarlar için zincir: seçili dil -> İngilizce -> verilen yedek metin ya da anahtarın kendisi.
+
+use std::cell::{Cell, RefCell};
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Turkish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Turkish];
+
+    fn code(self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::Turkish => "tr-TR",
+        }
+    }
+
+    // Dil menüsünde her dil kendi adıyla gösterilir.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Turkish => "Türkçe",
+        }
+    }
+
+    fn catalog(self) -> &'static str {
+        match self {
+            Language::English => include_str!("../locales/en.ftl"),
+            Language::Turkish => include_str!("../locales/tr.ftl"),
+        }
+    }
+
+    // Binlik ve ondalık ayırıcılar.
+    fn separators(self) -> (char, char) {
+        match self {
+            Language::English => (',', '.'),
+            Language::Turkish => ('.', ','),
+        }
+    }
+
+    fn fallback_chain(self) -> &'static [Language] {
+        match self {
+            Language::English => &[Language::English],
+            Language::Turkish => &[Language::Turkish, Language::English],
+        }
+    }
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let locale = language.code().parse().unwrap_or_default();
+    let mut bundle = FluentBundle::new(vec![locale]);
+    // Yön yalıtım karakterleri (U+2068/U+2069) egui'de kutu olarak görünür.
+    bundle.set_use_isolating(false);
+    // Hatalı satırlar ve yinelenen anahtarlar atlanır; geri kalan iletiler yine kullanılabilir.
+    // Kataloglar gömülü olduğundan bu hatalar testlerde yakalanır (bkz. tests::catalogs_*).
+    let resource = FluentResource::try_new(language.catalog().to_string()).unwrap_or_else(|(resource, _)| resource);
+    let _ = bundle.add_resource(resource);
+    bundle
+}
+
+// egui tek iş parçacığında çizildiği için dil ve kataloglar iş parçacığına yereldir.
+thread_local! {
+    static LANGUAGE: Cell<Language> = const { Cell::new(Language::English) };
+    static BUNDLES: RefCell<Vec<(Language, FluentBundle<FluentResource>)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn set_language(language: Language) {
+    LANGUAGE.with(|current| current.set(language));
+}
+
+pub fn language() -> Language {
+    LANGUAGE.with(Cell::get)
+}
+
+fn lookup(key: &str, args: Option<&FluentArgs>) -> Option<String> {
+    BUNDLES.with(|bundles| {
+        let mut bundles = bundles.borrow_mut();
+        for &language in language().fallback_chain() {
+            if !bundles.iter().any(|(loaded, _)| *loaded == language) {
+                bundles.push((language, bundle(language)));
+            }
+            let (_, bundle) = bundles.iter().find(|(loaded, _)| *loaded == language)?;
+            let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) else {
+                continue;
+            };
+            let mut errors = Vec::new();
+            return Some(bundle.format_pattern(pattern, args, &mut errors).into_owned());
+        }
+        None
+    })
+}
+
+// Çevrilmiş metin; hiçbir katalogda yoksa anahtarın kendisi gösterilir ki eksik çeviri fark edilsin.
+pub fn t(key: &str) -> String {
+    lookup(key, None).unwrap_or_else(|| key.to_string())
+}
+
+// Değişkenli ileti, ör. `t_with("rows-count", &[("count", rows.into())])`.
+pub fn t_with(key: &str, args: &[(&str, FluentValue)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, value.clone());
+    }
+    lookup(key, Some(&fluent_args)).unwrap_or_else(|| key.to_string())
+}
+
+// Katalogda olmayabilecek anahtarlar için (ör. uygulamayı gömen programın görünümleri).
+pub fn t_or(key: &str, fallback: &str) -> String {
+    lookup(key, None).unwrap_or_else(|| fallback.to_string())
+}
+
+// Görünümün varsayılan başlığı `view-<tür>` anahtarıyla çevrilir; kullanıcının verdiği
+// başlık olduğu gibi gösterilir.
+pub fn view_title(kind: &str, title: &str, default_title: &str) -> String {
+    if title == default_title {
+        t_or(&format!("view-{}", kind), default_title)
+    } else {
+        title.to_string()
+    }
+}
+
+// Sayının metnindeki ondalık ayırıcıyı ve binlik gruplamayı seçili dile çevirir
+// ("1234.5" -> "1,234.5" / "1.234,5"). Üslü gösterimler olduğu gibi bırakılır.
+pub fn localize_number(text: &str) -> String {
+    if text.contains(['e', 'E']) {
+        return text.to_string();
+    }
+    let (group, decimal) = language().separators();
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", text),
+    };
+    let (integer, fraction) = digits.split_once('.').map_or((digits, None), |(i, f)| (i, Some(f)));
+    let mut grouped = String::new();
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(group);
+        }
+        grouped.push(c);
+    }
+    match fraction {
+        Some(fraction) => format!("{}{}{}{}", sign, grouped, decimal, fraction),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+pub fn format_decimal(value: f64, decimals: usize) -> String {
+    localize_number(&format!("{:.*}", decimals, value))
+}
+
+// "YYYY-MM-DD" ile başlayan metni yerel tarih biçimine çevirir ("07/04/1996" / "04.07.1996");
+// saat kısmı olduğu gibi eklenir. Tarih değilse None döner.
+pub fn localize_date(text: &str) -> Option<String> {
+    let date = text.get(..10)?;
+    let rest = &text[10..];
+    if !rest.is_empty() && !rest.starts_with([' ', 'T']) {
+        return None;
+    }
+    let mut parts = date.split('-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    let numeric = |part: &str, len: usize| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+    if !(numeric(year, 4) && numeric(month, 2) && numeric(day, 2)) {
+        return None;
+    }
+    let time = rest.trim_start_matches('T').trim_start();
+    let date = match language() {
+        Language::English => format!("{}/{}/{}", month, day, year),
+        Language::Turkish => format!("{}.{}.{}", day, month, year),
+    };
+    Some(if time.is_empty() { date } else { format!("{} {}", date, time) })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fluent_syntax::ast::Entry;
+
+    use super::*;
+
+    fn keys(language: Language) -> BTreeSet<String> {
+        let resource = FluentResource::try_new(language.catalog().to_string())
+            .unwrap_or_else(|(_, errors)| panic!("errors in the {} catalog: {:?}", language.code(), errors));
+        let keys: Vec<String> = resource
+            .entries()
+            .filter_map(|entry| match entry {
+                Entry::Message(message) => Some(message.id.name.to_string()),
+                Entry::Term(term) => Some(format!("-{}", term.id.name)),
+                _ => None,
+            })
+            .collect();
+        let unique: BTreeSet<String> = keys.iter().cloned().collect();
+        assert_eq!(unique.len(), keys.len(), "duplicate messages in the {} catalog", language.code());
+        unique
+    }
+
+    #[test]
+    fn catalogs_parse_without_errors() {
+        for language in Language::ALL {
+            let resource = FluentResource::try_new(language.catalog().to_string()).map_err(|(_, errors)| errors);
+            assert!(resource.is_ok(), "{}: {:?}", language.code(), resource.err());
+            let mut bundle = FluentBundle::new(vec![language.code().parse().unwrap()]);
+            assert!(bundle.add_resource(resource.unwrap()).is_ok(), "{}", language.code());
+        }
+    }
+
+    #[test]
+    fn catalogs_have_the_same_keys() {
+        let english = keys(Language::English);
+        let turkish = keys(Language::Turkish);
+        let missing_in_turkish: Vec<_> = english.difference(&turkish).collect();
+        let missing_in_english: Vec<_> = turkish.difference(&english).collect();
+        assert!(missing_in_turkish.is_empty(), "missing in tr.ftl: {:?}", missing_in_turkish);
+        assert!(missing_in_english.is_empty(), "missing in en.ftl: {:?}", missing_in_english);
+    }
+}
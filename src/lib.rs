@@ -1,8 +1,21 @@
 mod app;
+mod commands;
+mod datasource;
+mod export;
+mod i18n;
+mod login;
+mod task;
+mod theme;
+mod trash;
 mod views;
 mod workspace;
 
-pub use app::Application;
+pub use app::{AppSettings, Application};
+// Canlı güncellemeleri uygulamak isteyen görünümler için (View::apply_changes).
+pub use datasource::changefeed::{ChangeBatch, ChangeEvent, ChangeOp};
+// Uygulamayı gömen programların kendi görünümlerini kaydedebilmesi için.
+pub use views::registry::{ViewDescriptor, ViewRegistry};
+pub use views::{PlaceWindow, View, ViewAction};
 #[cfg(target_arch = "wasm32")] // When compiling for web
 use {
     eframe::wasm_bindgen::{self, prelude::*, JsCast},
@@ -10,7 +23,9 @@ use {
 };
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
-pub async fn start(canvas_id: &str) -> std::result::Result<(), eframe::wasm_bindgen::JsValue> {
+// `server_url` JavaScript tarafında verilmezse (undefined) kaydedilmiş ayar kullanılır.
+// Boş dize, isteklerin sayfanın sunulduğu kökene gönderilmesini sağlar.
+pub async fn start(canvas_id: &str, server_url: Option<String>) -> std::result::Result<(), eframe::wasm_bindgen::JsValue> {
     let document = web_sys::window().unwrap().document().unwrap();
     let canvas = document.get_element_by_id(canvas_id).unwrap();
     let canvas: HtmlCanvasElement = canvas
@@ -23,7 +38,7 @@ pub async fn start(canvas_id: &str) -> std::result::Result<(), eframe::wasm_bind
         .start(
             canvas,
             web_options,
-            Box::new(|_cc| Ok(Box::new(Application::default()))),
+            Box::new(|cc| Ok(Box::new(Application::new(cc, server_url)))),
         )
         .await?;
     Ok(())
@@ -0,0 +1,180 @@
+// Veri sunucusuna oturum açma penceresi: kullanıcı adı ve parola (POST /login) ya da doğrudan
+// bir API belirteci. Sunucu 401 döndürdüğünde kendiliğinden, ⚙ Settings menüsünden de elle açılır.
+// Giriş yapılınca oturum açmayı bekleyen istekler yeni belirteçle yeniden gönderilir.
+
+use eframe::egui::{self, Id, Key, RichText};
+
+use crate::app::AppSettings;
+use crate::datasource::auth::{self, Login};
+use crate::datasource::FetchError;
+use crate::i18n::{t, t_with};
+use crate::task::{self, Task};
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Method {
+    #[default]
+    Password,
+    Token,
+}
+
+pub struct LoginDialog {
+    // auth::server_key biçiminde bağlantı adresi.
+    server: String,
+    method: Method,
+    username: String,
+    password: String,
+    token: String,
+    remember: bool,
+    task: Option<Task<Result<String, FetchError>>>,
+    error: Option<String>,
+    // İlk karede ilk boş alana odaklanılır.
+    focused: bool,
+}
+
+impl LoginDialog {
+    pub fn new(server: String) -> Self {
+        let previous = auth::login(&server);
+        Self {
+            method: match &previous {
+                Some(Login { user: None, .. }) => Method::Token,
+                _ => Method::Password,
+            },
+            username: previous.as_ref().and_then(|login| login.user.clone()).unwrap_or_default(),
+            password: String::new(),
+            token: String::new(),
+            remember: previous.is_none_or(|login| login.remember),
+            task: None,
+            error: None,
+            focused: false,
+            server,
+        }
+    }
+
+    pub fn server(&self) -> &str {
+        &self.server
+    }
+
+    fn can_submit(&self) -> bool {
+        self.task.is_none()
+            && match self.method {
+                Method::Password => !self.username.trim().is_empty() && !self.password.is_empty(),
+                Method::Token => !self.token.trim().is_empty(),
+            }
+    }
+
+    fn submit(&mut self, ctx: &egui::Context, settings: &AppSettings) -> bool {
+        self.error = None;
+        match self.method {
+            Method::Password => {
+                self.task = Some(auth::request_token(ctx, settings, &self.server, self.username.trim(), &self.password));
+                false
+            }
+            Method::Token => {
+                let login = Login { token: self.token.trim().to_string(), user: None, remember: self.remember };
+                auth::sign_in(&self.server, login);
+                true
+            }
+        }
+    }
+
+    // Pencere açık kaldıkça true döner. 401 yüzünden açılmış pencere girişsiz kapatılırsa
+    // oturum açmayı bekleyen istekler 401 hatasıyla sonuçlanır.
+    pub fn ui(&mut self, ctx: &egui::Context, settings: &AppSettings) -> bool {
+        if let Some(result) = task::take_ready(&mut self.task) {
+            match result {
+                Ok(token) => {
+                    let login = Login { token, user: Some(self.username.trim().to_string()), remember: self.remember };
+                    auth::sign_in(&self.server, login);
+                    return false;
+                }
+                Err(error) if error.is_unauthorized() => self.error = Some(t("login-invalid")),
+                Err(error) => self.error = Some(error.to_string()),
+            }
+        }
+
+        let prompt = auth::prompt().filter(|prompt| prompt.server == self.server);
+        let mut signed_in = false;
+        let mut cancel = false;
+        let modal = egui::Modal::new(Id::new("login_dialog")).show(ctx, |ui| {
+            ui.set_width(360.0);
+            ui.heading(t("login-title"));
+            let server = if self.server.is_empty() { t("settings-same-origin") } else { self.server.clone() };
+            ui.weak(t_with("login-server", &[("url", server.into())]));
+            match &prompt {
+                Some(prompt) if prompt.rejected => {
+                    ui.label(RichText::new(t("login-rejected")).color(ui.visuals().warn_fg_color));
+                }
+                Some(_) => {
+                    ui.label(t("login-required"));
+                }
+                None => {}
+            }
+            ui.add_space(6.0);
+
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.method, Method::Password, t("login-method-password"));
+                ui.selectable_value(&mut self.method, Method::Token, t("login-method-token"));
+            });
+            let enabled = self.task.is_none();
+            egui::Grid::new("login_fields").num_columns(2).spacing([8.0, 6.0]).show(ui, |ui| match self.method {
+                Method::Password => {
+                    ui.label(t("login-username"));
+                    let username = ui.add_enabled(enabled, egui::TextEdit::singleline(&mut self.username).desired_width(220.0));
+                    ui.end_row();
+                    ui.label(t("login-password"));
+                    let password = ui.add_enabled(enabled, egui::TextEdit::singleline(&mut self.password).password(true).desired_width(220.0));
+                    ui.end_row();
+                    if !std::mem::replace(&mut self.focused, true) {
+                        if self.username.is_empty() { username } else { password }.request_focus();
+                    }
+                }
+                Method::Token => {
+                    ui.label(t("login-token"));
+                    let token = ui.add_enabled(
+                        enabled,
+                        egui::TextEdit::singleline(&mut self.token)
+                            .password(true)
+                            .hint_text(t("login-token-hint"))
+                            .desired_width(220.0),
+                    );
+                    ui.end_row();
+                    if !std::mem::replace(&mut self.focused, true) {
+                        token.request_focus();
+                    }
+                }
+            });
+            // Belirteç düz metin olarak saklandığı için nerede durduğu açıkça söylenir.
+            let remember_hint = if cfg!(target_arch = "wasm32") { "login-remember-hint-web" } else { "login-remember-hint" };
+            ui.checkbox(&mut self.remember, t("login-remember")).on_hover_text(t(remember_hint));
+
+            if let Some(error) = &self.error {
+                ui.label(RichText::new(error).color(ui.visuals().error_fg_color));
+            }
+            ui.separator();
+            ui.horizontal(|ui| {
+                let submit = ui.add_enabled(self.can_submit(), egui::Button::new(t("login-sign-in"))).clicked()
+                    || (self.can_submit() && ui.input(|i| i.key_pressed(Key::Enter)));
+                if submit {
+                    signed_in = self.submit(ui.ctx(), settings);
+                }
+                if ui.button(t("login-cancel")).clicked() {
+                    cancel = true;
+                }
+                if self.task.is_some() {
+                    ui.spinner();
+                }
+            });
+        });
+
+        if signed_in {
+            return false;
+        }
+        if cancel || (modal.should_close() && self.task.is_none()) {
+            if prompt.is_some() {
+                auth::dismiss(&self.server);
+            }
+            return false;
+        }
+        true
+    }
+}
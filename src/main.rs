@@ -3,7 +3,22 @@
     windows_subsystem = "windows"
 )]
 
+// Komut satırından "--server-url <adres>" veya "--server-url=<adres>" okunur.
+fn server_url_from_args() -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--server-url" {
+            return args.next();
+        }
+        if let Some(url) = arg.strip_prefix("--server-url=") {
+            return Some(url.to_string());
+        }
+    }
+    None
+}
+
 fn main() -> Result<(), eframe::Error> {
+    let server_url = server_url_from_args();
     let native_options = eframe::NativeOptions {
         viewport: eframe::egui::ViewportBuilder::default()
             .with_inner_size([1024.0, 800.0]),
@@ -12,6 +27,6 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "Application Title",
         native_options,
-            Box::new(|_cc| Ok(Box::new(emartident_rust::Application::default()))),
+        Box::new(|cc| Ok(Box::new(emartident_rust::Application::new(cc, server_url)))),
     )
 }
@@ -0,0 +1,62 @@
+// Platformlar arası arka plan işi: native tarafta ayrı bir iş parçacığında,
+// WASM tarafında tarayıcının olay döngüsünde (spawn_local) çalışır.
+// Sonuç hazır olduğunda ctx.request_repaint() çağrılır; böylece tamamlanan istek
+// bir sonraki fare hareketini beklemeden hemen çizilir.
+
+use eframe::egui;
+use poll_promise::Promise;
+
+pub struct Task<T: Send + 'static>(Promise<T>);
+
+impl<T: Send + 'static> Task<T> {
+    // Zaten bilinen bir sonucu görev olarak sarar (ör. hemen dönen hatalar için).
+    pub fn ready(value: T) -> Self {
+        Self(Promise::from_ready(value))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_blocking(ctx: &egui::Context, job: impl FnOnce() -> T + Send + 'static) -> Self {
+        let (sender, promise) = Promise::new();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let result = job();
+            // Önce sonucu gönder, sonra yeniden çizim iste; tersi sırada bir sonraki kare
+            // sonucu henüz göremeyebilir.
+            sender.send(result);
+            ctx.request_repaint();
+        });
+        Self(promise)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_async(ctx: &egui::Context, job: impl std::future::Future<Output = T> + 'static) -> Self {
+        let (sender, promise) = Promise::new();
+        let ctx = ctx.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = job.await;
+            sender.send(result);
+            ctx.request_repaint();
+        });
+        Self(promise)
+    }
+}
+
+// Görev tamamlandıysa sonucunu alır ve yuvayı boşaltır; değilse görevi yerinde bırakır.
+pub fn take_ready<T: Send + 'static>(slot: &mut Option<Task<T>>) -> Option<T> {
+    let task = slot.take()?;
+    match task.0.try_take() {
+        Ok(value) => Some(value),
+        Err(promise) => {
+            *slot = Some(Task(promise));
+            None
+        }
+    }
+}
+
+// Bekleyen bir görev için tüm görünümlerde aynı görünen ilerleme göstergesi.
+pub fn spinner(ui: &mut egui::Ui, label: &str) {
+    ui.horizontal(|ui| {
+        ui.spinner();
+        ui.label(label);
+    });
+}
@@ -0,0 +1,271 @@
+// Uygulama teması: renkler, yazı boyutları, boşluklar ve gölgeler.
+// Tema AppSettings içinde kalıcı olarak saklanır ve değiştiği karede egui stiline uygulanır.
+// Hazır temalardan biri seçildikten sonra herhangi bir değer düzenlenirse tema "Custom" olur.
+
+use eframe::egui::{self, Color32, RichText, TextStyle};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::t;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ThemePreset {
+    Light,
+    Dark,
+    HighContrast,
+    Custom,
+}
+
+impl ThemePreset {
+    // Custom bir hazır tema değil, düzenlenmiş değerlerin adıdır; menülerde en sonda gösterilir.
+    pub const ALL: [ThemePreset; 4] = [ThemePreset::Light, ThemePreset::Dark, ThemePreset::HighContrast, ThemePreset::Custom];
+
+    pub fn label(self) -> String {
+        t(match self {
+            ThemePreset::Light => "theme-preset-light",
+            ThemePreset::Dark => "theme-preset-dark",
+            ThemePreset::HighContrast => "theme-preset-high-contrast",
+            ThemePreset::Custom => "theme-preset-custom",
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub preset: ThemePreset,
+    // true ise egui'nin koyu renkleri temel alınır.
+    pub dark: bool,
+    pub window_fill: Color32,
+    // Seçim, bağlantı ve odak rengi.
+    pub accent: Color32,
+    // Verilirse tüm metinler bu renkte çizilir (yüksek karşıtlık için).
+    pub text_color: Option<Color32>,
+    pub body_size: f32,
+    pub heading_size: f32,
+    pub monospace_size: f32,
+    pub small_size: f32,
+    pub item_spacing: egui::Vec2,
+    pub button_padding: egui::Vec2,
+    pub shadows: bool,
+    pub shadow_blur: u8,
+    pub shadow_offset: [i8; 2],
+    pub shadow_color: Color32,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::preset(ThemePreset::Dark)
+    }
+}
+
+impl Theme {
+    // Hazır temanın değerleri; Custom için koyu tema temel alınır.
+    pub fn preset(preset: ThemePreset) -> Self {
+        let style = egui::Style::default();
+        let size = |text_style: TextStyle| style.text_styles.get(&text_style).map_or(14.0, |font| font.size);
+        let (visuals, text_color) = match preset {
+            ThemePreset::Light => (egui::Visuals::light(), None),
+            ThemePreset::Dark | ThemePreset::Custom => (egui::Visuals::dark(), None),
+            ThemePreset::HighContrast => (egui::Visuals::dark(), Some(Color32::WHITE)),
+        };
+        let high_contrast = preset == ThemePreset::HighContrast;
+        Self {
+            preset,
+            dark: visuals.dark_mode,
+            window_fill: if high_contrast { Color32::BLACK } else { visuals.window_fill },
+            accent: if high_contrast { Color32::from_rgb(255, 210, 0) } else { visuals.selection.bg_fill },
+            text_color,
+            body_size: size(TextStyle::Body) + if high_contrast { 2.0 } else { 0.0 },
+            heading_size: size(TextStyle::Heading) + if high_contrast { 2.0 } else { 0.0 },
+            monospace_size: size(TextStyle::Monospace) + if high_contrast { 2.0 } else { 0.0 },
+            small_size: size(TextStyle::Small) + if high_contrast { 2.0 } else { 0.0 },
+            item_spacing: style.spacing.item_spacing,
+            button_padding: style.spacing.button_padding,
+            shadows: !high_contrast,
+            shadow_blur: visuals.window_shadow.blur,
+            shadow_offset: visuals.window_shadow.offset,
+            shadow_color: visuals.window_shadow.color,
+        }
+    }
+
+    // Temayı egui'nin koyu ya da açık stiline yazar ve o stili etkin yapar.
+    pub fn apply(&self, ctx: &egui::Context) {
+        let mut style = egui::Style {
+            visuals: if self.dark { egui::Visuals::dark() } else { egui::Visuals::light() },
+            ..Default::default()
+        };
+
+        let visuals = &mut style.visuals;
+        visuals.window_fill = self.window_fill;
+        visuals.selection.bg_fill = self.accent;
+        visuals.hyperlink_color = self.accent;
+        visuals.override_text_color = self.text_color;
+        if let Some(text_color) = self.text_color {
+            // Yüksek karşıtlıkta kenarlıklar da metin renginde ve kalın çizilir.
+            visuals.window_stroke = egui::Stroke::new(1.5, text_color);
+            visuals.widgets.noninteractive.bg_stroke = egui::Stroke::new(1.0, text_color);
+            visuals.widgets.inactive.bg_stroke = egui::Stroke::new(1.0, text_color);
+            visuals.selection.stroke = egui::Stroke::new(1.5, Color32::BLACK);
+        }
+        let shadow = egui::Shadow {
+            offset: self.shadow_offset,
+            blur: self.shadow_blur,
+            spread: 0,
+            color: self.shadow_color,
+        };
+        visuals.window_shadow = if self.shadows { shadow } else { egui::Shadow::NONE };
+        visuals.popup_shadow = if self.shadows { shadow } else { egui::Shadow::NONE };
+
+        for (text_style, size) in [
+            (TextStyle::Body, self.body_size),
+            (TextStyle::Button, self.body_size),
+            (TextStyle::Heading, self.heading_size),
+            (TextStyle::Monospace, self.monospace_size),
+            (TextStyle::Small, self.small_size),
+        ] {
+            if let Some(font) = style.text_styles.get_mut(&text_style) {
+                font.size = size;
+            }
+        }
+        style.spacing.item_spacing = self.item_spacing;
+        style.spacing.button_padding = self.button_padding;
+
+        let egui_theme = if self.dark { egui::Theme::Dark } else { egui::Theme::Light };
+        ctx.set_style_of(egui_theme, style);
+        ctx.set_theme(egui_theme);
+    }
+}
+
+fn color_row(ui: &mut egui::Ui, key: &str, color: &mut Color32) {
+    ui.label(t(key));
+    ui.color_edit_button_srgba(color);
+    ui.end_row();
+}
+
+// Tema düzenleyicisinin içeriği. Uygulama temayı değiştiği karede yeniden uyguladığı için
+// önizleme canlıdır.
+pub fn editor_ui(ui: &mut egui::Ui, theme: &mut Theme, rounding: &mut egui::CornerRadius) {
+    let before = theme.clone();
+
+    ui.horizontal_wrapped(|ui| {
+        for preset in ThemePreset::ALL {
+            if preset == ThemePreset::Custom {
+                continue;
+            }
+            if ui.selectable_label(theme.preset == preset, preset.label()).clicked() {
+                *theme = Theme::preset(preset);
+            }
+        }
+        if theme.preset == ThemePreset::Custom {
+            ui.label(RichText::new(ThemePreset::Custom.label()).strong());
+        }
+    });
+    ui.separator();
+
+    egui::Grid::new("theme_editor").num_columns(2).spacing([12.0, 6.0]).show(ui, |ui| {
+        ui.label(t("theme-base"));
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut theme.dark, false, t("theme-base-light"));
+            ui.radio_value(&mut theme.dark, true, t("theme-base-dark"));
+        });
+        ui.end_row();
+
+        color_row(ui, "theme-window-fill", &mut theme.window_fill);
+        color_row(ui, "theme-accent", &mut theme.accent);
+
+        ui.label(t("theme-text-color"));
+        ui.horizontal(|ui| {
+            let mut override_text = theme.text_color.is_some();
+            if ui.checkbox(&mut override_text, t("theme-override")).changed() {
+                theme.text_color = override_text.then_some(if theme.dark { Color32::WHITE } else { Color32::BLACK });
+            }
+            if let Some(color) = theme.text_color.as_mut() {
+                ui.color_edit_button_srgba(color);
+            }
+        });
+        ui.end_row();
+
+        ui.label(t("theme-window-rounding"));
+        ui.horizontal(|ui| {
+            for (label, corner) in [
+                ("↖", &mut rounding.nw),
+                ("↗", &mut rounding.ne),
+                ("↙", &mut rounding.sw),
+                ("↘", &mut rounding.se),
+            ] {
+                ui.label(label);
+                ui.add(egui::DragValue::new(corner).range(0..=32));
+            }
+        });
+        ui.end_row();
+
+        for (key, size) in [
+            ("theme-body-size", &mut theme.body_size),
+            ("theme-heading-size", &mut theme.heading_size),
+            ("theme-monospace-size", &mut theme.monospace_size),
+            ("theme-small-size", &mut theme.small_size),
+        ] {
+            ui.label(t(key));
+            ui.add(egui::Slider::new(size, 8.0..=32.0).suffix(" pt"));
+            ui.end_row();
+        }
+
+        ui.label(t("theme-item-spacing"));
+        ui.horizontal(|ui| {
+            ui.add(egui::DragValue::new(&mut theme.item_spacing.x).range(0.0..=24.0).prefix("x "));
+            ui.add(egui::DragValue::new(&mut theme.item_spacing.y).range(0.0..=24.0).prefix("y "));
+        });
+        ui.end_row();
+
+        ui.label(t("theme-button-padding"));
+        ui.horizontal(|ui| {
+            ui.add(egui::DragValue::new(&mut theme.button_padding.x).range(0.0..=24.0).prefix("x "));
+            ui.add(egui::DragValue::new(&mut theme.button_padding.y).range(0.0..=24.0).prefix("y "));
+        });
+        ui.end_row();
+
+        ui.label(t("theme-shadows"));
+        ui.checkbox(&mut theme.shadows, t("theme-shadows-windows-menus"));
+        ui.end_row();
+
+        if theme.shadows {
+            ui.label(t("theme-shadow-blur"));
+            ui.add(egui::Slider::new(&mut theme.shadow_blur, 0..=64));
+            ui.end_row();
+
+            ui.label(t("theme-shadow-offset"));
+            ui.horizontal(|ui| {
+                ui.add(egui::DragValue::new(&mut theme.shadow_offset[0]).range(-32..=32).prefix("x "));
+                ui.add(egui::DragValue::new(&mut theme.shadow_offset[1]).range(-32..=32).prefix("y "));
+            });
+            ui.end_row();
+
+            color_row(ui, "theme-shadow-color", &mut theme.shadow_color);
+        }
+    });
+
+    // Hazır temadan sonra bir değer elle değiştiyse tema artık hazır temalardan biri değildir.
+    let preset_picked = theme.preset != before.preset;
+    if !preset_picked && *theme != before {
+        theme.preset = ThemePreset::Custom;
+    }
+
+    ui.separator();
+    ui.label(RichText::new(t("theme-preview")).strong());
+    preview_ui(ui);
+}
+
+// Önizleme için sık kullanılan birkaç bileşen; değerleri kaydedilmez.
+fn preview_ui(ui: &mut egui::Ui) {
+    ui.heading(t("theme-preview-heading"));
+    ui.label(t("theme-preview-body"));
+    ui.horizontal(|ui| {
+        let _ = ui.button(t("theme-preview-button"));
+        let _ = ui.selectable_label(true, t("theme-preview-selected"));
+        let _ = ui.link(t("theme-preview-link"));
+        let mut checked = true;
+        ui.checkbox(&mut checked, t("theme-preview-checkbox"));
+    });
+    ui.label(RichText::new("SELECT * FROM Customers").monospace());
+    ui.small(t("theme-preview-small"));
+}
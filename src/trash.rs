@@ -0,0 +1,228 @@
+// Silinen çalışma alanlarının çöp kutusu. Silme onay sormadan yapılır; ardından ekranın altında
+// kısa süreliğine "Undo" bildirimi görünür. Silinen çalışma alanları üst çubuktaki 🗑 menüsünden
+// de geri alınabilir; çöp kutusu uygulama durumuyla birlikte kaydedilir.
+
+use eframe::egui::{self, Id, RichText};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{t, t_with};
+use crate::views::registry::ViewRegistry;
+use crate::workspace::Workspace;
+
+// Bundan eski kayıtlar kendiliğinden kalıcı olarak silinir.
+const MAX_ENTRIES: usize = 20;
+const TOAST_SECONDS: f64 = 8.0;
+
+#[derive(Serialize, Deserialize)]
+struct TrashedWorkspace {
+    workspace: Workspace,
+    // Silinmeden önceki sekme sırası; geri alınınca mümkünse aynı yere konur.
+    index: usize,
+}
+
+// Son silme işleminin bildirimi; "Undo" o işlemde silinen çalışma alanlarının hepsini geri getirir.
+struct Toast {
+    ids: Vec<u64>,
+    message: String,
+    expires_at: f64,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Trash {
+    // En yeni kayıt sondadır.
+    entries: Vec<TrashedWorkspace>,
+    #[serde(skip)]
+    toast: Option<Toast>,
+}
+
+impl Trash {
+    pub fn count(&self) -> usize {
+        self.entries.len()
+    }
+
+    // Tek bir işlemde silinen çalışma alanlarını (eski sıralarıyla) çöp kutusuna taşır.
+    pub fn discard(&mut self, ctx: &egui::Context, deleted: Vec<(usize, Workspace)>) {
+        let message = match deleted.as_slice() {
+            [] => return,
+            [(_, workspace)] => t_with("trash-deleted-one", &[("name", workspace.name.clone().into())]),
+            _ => t_with("trash-deleted-many", &[("count", deleted.len().into())]),
+        };
+        let ids = deleted.iter().map(|(_, workspace)| workspace.id).collect();
+        self.entries
+            .extend(deleted.into_iter().map(|(index, workspace)| TrashedWorkspace { workspace, index }));
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.drain(..self.entries.len() - MAX_ENTRIES);
+        }
+        self.toast = Some(Toast {
+            ids,
+            message,
+            expires_at: ctx.input(|i| i.time) + TOAST_SECONDS,
+        });
+    }
+
+    // Kimlikleri verilen çalışma alanlarını çöp kutusundan çıkarır; eski sıralarına göre sıralı döner.
+    pub fn take(&mut self, ids: &[u64]) -> Vec<(usize, Workspace)> {
+        let (taken, kept) = std::mem::take(&mut self.entries)
+            .into_iter()
+            .partition(|entry| ids.contains(&entry.workspace.id));
+        self.entries = kept;
+        if self.toast.as_ref().is_some_and(|toast| toast.ids.iter().any(|id| ids.contains(id))) {
+            self.toast = None;
+        }
+        let mut taken: Vec<(usize, Workspace)> = taken
+            .into_iter()
+            .map(|entry: TrashedWorkspace| (entry.index, entry.workspace))
+            .collect();
+        taken.sort_by_key(|(index, _)| *index);
+        taken
+    }
+
+    pub fn last_id(&self) -> Option<u64> {
+        self.entries.last().map(|entry| entry.workspace.id)
+    }
+
+    pub fn store_views(&mut self) {
+        for entry in self.entries.iter_mut() {
+            entry.workspace.store_views();
+        }
+    }
+
+    pub fn restore_views(&mut self, registry: &ViewRegistry) {
+        for entry in self.entries.iter_mut() {
+            entry.workspace.restore_views(registry);
+        }
+    }
+
+    // Üst çubuktaki menünün içeriği; geri alınması istenen çalışma alanlarının kimliklerini döndürür.
+    pub fn menu_ui(&mut self, ui: &mut egui::Ui) -> Vec<u64> {
+        let mut restore = Vec::new();
+        if self.entries.is_empty() {
+            ui.weak(t("trash-empty"));
+            return restore;
+        }
+        let mut remove = None;
+        for entry in self.entries.iter().rev() {
+            let workspace = &entry.workspace;
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(&workspace.name).strong());
+                ui.weak(t_with("trash-window-count", &[("count", workspace.view_count().into())]));
+                if ui.button(t("trash-restore")).clicked() {
+                    restore.push(workspace.id);
+                    ui.close_menu();
+                }
+                if ui.button("✖").on_hover_text(t("trash-delete-permanently")).clicked() {
+                    remove = Some(workspace.id);
+                }
+            });
+        }
+        if let Some(id) = remove {
+            self.take(&[id]);
+        }
+        ui.separator();
+        if ui.button(t("trash-empty-all")).clicked() {
+            self.entries.clear();
+            self.toast = None;
+            ui.close_menu();
+        }
+        restore
+    }
+
+    // Son silmenin "Undo" bildirimi; geri alınması istenen çalışma alanlarının kimliklerini döndürür.
+    pub fn toast_ui(&mut self, ctx: &egui::Context) -> Vec<u64> {
+        let Some(toast) = self.toast.as_ref() else {
+            return Vec::new();
+        };
+        let remaining = toast.expires_at - ctx.input(|i| i.time);
+        if remaining <= 0.0 {
+            self.toast = None;
+            return Vec::new();
+        }
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+
+        let mut undo = false;
+        let mut dismiss = false;
+        egui::Area::new(Id::new("trash_undo_toast"))
+            .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -24.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(&toast.message);
+                        undo = ui.button(t("trash-undo")).clicked();
+                        dismiss = ui.small_button("✖").clicked();
+                    });
+                });
+            });
+
+        if undo {
+            toast.ids.clone()
+        } else {
+            if dismiss {
+                self.toast = None;
+            }
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(id: u64) -> Workspace {
+        let mut workspace = Workspace::new_with_name(format!("Workspace {}", id));
+        workspace.id = id;
+        workspace
+    }
+
+    fn ids(taken: &[(usize, Workspace)]) -> Vec<(usize, u64)> {
+        taken.iter().map(|(index, workspace)| (*index, workspace.id)).collect()
+    }
+
+    #[test]
+    fn take_returns_workspaces_sorted_by_original_index() {
+        let ctx = egui::Context::default();
+        let mut trash = Trash::default();
+        trash.discard(&ctx, vec![(3, workspace(1))]);
+        trash.discard(&ctx, vec![(0, workspace(2)), (1, workspace(3))]);
+        assert_eq!(trash.count(), 3);
+        assert_eq!(trash.last_id(), Some(3));
+
+        let taken = trash.take(&[1, 3, 2]);
+        assert_eq!(ids(&taken), vec![(0, 2), (1, 3), (3, 1)]);
+        assert_eq!(trash.count(), 0);
+        // Çöp kutusunda olmayan kimlikler yok sayılır.
+        assert!(trash.take(&[1, 99]).is_empty());
+    }
+
+    #[test]
+    fn discard_keeps_only_the_newest_entries() {
+        let ctx = egui::Context::default();
+        let mut trash = Trash::default();
+        for id in 1..=MAX_ENTRIES as u64 + 5 {
+            trash.discard(&ctx, vec![(0, workspace(id))]);
+        }
+        assert_eq!(trash.count(), MAX_ENTRIES);
+        assert!(trash.take(&[1, 2, 3, 4, 5]).is_empty());
+        assert_eq!(trash.last_id(), Some(MAX_ENTRIES as u64 + 5));
+        assert_eq!(trash.take(&[6]).len(), 1);
+    }
+
+    #[test]
+    fn taking_a_deleted_workspace_clears_its_toast() {
+        let ctx = egui::Context::default();
+        let mut trash = Trash::default();
+        trash.discard(&ctx, vec![(0, workspace(1))]);
+        trash.discard(&ctx, vec![(0, workspace(2)), (1, workspace(3))]);
+        assert_eq!(trash.toast.as_ref().map(|toast| toast.ids.clone()), Some(vec![2, 3]));
+
+        // Bildirimdeki işleme ait olmayan bir geri alma bildirimi kapatmaz.
+        trash.take(&[1]);
+        assert!(trash.toast.is_some());
+
+        trash.take(&[3]);
+        assert!(trash.toast.is_none());
+        assert_eq!(trash.count(), 1);
+    }
+}
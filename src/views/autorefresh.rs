@@ -0,0 +1,144 @@
+// Veri görünümlerinin kendiliğinden yenilenme ayarı ve "son güncelleme" göstergesi.
+// Sayaç yalnızca görünüm çizilirken işler: gizli çalışma alanlarındaki, daraltılmış ya da
+// yerleşik düzende arka sekmede kalan görünümler çizilmediği için yenilenmez.
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{t, t_with};
+
+const PRESET_SECONDS: [u32; 4] = [5, 30, 60, 300];
+const DEFAULT_CUSTOM_SECONDS: u32 = 120;
+const MAX_CUSTOM_SECONDS: u32 = 24 * 60 * 60;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum Interval {
+    #[default]
+    Off,
+    Every(u32),
+    Custom(u32),
+}
+
+impl Interval {
+    fn seconds(self) -> Option<u32> {
+        match self {
+            Interval::Off => None,
+            Interval::Every(seconds) | Interval::Custom(seconds) => Some(seconds.max(1)),
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Interval::Off => t("refresh-off"),
+            Interval::Every(seconds) => duration_label(seconds),
+            Interval::Custom(_) => t("refresh-custom"),
+        }
+    }
+}
+
+fn duration_label(seconds: u32) -> String {
+    if seconds >= 60 && seconds.is_multiple_of(60) {
+        t_with("refresh-minutes", &[("count", (seconds / 60).into())])
+    } else {
+        t_with("refresh-seconds", &[("count", seconds.into())])
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoRefresh {
+    interval: Interval,
+    // egui saatine göre son başarılı yenileme; oturuma özgü olduğu için kaydedilmez.
+    #[serde(skip)]
+    updated_at: Option<f64>,
+    // Son isteğin başladığı an; başarısız bir istek de bir sonraki denemeyi bir aralık bekletir.
+    #[serde(skip)]
+    started_at: Option<f64>,
+}
+
+impl AutoRefresh {
+    // Görünüm (elle ya da kendiliğinden) bir istek başlattığında çağırır.
+    pub fn started(&mut self, ctx: &egui::Context) {
+        self.started_at = Some(ctx.input(|i| i.time));
+    }
+
+    // İstek başarıyla tamamlandığında çağrılır.
+    pub fn updated(&mut self, ctx: &egui::Context) {
+        self.updated_at = Some(ctx.input(|i| i.time));
+    }
+
+    // Yenileme zamanı geldiyse true döner. İstek sürerken, görünüm `paused` dediğinde
+    // (ör. kaydedilmemiş düzenlemeler), bir metin kutusuna yazılırken ya da pencere
+    // simge durumundayken beklenir.
+    pub fn due(&self, ctx: &egui::Context, busy: bool, paused: bool) -> bool {
+        let Some(seconds) = self.interval.seconds() else {
+            return false;
+        };
+        let minimized = ctx.input(|i| i.viewport().minimized == Some(true));
+        if busy || paused || minimized || ctx.wants_keyboard_input() {
+            return false;
+        }
+        let now = ctx.input(|i| i.time);
+        let remaining = match self.started_at {
+            Some(started_at) => started_at + seconds as f64 - now,
+            None => 0.0,
+        };
+        if remaining > 0.0 {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+            return false;
+        }
+        true
+    }
+
+    // Aralık seçici ve "N saniye önce güncellendi" göstergesi; `busy` iken küçük bir gösterge döner.
+    pub fn ui(&mut self, ui: &mut egui::Ui, busy: bool, paused: bool) {
+        ui.horizontal(|ui| {
+            ui.label(t("refresh-auto"));
+            egui::ComboBox::from_id_salt(ui.id().with("auto_refresh"))
+                .selected_text(self.interval.label())
+                .width(80.0)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.interval, Interval::Off, Interval::Off.label());
+                    for seconds in PRESET_SECONDS {
+                        let interval = Interval::Every(seconds);
+                        ui.selectable_value(&mut self.interval, interval, interval.label());
+                    }
+                    let custom = match self.interval {
+                        Interval::Custom(seconds) => Interval::Custom(seconds),
+                        _ => Interval::Custom(DEFAULT_CUSTOM_SECONDS),
+                    };
+                    ui.selectable_value(&mut self.interval, custom, custom.label());
+                });
+            if let Interval::Custom(seconds) = &mut self.interval {
+                ui.add(
+                    egui::DragValue::new(seconds)
+                        .range(1..=MAX_CUSTOM_SECONDS)
+                        .suffix(t("refresh-seconds-suffix")),
+                );
+            }
+
+            if busy {
+                ui.spinner();
+            } else if paused && self.interval != Interval::Off {
+                ui.weak(t("refresh-paused"));
+            } else if let Some(updated_at) = self.updated_at {
+                let elapsed = (ui.input(|i| i.time) - updated_at).max(0.0) as u64;
+                ui.weak(updated_label(elapsed));
+                // Gösterge bir sonraki değişeceği anda yeniden çizilir.
+                let step = if elapsed < 60 { 1 } else if elapsed < 3600 { 60 } else { 3600 };
+                let wait = step - elapsed % step;
+                ui.ctx().request_repaint_after(std::time::Duration::from_secs(wait));
+            }
+        });
+    }
+}
+
+fn updated_label(elapsed: u64) -> String {
+    if elapsed < 60 {
+        t_with("refresh-updated-seconds", &[("count", elapsed.into())])
+    } else if elapsed < 3600 {
+        t_with("refresh-updated-minutes", &[("count", (elapsed / 60).into())])
+    } else {
+        t_with("refresh-updated-hours", &[("count", (elapsed / 3600).into())])
+    }
+}
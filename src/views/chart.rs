@@ -0,0 +1,567 @@
+use std::collections::HashMap;
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use super::autorefresh::AutoRefresh;
+use super::chartpaint;
+use super::{errorpanel, PlaceWindow, View};
+use crate::app::AppSettings;
+use crate::datasource::{self, cell_text, DataSource, FetchError, QueryResult};
+use crate::i18n::{self, t, t_with};
+use crate::task::{self, Task};
+
+pub const WINDOW_TITLE: &str = "Chart";
+pub const VIEW_KIND: &str = "chart";
+
+const CHART_HEIGHT: f32 = 300.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum ChartType {
+    #[default]
+    Bar,
+    Line,
+    Pie,
+    Scatter,
+}
+
+impl ChartType {
+    const ALL: [ChartType; 4] = [ChartType::Bar, ChartType::Line, ChartType::Pie, ChartType::Scatter];
+
+    fn label(self) -> String {
+        t(match self {
+            ChartType::Bar => "chart-type-bar",
+            ChartType::Line => "chart-type-line",
+            ChartType::Pie => "chart-type-pie",
+            ChartType::Scatter => "chart-type-scatter",
+        })
+    }
+}
+
+// Aynı X değerine sahip satırların Y değerlerinin nasıl birleştirileceği.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum Aggregation {
+    Count,
+    #[default]
+    Sum,
+    Average,
+    Min,
+    Max,
+}
+
+impl Aggregation {
+    const ALL: [Aggregation; 5] = [Aggregation::Count, Aggregation::Sum, Aggregation::Average, Aggregation::Min, Aggregation::Max];
+
+    fn label(self) -> String {
+        t(match self {
+            Aggregation::Count => "chart-aggregation-count",
+            Aggregation::Sum => "chart-aggregation-sum",
+            Aggregation::Average => "chart-aggregation-average",
+            Aggregation::Min => "chart-aggregation-min",
+            Aggregation::Max => "chart-aggregation-max",
+        })
+    }
+
+    fn apply(self, values: &[f64]) -> f64 {
+        match self {
+            Aggregation::Count => values.len() as f64,
+            Aggregation::Sum => values.iter().sum(),
+            Aggregation::Average if values.is_empty() => 0.0,
+            Aggregation::Average => values.iter().sum::<f64>() / values.len() as f64,
+            Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Aggregation::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum CategoryOrder {
+    // X değerine göre (aylar gibi sıralı değerler için).
+    #[default]
+    ByCategory,
+    // Büyükten küçüğe (ör. "en çok satan 10 ürün").
+    ByValueDescending,
+}
+
+// Grafiğin sonuca nasıl bağlandığı. `y_column` boşsa satırlar sayılır.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct ChartConfig {
+    chart_type: ChartType,
+    x_column: String,
+    y_column: Option<String>,
+    aggregation: Aggregation,
+    order: CategoryOrder,
+    limit: usize, // 0: tüm kategoriler
+}
+
+impl Default for ChartConfig {
+    fn default() -> Self {
+        Self {
+            chart_type: ChartType::Bar,
+            x_column: String::new(),
+            y_column: None,
+            aggregation: Aggregation::Sum,
+            order: CategoryOrder::ByCategory,
+            limit: 0,
+        }
+    }
+}
+
+impl ChartConfig {
+    fn series_name(&self) -> String {
+        match &self.y_column {
+            Some(y) if self.aggregation != Aggregation::Count => {
+                t_with("chart-series", &[("aggregation", self.aggregation.label().into()), ("column", y.as_str().into())])
+            }
+            _ => Aggregation::Count.label(),
+        }
+    }
+}
+
+// Hazır Northwind grafikleri: sorgu ve grafiğin ona bağlanışı.
+struct Preset {
+    // Katalogdaki adının anahtarı.
+    name: &'static str,
+    sql: &'static str,
+    chart_type: ChartType,
+    x_column: &'static str,
+    y_column: Option<&'static str>,
+    aggregation: Aggregation,
+    order: CategoryOrder,
+    limit: usize,
+}
+
+const PRESETS: [Preset; 5] = [
+    Preset {
+        name: "chart-preset-orders-per-month",
+        sql: "SELECT strftime('%Y-%m', OrderDate) AS Month, OrderID FROM Orders",
+        chart_type: ChartType::Line,
+        x_column: "Month",
+        y_column: None,
+        aggregation: Aggregation::Count,
+        order: CategoryOrder::ByCategory,
+        limit: 0,
+    },
+    Preset {
+        name: "chart-preset-revenue-per-country",
+        sql: "SELECT c.Country, d.Quantity * p.Price AS Revenue FROM OrderDetails d \
+              JOIN Orders o ON o.OrderID = d.OrderID \
+              JOIN Customers c ON c.CustomerID = o.CustomerID \
+              JOIN Products p ON p.ProductID = d.ProductID",
+        chart_type: ChartType::Bar,
+        x_column: "Country",
+        y_column: Some("Revenue"),
+        aggregation: Aggregation::Sum,
+        order: CategoryOrder::ByValueDescending,
+        limit: 0,
+    },
+    Preset {
+        name: "chart-preset-top-products",
+        sql: "SELECT p.ProductName AS Product, d.Quantity * p.Price AS Revenue FROM OrderDetails d \
+              JOIN Products p ON p.ProductID = d.ProductID",
+        chart_type: ChartType::Bar,
+        x_column: "Product",
+        y_column: Some("Revenue"),
+        aggregation: Aggregation::Sum,
+        order: CategoryOrder::ByValueDescending,
+        limit: 10,
+    },
+    Preset {
+        name: "chart-preset-revenue-share",
+        sql: "SELECT c.CategoryName AS Category, d.Quantity * p.Price AS Revenue FROM OrderDetails d \
+              JOIN Products p ON p.ProductID = d.ProductID \
+              JOIN Categories c ON c.CategoryID = p.CategoryID",
+        chart_type: ChartType::Pie,
+        x_column: "Category",
+        y_column: Some("Revenue"),
+        aggregation: Aggregation::Sum,
+        order: CategoryOrder::ByValueDescending,
+        limit: 0,
+    },
+    Preset {
+        name: "chart-preset-price-vs-quantity",
+        sql: "SELECT p.ProductName AS Product, p.Price, SUM(d.Quantity) AS QuantitySold FROM Products p \
+              JOIN OrderDetails d ON d.ProductID = p.ProductID GROUP BY p.ProductID",
+        chart_type: ChartType::Scatter,
+        x_column: "Price",
+        y_column: Some("QuantitySold"),
+        aggregation: Aggregation::Sum,
+        order: CategoryOrder::ByCategory,
+        limit: 0,
+    },
+];
+
+// Çizime hazır veri; yalnızca sonuç ya da ayarlar değiştiğinde yeniden hesaplanır.
+enum ChartData {
+    Categories { labels: Vec<String>, values: Vec<f64> },
+    // Dağılım grafiği: sayısal (x, y) çiftleri ve her noktanın ilk metin sütunundaki değeri.
+    Points { points: Vec<[f64; 2]>, labels: Vec<String> },
+}
+
+// Hücre değerini sayıya çevirir; sayı gibi yazılmış metinler de kabul edilir.
+fn numeric(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        serde_json::Value::Bool(b) => Some(*b as u8 as f64),
+        _ => None,
+    }
+}
+
+fn compute(result: &QueryResult, config: &ChartConfig) -> Result<ChartData, String> {
+    let x_col = result
+        .column_index(&config.x_column)
+        .ok_or_else(|| t("chart-choose-x"))?;
+    let y_col = match &config.y_column {
+        Some(name) => Some(result.column_index(name).ok_or_else(|| t_with("chart-missing-column", &[("column", name.as_str().into())]))?),
+        None => None,
+    };
+
+    if config.chart_type == ChartType::Scatter {
+        let y_col = y_col.ok_or_else(|| t("chart-scatter-needs-y"))?;
+        // Nokta etiketi olarak X ve Y dışındaki ilk metin sütunu kullanılır.
+        let label_col = (0..result.columns.len()).find(|col| {
+            *col != x_col && *col != y_col && result.rows.first().is_some_and(|row| row[*col].is_string())
+        });
+        let mut points = Vec::new();
+        let mut labels = Vec::new();
+        for row in &result.rows {
+            if let (Some(x), Some(y)) = (numeric(&row[x_col]), numeric(&row[y_col])) {
+                points.push([x, y]);
+                labels.push(label_col.map(|col| cell_text(&row[col])).unwrap_or_default());
+            }
+        }
+        return Ok(ChartData::Points { points, labels });
+    }
+
+    // Kategoriler ilk görüldükleri sırayla toplanır.
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<(String, Vec<f64>)> = Vec::new();
+    for row in &result.rows {
+        let label = cell_text(&row[x_col]);
+        let value = match y_col {
+            Some(y_col) if config.aggregation != Aggregation::Count => match numeric(&row[y_col]) {
+                Some(value) => value,
+                None => continue,
+            },
+            _ => 1.0,
+        };
+        let i = *index.entry(label.clone()).or_insert_with(|| {
+            groups.push((label, Vec::new()));
+            groups.len() - 1
+        });
+        groups[i].1.push(value);
+    }
+    let aggregation = if y_col.is_some() { config.aggregation } else { Aggregation::Count };
+    let mut categories: Vec<(String, f64)> = groups
+        .into_iter()
+        .map(|(label, values)| (label, aggregation.apply(&values)))
+        .collect();
+    match config.order {
+        CategoryOrder::ByCategory => categories.sort_by(|a, b| a.0.cmp(&b.0)),
+        CategoryOrder::ByValueDescending => categories.sort_by(|a, b| b.1.total_cmp(&a.1)),
+    }
+    if config.limit > 0 {
+        categories.truncate(config.limit);
+    }
+    let (labels, values) = categories.into_iter().unzip();
+    Ok(ChartData::Categories { labels, values })
+}
+
+// Bir sorgunun sonucunu çubuk, çizgi, pasta ya da dağılım grafiği olarak gösterir.
+// X/Y sütunları, birleştirme ve sıralama sonuçtan seçilir; hazır Northwind grafikleri de vardır.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChartView {
+    title: String,
+    source: DataSource,
+    sql: String,
+    config: ChartConfig,
+    result: QueryResult,
+    auto_refresh: AutoRefresh,
+    #[serde(skip)]
+    run_on_open: bool,
+    #[serde(skip)]
+    error: Option<FetchError>,
+    #[serde(skip)]
+    query_task: Option<Task<Result<String, FetchError>>>,
+    // Son hesaplanan veri ve hangi ayarlarla hesaplandığı.
+    #[serde(skip)]
+    data: Option<(ChartConfig, Result<ChartData, String>)>,
+}
+
+impl Default for ChartView {
+    fn default() -> Self {
+        let mut chart = Self {
+            title: WINDOW_TITLE.to_string(),
+            source: DataSource::default(),
+            sql: String::new(),
+            config: ChartConfig::default(),
+            result: QueryResult::default(),
+            auto_refresh: AutoRefresh::default(),
+            run_on_open: true,
+            error: None,
+            query_task: None,
+            data: None,
+        };
+        chart.apply_preset(&PRESETS[0]);
+        chart.title = WINDOW_TITLE.to_string();
+        chart
+    }
+}
+
+impl ChartView {
+    fn apply_preset(&mut self, preset: &Preset) {
+        self.title = t_with("chart-preset-title", &[("preset", t(preset.name).into())]);
+        self.sql = preset.sql.to_string();
+        self.config = ChartConfig {
+            chart_type: preset.chart_type,
+            x_column: preset.x_column.to_string(),
+            y_column: preset.y_column.map(String::from),
+            aggregation: preset.aggregation,
+            order: preset.order,
+            limit: preset.limit,
+        };
+    }
+
+    fn run_query(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.error = None;
+        self.auto_refresh.started(ctx);
+        self.query_task = Some(self.source.run_query(ctx, settings, &self.sql));
+    }
+
+    fn process_result(&mut self, raw_json_result: Result<String, FetchError>) {
+        let parsed = raw_json_result.and_then(|raw_json| {
+            let value = serde_json::from_str::<serde_json::Value>(&raw_json).map_err(|e| {
+                FetchError::InvalidJson { message: format!("Response is not valid JSON: {}", e) }
+            })?;
+            QueryResult::from_json(value).map_err(|message| FetchError::SchemaMismatch { message })
+        });
+        match parsed {
+            Ok(result) => self.result = result,
+            Err(e) => {
+                self.result = QueryResult::default();
+                self.error = Some(e);
+            }
+        }
+        self.data = None;
+    }
+
+    fn column_combo(ui: &mut egui::Ui, id: &str, label: &str, value: &mut String, columns: &[String]) {
+        ui.label(label);
+        egui::ComboBox::from_id_salt(ui.id().with(id))
+            .selected_text(value.as_str())
+            .show_ui(ui, |ui| {
+                for column in columns {
+                    ui.selectable_value(value, column.clone(), column);
+                }
+            });
+    }
+
+    fn config_ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            for chart_type in ChartType::ALL {
+                ui.selectable_value(&mut self.config.chart_type, chart_type, chart_type.label());
+            }
+        });
+        let columns = self.result.columns.clone();
+        ui.horizontal_wrapped(|ui| {
+            Self::column_combo(ui, "x_column", &t("chart-x"), &mut self.config.x_column, &columns);
+
+            ui.label(t("chart-y"));
+            let y_text = self.config.y_column.clone().unwrap_or_else(|| t("chart-row-count"));
+            egui::ComboBox::from_id_salt(ui.id().with("y_column"))
+                .selected_text(y_text)
+                .show_ui(ui, |ui| {
+                    if self.config.chart_type != ChartType::Scatter {
+                        ui.selectable_value(&mut self.config.y_column, None, t("chart-row-count"));
+                    }
+                    for column in &columns {
+                        ui.selectable_value(&mut self.config.y_column, Some(column.clone()), column);
+                    }
+                });
+
+            if self.config.chart_type != ChartType::Scatter {
+                ui.add_enabled_ui(self.config.y_column.is_some(), |ui| {
+                    egui::ComboBox::from_id_salt(ui.id().with("aggregation"))
+                        .selected_text(self.config.aggregation.label())
+                        .show_ui(ui, |ui| {
+                            for aggregation in Aggregation::ALL {
+                                ui.selectable_value(&mut self.config.aggregation, aggregation, aggregation.label());
+                            }
+                        });
+                });
+
+                ui.separator();
+                ui.selectable_value(&mut self.config.order, CategoryOrder::ByCategory, t("chart-order-category"));
+                ui.selectable_value(&mut self.config.order, CategoryOrder::ByValueDescending, t("chart-order-largest"));
+                ui.label(t("chart-top"));
+                ui.add(egui::DragValue::new(&mut self.config.limit).range(0..=1000))
+                    .on_hover_text(t("chart-top-hint"));
+            }
+        });
+    }
+
+    fn chart_ui(&mut self, ui: &mut egui::Ui) {
+        if self.data.as_ref().map(|(config, _)| config) != Some(&self.config) {
+            self.data = Some((self.config.clone(), compute(&self.result, &self.config)));
+        }
+        let Some((config, data)) = &self.data else {
+            return;
+        };
+        let series = config.series_name();
+        match data {
+            Err(message) => {
+                ui.weak(message);
+            }
+            Ok(ChartData::Categories { labels, .. }) if labels.is_empty() => {
+                ui.weak(t("chart-no-rows"));
+            }
+            Ok(ChartData::Categories { labels, values }) => {
+                match config.chart_type {
+                    ChartType::Bar => chartpaint::bar(ui, CHART_HEIGHT, labels, values, &series),
+                    ChartType::Line => chartpaint::line(ui, CHART_HEIGHT, labels, values, &series),
+                    _ => chartpaint::pie(ui, CHART_HEIGHT, labels, values, &series),
+                }
+                let legend: Vec<(String, egui::Color32)> = if config.chart_type == ChartType::Pie {
+                    let total: f64 = values.iter().map(|v| v.max(0.0)).sum();
+                    labels
+                        .iter()
+                        .zip(values)
+                        .enumerate()
+                        .map(|(i, (label, value))| {
+                            let share = if total > 0.0 { value.max(0.0) / total * 100.0 } else { 0.0 };
+                            (format!("{} ({}%)", label, i18n::format_decimal(share, 1)), chartpaint::color(i))
+                        })
+                        .collect()
+                } else {
+                    vec![(t_with("chart-legend-series", &[("series", series.as_str().into()), ("column", config.x_column.as_str().into())]), chartpaint::color(0))]
+                };
+                chartpaint::legend(ui, &legend);
+            }
+            Ok(ChartData::Points { points, labels }) => {
+                let y_name = config.y_column.clone().unwrap_or_default();
+                chartpaint::scatter(ui, CHART_HEIGHT, points, labels, &config.x_column, &y_name);
+                let legend = t_with(
+                    "chart-legend-points",
+                    &[("y", y_name.as_str().into()), ("x", config.x_column.as_str().into()), ("count", points.len().into())],
+                );
+                chartpaint::legend(ui, &[(legend, chartpaint::color(0))]);
+            }
+        }
+    }
+}
+
+impl View for ChartView {
+    fn title(&self) -> String {
+        i18n::view_title(VIEW_KIND, &self.title, WINDOW_TITLE)
+    }
+
+    fn kind(&self) -> &'static str {
+        VIEW_KIND
+    }
+
+    fn refresh(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.run_query(ctx, settings);
+    }
+
+    fn can_rename(&self) -> bool {
+        true
+    }
+
+    fn rename(&mut self, title: String) {
+        self.title = title;
+    }
+
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) {
+        if let Ok(restored) = serde_json::from_value::<ChartView>(state) {
+            *self = restored;
+        }
+    }
+
+    fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings) {
+        egui::Window::new(self.title())
+            .id(id)
+            .default_width(560.0)
+            .frame(egui::Frame::window(&ctx.style())
+                .corner_radius(settings.global_rounding)
+            )
+            .placed(ctx, id)
+            .open(open)
+            .show(ctx, |ui| {
+                self.ui(ui, settings);
+            });
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, settings: &AppSettings) {
+        // Kaydedilmiş sonuç varsa yeniden sorgulamadan çizilir.
+        if self.run_on_open && self.result.columns.is_empty() {
+            self.run_query(ui.ctx(), settings);
+        }
+        self.run_on_open = false;
+
+        ui.horizontal(|ui| {
+            ui.label(t("chart-preset"));
+            egui::ComboBox::from_id_salt(ui.id().with("preset"))
+                .selected_text(t("chart-choose"))
+                .show_ui(ui, |ui| {
+                    for preset in &PRESETS {
+                        if ui.selectable_label(false, t(preset.name)).clicked() {
+                            self.apply_preset(preset);
+                            self.run_query(ui.ctx(), settings);
+                        }
+                    }
+                });
+            if self.source.picker_ui(ui) {
+                self.run_query(ui.ctx(), settings);
+            }
+        });
+
+        // Yenilenirken eski grafik yerinde kalır; gösterge yenileme satırında döner.
+        let busy = self.query_task.is_some();
+        self.auto_refresh.ui(ui, busy && !self.result.columns.is_empty(), false);
+        if self.auto_refresh.due(ui.ctx(), busy, false) {
+            self.run_query(ui.ctx(), settings);
+        }
+
+        egui::CollapsingHeader::new(t("query")).id_salt("query").show(ui, |ui| {
+            ui.add(
+                egui::TextEdit::multiline(&mut self.sql)
+                    .code_editor()
+                    .desired_width(f32::INFINITY)
+                    .desired_rows(3),
+            );
+            ui.horizontal(|ui| {
+                if ui.add_enabled(self.query_task.is_none(), egui::Button::new(t("query-run"))).clicked() {
+                    self.run_query(ui.ctx(), settings);
+                }
+                ui.weak(t_with("rows-count", &[("count", self.result.rows.len().into())]));
+            });
+        });
+
+        if let Some(result) = task::take_ready(&mut self.query_task) {
+            self.process_result(result);
+            if self.error.is_none() {
+                self.auto_refresh.updated(ui.ctx());
+            }
+        } else if self.query_task.is_some() && self.result.columns.is_empty() {
+            task::spinner(ui, &t("query-running"));
+        }
+
+        if let Some(error) = &self.error {
+            let source = self.source.describe(settings, datasource::QUERY_ENDPOINT);
+            if errorpanel::show(ui, error, &source) {
+                self.run_query(ui.ctx(), settings);
+            }
+        }
+
+        self.config_ui(ui);
+        ui.separator();
+        self.chart_ui(ui);
+    }
+}
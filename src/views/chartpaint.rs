@@ -0,0 +1,330 @@
+// Grafik çizimi: çubuk, çizgi, pasta ve dağılım grafikleri doğrudan egui painter ile çizilir.
+// Her fonksiyon kullanılabilir genişlikte bir alan ayırır, üzerine gelinen öğeyi vurgular
+// ve değerini araç ipucunda gösterir.
+
+use std::f32::consts::TAU;
+
+use eframe::egui;
+use egui::{Align2, Color32, FontId, Pos2, Rect, Response, Sense, Shape, Stroke, Ui};
+
+use crate::i18n::{self, t, t_with};
+
+// Kategori renkleri; daha fazla kategori olursa baştan tekrar edilir.
+const PALETTE: [Color32; 10] = [
+    Color32::from_rgb(76, 142, 218),
+    Color32::from_rgb(240, 145, 60),
+    Color32::from_rgb(90, 180, 100),
+    Color32::from_rgb(214, 82, 82),
+    Color32::from_rgb(150, 110, 200),
+    Color32::from_rgb(150, 100, 80),
+    Color32::from_rgb(220, 120, 180),
+    Color32::from_rgb(128, 128, 128),
+    Color32::from_rgb(190, 190, 60),
+    Color32::from_rgb(60, 190, 200),
+];
+
+// Eksen etiketleri için ayrılan boşluklar.
+const LEFT_MARGIN: f32 = 60.0;
+const BOTTOM_MARGIN: f32 = 36.0;
+const TOP_MARGIN: f32 = 8.0;
+const RIGHT_MARGIN: f32 = 12.0;
+
+pub fn color(index: usize) -> Color32 {
+    PALETTE[index % PALETTE.len()]
+}
+
+// Tam sayıları ondalıksız, diğerlerini iki basamakla gösterir.
+pub fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        i18n::localize_number(&format!("{}", value as i64))
+    } else {
+        i18n::format_decimal(value, 2)
+    }
+}
+
+fn truncate(label: &str, max_chars: usize) -> String {
+    if label.chars().count() <= max_chars {
+        label.to_string()
+    } else {
+        let mut short: String = label.chars().take(max_chars.saturating_sub(1)).collect();
+        short.push('…');
+        short
+    }
+}
+
+// Okunabilir eksen değerleri: adım 1, 2 ya da 5'in on katlarından seçilir.
+fn nice_ticks(min: f64, max: f64, target_count: usize) -> Vec<f64> {
+    let range = max - min;
+    if range <= 0.0 || !range.is_finite() {
+        return vec![min];
+    }
+    let raw_step = range / target_count.max(1) as f64;
+    let magnitude = 10f64.powf(raw_step.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= raw_step)
+        .unwrap_or(10.0 * magnitude);
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+// Eksen aralığını sıfırı içerecek ve üstte biraz boşluk kalacak şekilde genişletir.
+fn value_range(values: impl Iterator<Item = f64>, include_zero: bool) -> (f64, f64) {
+    let (mut min, mut max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)));
+    if !min.is_finite() || !max.is_finite() {
+        return (0.0, 1.0);
+    }
+    if include_zero {
+        min = min.min(0.0);
+        max = max.max(0.0);
+    }
+    if min == max {
+        max = min + 1.0;
+    }
+    let padding = (max - min) * 0.05;
+    (if min < 0.0 { min - padding } else { min }, max + padding)
+}
+
+struct Axes {
+    plot: Rect,
+    y_min: f64,
+    y_max: f64,
+}
+
+impl Axes {
+    fn y_to_screen(&self, value: f64) -> f32 {
+        let t = ((value - self.y_min) / (self.y_max - self.y_min)) as f32;
+        self.plot.bottom() - t * self.plot.height()
+    }
+}
+
+fn allocate(ui: &mut Ui, height: f32) -> (Response, egui::Painter) {
+    let size = egui::vec2(ui.available_width().max(200.0), height);
+    ui.allocate_painter(size, Sense::hover())
+}
+
+// Yatay ızgara çizgileri ve sol eksendeki değer etiketleri.
+fn draw_value_axis(ui: &Ui, painter: &egui::Painter, axes: &Axes) {
+    let grid_color = ui.visuals().widgets.noninteractive.bg_stroke.color;
+    let text_color = ui.visuals().weak_text_color();
+    for tick in nice_ticks(axes.y_min, axes.y_max, 5) {
+        let y = axes.y_to_screen(tick);
+        painter.line_segment([Pos2::new(axes.plot.left(), y), Pos2::new(axes.plot.right(), y)], Stroke::new(1.0, grid_color));
+        painter.text(Pos2::new(axes.plot.left() - 6.0, y), Align2::RIGHT_CENTER, format_value(tick), FontId::proportional(11.0), text_color);
+    }
+    painter.line_segment([axes.plot.left_bottom(), axes.plot.right_bottom()], Stroke::new(1.0, text_color));
+}
+
+// Kategori etiketleri; sığmazlarsa yalnızca her n'inci etiket yazılır.
+fn draw_category_labels(ui: &Ui, painter: &egui::Painter, plot: Rect, labels: &[String]) {
+    if labels.is_empty() {
+        return;
+    }
+    let band = plot.width() / labels.len() as f32;
+    let every = (70.0 / band).ceil().max(1.0) as usize;
+    let max_chars = ((band * every as f32) / 7.0).max(3.0) as usize;
+    for (i, label) in labels.iter().enumerate().step_by(every) {
+        let x = plot.left() + band * (i as f32 + 0.5);
+        painter.text(
+            Pos2::new(x, plot.bottom() + 4.0),
+            Align2::CENTER_TOP,
+            truncate(label, max_chars),
+            FontId::proportional(11.0),
+            ui.visuals().text_color(),
+        );
+    }
+}
+
+fn category_axes(response: &Response, values: &[f64]) -> Axes {
+    let plot = Rect::from_min_max(
+        response.rect.min + egui::vec2(LEFT_MARGIN, TOP_MARGIN),
+        response.rect.max - egui::vec2(RIGHT_MARGIN, BOTTOM_MARGIN),
+    );
+    let (y_min, y_max) = value_range(values.iter().copied(), true);
+    Axes { plot, y_min, y_max }
+}
+
+fn hovered_category(response: &Response, plot: Rect, count: usize) -> Option<usize> {
+    let pointer = response.hover_pos()?;
+    if count == 0 || !plot.expand2(egui::vec2(0.0, BOTTOM_MARGIN)).contains(pointer) {
+        return None;
+    }
+    let band = plot.width() / count as f32;
+    Some((((pointer.x - plot.left()) / band) as usize).min(count - 1))
+}
+
+fn category_tooltip(response: Response, hovered: Option<usize>, labels: &[String], values: &[f64], series: &str) {
+    if let Some(i) = hovered {
+        response.on_hover_ui_at_pointer(|ui| {
+            ui.strong(&labels[i]);
+            ui.label(format!("{}: {}", series, format_value(values[i])));
+        });
+    }
+}
+
+pub fn bar(ui: &mut Ui, height: f32, labels: &[String], values: &[f64], series: &str) {
+    let (response, painter) = allocate(ui, height);
+    let axes = category_axes(&response, values);
+    draw_value_axis(ui, &painter, &axes);
+    draw_category_labels(ui, &painter, axes.plot, labels);
+
+    let hovered = hovered_category(&response, axes.plot, values.len());
+    let band = axes.plot.width() / values.len().max(1) as f32;
+    let zero = axes.y_to_screen(0.0);
+    for (i, value) in values.iter().enumerate() {
+        let center = axes.plot.left() + band * (i as f32 + 0.5);
+        let half_width = (band * 0.35).max(0.5);
+        let top = axes.y_to_screen(*value);
+        let rect = Rect::from_x_y_ranges(center - half_width..=center + half_width, top.min(zero)..=top.max(zero));
+        let fill = if hovered == Some(i) { color(0).gamma_multiply(0.7) } else { color(0) };
+        painter.rect_filled(rect, 1.0, fill);
+    }
+    category_tooltip(response, hovered, labels, values, series);
+}
+
+pub fn line(ui: &mut Ui, height: f32, labels: &[String], values: &[f64], series: &str) {
+    let (response, painter) = allocate(ui, height);
+    let axes = category_axes(&response, values);
+    draw_value_axis(ui, &painter, &axes);
+    draw_category_labels(ui, &painter, axes.plot, labels);
+
+    let hovered = hovered_category(&response, axes.plot, values.len());
+    let band = axes.plot.width() / values.len().max(1) as f32;
+    let points: Vec<Pos2> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| Pos2::new(axes.plot.left() + band * (i as f32 + 0.5), axes.y_to_screen(*value)))
+        .collect();
+    painter.add(Shape::line(points.clone(), Stroke::new(2.0, color(0))));
+    for (i, point) in points.iter().enumerate() {
+        let radius = if hovered == Some(i) { 5.0 } else { 2.5 };
+        painter.circle_filled(*point, radius, color(0));
+    }
+    if let Some(i) = hovered {
+        painter.line_segment(
+            [Pos2::new(points[i].x, axes.plot.top()), Pos2::new(points[i].x, axes.plot.bottom())],
+            Stroke::new(1.0, ui.visuals().weak_text_color()),
+        );
+    }
+    category_tooltip(response, hovered, labels, values, series);
+}
+
+// Negatif değerler pastada gösterilemez; bunlar sıfır sayılır.
+pub fn pie(ui: &mut Ui, height: f32, labels: &[String], values: &[f64], series: &str) {
+    let (response, painter) = allocate(ui, height);
+    let total: f64 = values.iter().map(|v| v.max(0.0)).sum();
+    let center = response.rect.center();
+    let radius = (response.rect.width().min(response.rect.height()) / 2.0 - 8.0).max(10.0);
+    if total <= 0.0 {
+        painter.text(center, Align2::CENTER_CENTER, t("chart-no-positive-values"), FontId::proportional(13.0), ui.visuals().weak_text_color());
+        return;
+    }
+
+    // Üzerine gelinen dilim, işaretçinin merkeze göre açısından bulunur (saat 12'den saat yönünde).
+    let hovered = response.hover_pos().and_then(|pointer| {
+        let offset = pointer - center;
+        if offset.length() > radius {
+            return None;
+        }
+        let angle = (offset.x.atan2(-offset.y) + TAU) % TAU;
+        let mut start = 0.0;
+        values.iter().position(|value| {
+            let sweep = (value.max(0.0) / total) as f32 * TAU;
+            let inside = angle >= start && angle < start + sweep;
+            start += sweep;
+            inside
+        })
+    });
+
+    let mut mesh = egui::Mesh::default();
+    let mut start = 0.0_f32;
+    for (i, value) in values.iter().enumerate() {
+        let sweep = (value.max(0.0) / total) as f32 * TAU;
+        let slice_radius = if hovered == Some(i) { radius + 6.0 } else { radius };
+        let fill = color(i);
+        let steps = ((sweep / TAU) * 96.0).ceil().max(1.0) as usize;
+        let center_index = mesh.vertices.len() as u32;
+        mesh.colored_vertex(center, fill);
+        for step in 0..=steps {
+            let angle = start + sweep * step as f32 / steps as f32;
+            mesh.colored_vertex(center + slice_radius * egui::vec2(angle.sin(), -angle.cos()), fill);
+            if step > 0 {
+                let last = mesh.vertices.len() as u32 - 1;
+                mesh.add_triangle(center_index, last - 1, last);
+            }
+        }
+        start += sweep;
+    }
+    painter.add(Shape::mesh(mesh));
+
+    if let Some(i) = hovered {
+        response.on_hover_ui_at_pointer(|ui| {
+            ui.strong(&labels[i]);
+            ui.label(format!("{}: {}", series, format_value(values[i])));
+            ui.label(t_with(
+                "chart-share-of-total",
+                &[("share", i18n::format_decimal(values[i].max(0.0) / total * 100.0, 1).into()), ("total", format_value(total).into())],
+            ));
+        });
+    }
+}
+
+// X ve Y'si sayısal noktalar. `labels` araç ipucunda noktanın hangi satırdan geldiğini gösterir.
+pub fn scatter(ui: &mut Ui, height: f32, points: &[[f64; 2]], labels: &[String], x_name: &str, y_name: &str) {
+    let (response, painter) = allocate(ui, height);
+    let plot = Rect::from_min_max(
+        response.rect.min + egui::vec2(LEFT_MARGIN, TOP_MARGIN),
+        response.rect.max - egui::vec2(RIGHT_MARGIN, BOTTOM_MARGIN),
+    );
+    let (x_min, x_max) = value_range(points.iter().map(|p| p[0]), false);
+    let (y_min, y_max) = value_range(points.iter().map(|p| p[1]), false);
+    let axes = Axes { plot, y_min, y_max };
+    draw_value_axis(ui, &painter, &axes);
+
+    let x_to_screen = |x: f64| plot.left() + ((x - x_min) / (x_max - x_min)) as f32 * plot.width();
+    let text_color = ui.visuals().weak_text_color();
+    for tick in nice_ticks(x_min, x_max, 6) {
+        painter.text(Pos2::new(x_to_screen(tick), plot.bottom() + 4.0), Align2::CENTER_TOP, format_value(tick), FontId::proportional(11.0), text_color);
+    }
+    painter.text(plot.right_bottom() + egui::vec2(0.0, 20.0), Align2::RIGHT_TOP, x_name, FontId::proportional(11.0), text_color);
+
+    let screen: Vec<Pos2> = points.iter().map(|p| Pos2::new(x_to_screen(p[0]), axes.y_to_screen(p[1]))).collect();
+    // İşaretçiye 8 pikselden yakın olan en yakın nokta.
+    let hovered = response.hover_pos().and_then(|pointer| {
+        screen
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (i, point.distance(pointer)))
+            .filter(|(_, distance)| *distance <= 8.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    });
+    for (i, point) in screen.iter().enumerate() {
+        let radius = if hovered == Some(i) { 5.5 } else { 3.5 };
+        painter.circle_filled(*point, radius, color(0).gamma_multiply(0.8));
+    }
+
+    if let Some(i) = hovered {
+        response.on_hover_ui_at_pointer(|ui| {
+            if let Some(label) = labels.get(i).filter(|label| !label.is_empty()) {
+                ui.strong(label);
+            }
+            ui.label(format!("{}: {}", x_name, format_value(points[i][0])));
+            ui.label(format!("{}: {}", y_name, format_value(points[i][1])));
+        });
+    }
+}
+
+// Renk kutucuğu ve ad; grafiklerin altında gösterilir.
+pub fn legend(ui: &mut Ui, items: &[(String, Color32)]) {
+    ui.horizontal_wrapped(|ui| {
+        for (label, fill) in items {
+            let (rect, _) = ui.allocate_exact_size(egui::vec2(10.0, 10.0), Sense::hover());
+            ui.painter().rect_filled(rect, 2.0, *fill);
+            ui.label(label);
+            ui.add_space(6.0);
+        }
+    });
+}
@@ -0,0 +1,344 @@
+use eframe::egui;
+use egui::RichText;
+use serde::{Deserialize, Serialize};
+
+use super::autorefresh::AutoRefresh;
+use super::resultgrid::{self, FilterMode, ResultGrid};
+use super::{errorpanel, PlaceWindow, View};
+use crate::app::AppSettings;
+use crate::datasource::{self, cell_text, DataSource, FetchError, QueryResult};
+use crate::i18n::{self, t, t_with};
+use crate::task::{self, Task};
+
+pub const WINDOW_TITLE: &str = "Customer Orders";
+pub const VIEW_KIND: &str = "customer_orders";
+
+// Ayrıntı düzeyleri: müşterinin siparişleri -> siparişin kalemleri -> ürün bilgisi.
+// Sorgular yalnızca sayısal anahtarlarla oluşturulur.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+enum Level {
+    Customer { id: i64 },
+    Order { id: i64 },
+    Product { id: i64 },
+}
+
+impl Level {
+    fn sql(&self) -> String {
+        match self {
+            Level::Customer { id } => format!(
+                "SELECT o.OrderID, o.OrderDate, e.FirstName || ' ' || e.LastName AS Employee, \
+                 s.ShipperName AS Shipper, COUNT(d.OrderDetailID) AS Lines, \
+                 COALESCE(SUM(d.Quantity), 0) AS Items, ROUND(COALESCE(SUM(d.Quantity * p.Price), 0), 2) AS Total \
+                 FROM Orders o \
+                 LEFT JOIN Employees e ON e.EmployeeID = o.EmployeeID \
+                 LEFT JOIN Shippers s ON s.ShipperID = o.ShipperID \
+                 LEFT JOIN OrderDetails d ON d.OrderID = o.OrderID \
+                 LEFT JOIN Products p ON p.ProductID = d.ProductID \
+                 WHERE o.CustomerID = {} GROUP BY o.OrderID ORDER BY o.OrderDate, o.OrderID",
+                id
+            ),
+            Level::Order { id } => format!(
+                "SELECT d.ProductID, p.ProductName, p.Unit, d.Quantity, p.Price, \
+                 ROUND(d.Quantity * p.Price, 2) AS LineTotal \
+                 FROM OrderDetails d LEFT JOIN Products p ON p.ProductID = d.ProductID \
+                 WHERE d.OrderID = {} ORDER BY d.OrderDetailID",
+                id
+            ),
+            Level::Product { id } => format!(
+                "SELECT p.ProductID, p.ProductName, c.CategoryName AS Category, s.SupplierName AS Supplier, \
+                 s.Country AS SupplierCountry, p.Unit, p.Price \
+                 FROM Products p \
+                 LEFT JOIN Categories c ON c.CategoryID = p.CategoryID \
+                 LEFT JOIN Suppliers s ON s.SupplierID = p.SupplierID \
+                 WHERE p.ProductID = {}",
+                id
+            ),
+        }
+    }
+
+    // Bu düzeyde tıklanınca bir alt düzeye inen sütun.
+    fn link_column(&self) -> Option<&'static str> {
+        match self {
+            Level::Customer { .. } => Some("OrderID"),
+            Level::Order { .. } => Some("ProductName"),
+            Level::Product { .. } => None,
+        }
+    }
+}
+
+// İçerik haritasındaki bir adım ve gösterilen etiketi.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Crumb {
+    level: Level,
+    label: String,
+}
+
+// Bir müşterinin siparişlerini, sipariş kalemlerini ve toplamlarını gösterir.
+// Satırlara tıklayarak alt düzeylere inilir; içerik haritası (breadcrumbs) geri dönmeyi sağlar.
+// Müşteri tablosunda bir isme tıklamak bu pencereyi açar ya da zaten açıksa öne getirir.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomerOrders {
+    source: DataSource,
+    crumbs: Vec<Crumb>,
+    auto_refresh: AutoRefresh,
+    #[serde(skip)]
+    result: QueryResult,
+    #[serde(skip)]
+    grid: ResultGrid,
+    // Sonucun hangi düzeye ait olduğu; son adımdan farklıysa yeniden sorgulanır.
+    #[serde(skip)]
+    loaded: Option<Level>,
+    #[serde(skip)]
+    error: Option<FetchError>,
+    #[serde(skip)]
+    query_task: Option<Task<Result<String, FetchError>>>,
+}
+
+impl CustomerOrders {
+    pub fn for_customer(source: DataSource, customer_id: i64, customer_name: &str) -> Self {
+        Self {
+            source,
+            crumbs: vec![Crumb { level: Level::Customer { id: customer_id }, label: customer_name.to_string() }],
+            ..Default::default()
+        }
+    }
+
+    fn fetch(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        let Some(crumb) = self.crumbs.last() else {
+            return;
+        };
+        self.error = None;
+        self.result = QueryResult::default();
+        self.grid = ResultGrid::default();
+        self.loaded = Some(crumb.level.clone());
+        self.reload(ctx, settings);
+    }
+
+    // Geçerli düzeyi yeniden sorgular; yeni sonuç gelene kadar tablo, sıralaması ve
+    // kaydırma konumuyla yerinde kalır.
+    fn reload(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        let Some(crumb) = self.crumbs.last() else {
+            return;
+        };
+        self.auto_refresh.started(ctx);
+        self.query_task = Some(self.source.run_query(ctx, settings, &crumb.level.sql()));
+    }
+
+    fn process_result(&mut self, raw_json_result: Result<String, FetchError>) {
+        let parsed = raw_json_result.and_then(|raw_json| {
+            let value = serde_json::from_str::<serde_json::Value>(&raw_json).map_err(|e| {
+                FetchError::InvalidJson { message: format!("Response is not valid JSON: {}", e) }
+            })?;
+            QueryResult::from_json(value).map_err(|message| FetchError::SchemaMismatch { message })
+        });
+        match parsed {
+            Ok(result) => {
+                self.result = result;
+                self.error = None;
+                self.grid.invalidate();
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn column_sum(&self, column: &str) -> f64 {
+        self.result
+            .column_index(column)
+            .map(|col| self.result.rows.iter().filter_map(|row| row[col].as_f64()).sum())
+            .unwrap_or_default()
+    }
+
+    // Tıklanabilir içerik haritası; bir adıma tıklamak ondan sonraki adımları kapatır.
+    fn breadcrumbs_ui(&mut self, ui: &mut egui::Ui) {
+        let mut go_to = None;
+        ui.horizontal_wrapped(|ui| {
+            if ui
+                .add_enabled(self.crumbs.len() > 1, egui::Button::new(t("orders-back")))
+                .clicked()
+            {
+                go_to = Some(self.crumbs.len() - 2);
+            }
+            ui.separator();
+            let last = self.crumbs.len().saturating_sub(1);
+            for (i, crumb) in self.crumbs.iter().enumerate() {
+                if i > 0 {
+                    ui.weak("›");
+                }
+                if i == last {
+                    ui.label(RichText::new(&crumb.label).strong());
+                } else if ui.link(&crumb.label).clicked() {
+                    go_to = Some(i);
+                }
+            }
+        });
+        if let Some(i) = go_to {
+            self.crumbs.truncate(i + 1);
+        }
+    }
+
+    fn totals_ui(&self, ui: &mut egui::Ui) {
+        let Some(crumb) = self.crumbs.last() else {
+            return;
+        };
+        ui.horizontal(|ui| match crumb.level {
+            Level::Customer { .. } => {
+                ui.label(t_with("orders-count", &[("count", self.result.rows.len().into())]));
+                ui.separator();
+                ui.label(t_with("orders-items", &[("count", self.column_sum("Items").into())]));
+                ui.separator();
+                let total = i18n::format_decimal(self.column_sum("Total"), 2);
+                ui.label(RichText::new(t_with("orders-total", &[("total", total.into())])).strong());
+            }
+            Level::Order { .. } => {
+                ui.label(t_with("orders-lines", &[("count", self.result.rows.len().into())]));
+                ui.separator();
+                ui.label(t_with("orders-items", &[("count", self.column_sum("Quantity").into())]));
+                ui.separator();
+                let total = i18n::format_decimal(self.column_sum("LineTotal"), 2);
+                ui.label(RichText::new(t_with("orders-order-total", &[("total", total.into())])).strong());
+            }
+            Level::Product { .. } => {}
+        });
+    }
+
+    // Tek satırlık ürün bilgisi tablo yerine ad/değer listesi olarak gösterilir.
+    fn product_ui(&self, ui: &mut egui::Ui) {
+        let Some(row) = self.result.rows.first() else {
+            ui.weak(t("orders-product-not-found"));
+            return;
+        };
+        egui::Grid::new(ui.id().with("product")).num_columns(2).striped(true).show(ui, |ui| {
+            for (name, value) in self.result.columns.iter().zip(row) {
+                ui.label(RichText::new(name).strong());
+                resultgrid::default_cell(ui, value);
+                ui.end_row();
+            }
+        });
+    }
+}
+
+impl View for CustomerOrders {
+    fn title(&self) -> String {
+        match self.crumbs.first() {
+            Some(crumb) => t_with("orders-title", &[("customer", crumb.label.as_str().into())]),
+            None => i18n::view_title(VIEW_KIND, WINDOW_TITLE, WINDOW_TITLE),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        VIEW_KIND
+    }
+
+    fn refresh(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.reload(ctx, settings);
+    }
+
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) {
+        if let Ok(restored) = serde_json::from_value::<CustomerOrders>(state) {
+            *self = restored;
+        }
+    }
+
+    fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings) {
+        // Başlık müşteriyle değiştiği için pencere kimliği başlıktan değil `id`'den gelir.
+        egui::Window::new(self.title())
+            .id(id)
+            .default_width(560.0)
+            .default_height(420.0)
+            .frame(egui::Frame::window(&ctx.style())
+                .corner_radius(settings.global_rounding)
+            )
+            .placed(ctx, id)
+            .open(open)
+            .show(ctx, |ui| {
+                self.ui(ui, settings);
+            });
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, settings: &AppSettings) {
+        if self.crumbs.is_empty() {
+            ui.weak(t("orders-empty"));
+            return;
+        }
+
+        self.breadcrumbs_ui(ui);
+
+        let current = self.crumbs.last().map(|crumb| crumb.level.clone());
+        if current != self.loaded && self.query_task.is_none() {
+            self.fetch(ui.ctx(), settings);
+        }
+        let busy = self.query_task.is_some();
+        self.auto_refresh.ui(ui, busy && !self.result.columns.is_empty(), false);
+        if self.auto_refresh.due(ui.ctx(), busy, false) {
+            self.reload(ui.ctx(), settings);
+        }
+        ui.separator();
+
+        if let Some(result) = task::take_ready(&mut self.query_task) {
+            self.process_result(result);
+            if self.error.is_none() {
+                self.auto_refresh.updated(ui.ctx());
+            }
+            // Beklerken başka bir adıma geçildiyse sonuç eskidir; yeniden sorgula.
+            if self.crumbs.last().map(|crumb| &crumb.level) != self.loaded.as_ref() {
+                self.fetch(ui.ctx(), settings);
+            }
+        } else if self.query_task.is_some() && self.result.columns.is_empty() {
+            task::spinner(ui, &t("loading"));
+            return;
+        }
+
+        if let Some(error) = &self.error {
+            let source = self.source.describe(settings, datasource::QUERY_ENDPOINT);
+            if errorpanel::show(ui, error, &source) {
+                self.fetch(ui.ctx(), settings);
+            }
+            return;
+        }
+
+        let Some(level) = current else {
+            return;
+        };
+        if matches!(level, Level::Product { .. }) {
+            self.product_ui(ui);
+            return;
+        }
+
+        self.totals_ui(ui);
+        ui.separator();
+
+        let link_col = level.link_column().and_then(|name| self.result.column_index(name));
+        let mut drill_into = None;
+        self.grid.show(ui, ("customer_orders", &level), &self.result, FilterMode::Client, |ui, row, col, value| {
+            if Some(col) != link_col {
+                resultgrid::default_cell(ui, value);
+                return;
+            }
+            let row = &self.result.rows[row];
+            let hint = match level {
+                Level::Customer { .. } => t("orders-show-lines"),
+                _ => t("orders-show-product"),
+            };
+            if ui.link(cell_text(value)).on_hover_text(hint).clicked() {
+                drill_into = match level {
+                    Level::Customer { .. } => value
+                        .as_i64()
+                        .map(|id| Crumb { level: Level::Order { id }, label: t_with("orders-order-crumb", &[("id", id.into())]) }),
+                    _ => self
+                        .result
+                        .column_index("ProductID")
+                        .and_then(|id_col| row[id_col].as_i64())
+                        .map(|id| Crumb { level: Level::Product { id }, label: cell_text(value) }),
+                };
+            }
+        });
+        if let Some(crumb) = drill_into {
+            self.crumbs.push(crumb);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use eframe::egui;
+use egui::{Color32, RichText};
+
+use crate::datasource::{auth, FetchError};
+use crate::i18n::{t, t_or};
+
+// Veri çekme hatalarını tüm görünümlerde aynı biçimde gösteren panel.
+// "Retry" tıklandığında true döner; isteği yeniden başlatmak görünümün işidir.
+// 401 hatalarında düğme "Sign in" olur: yeniden gönderilen istek oturum açma penceresini açar.
+pub fn show(ui: &mut egui::Ui, error: &FetchError, source: &str) -> bool {
+    let mut retry = false;
+    egui::Frame::group(ui.style())
+        .stroke(egui::Stroke::new(1.0, Color32::RED))
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.label(RichText::new(format!("⚠ {}", t_or(error.title_key(), error.title()))).strong().color(Color32::RED));
+            ui.label(error.to_string());
+            if let Some(details) = error.server_details() {
+                ui.label(RichText::new(details).monospace());
+            }
+            ui.horizontal(|ui| {
+                if error.is_unauthorized() {
+                    if ui.button(t("error-sign-in")).clicked() {
+                        auth::allow_prompts();
+                        retry = true;
+                    }
+                } else if ui.button(t("error-retry")).clicked() {
+                    retry = true;
+                }
+                if ui.button(t("error-copy-diagnostics")).clicked() {
+                    ui.ctx().copy_text(error.diagnostics(source));
+                }
+            });
+        });
+    retry
+}
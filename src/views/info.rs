@@ -1,10 +1,13 @@
 use eframe::egui;
-use super::View;
+use super::{PlaceWindow, View};
 use crate::app::AppSettings;
+use crate::i18n::t;
+use serde::{Deserialize, Serialize};
 
 pub const WINDOW_TITLE: &str = "README";
+pub const VIEW_KIND: &str = "info";
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct Info;
 
 impl View for Info {
@@ -12,28 +15,32 @@ impl View for Info {
         WINDOW_TITLE.to_string()
     }
 
+    fn kind(&self) -> &'static str {
+        VIEW_KIND
+    }
+
     fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings) {
         egui::Window::new(self.title())
             .id(id)
             .default_width(320.0)
-            .frame(egui::Frame::window(&*ctx.style())
+            .frame(egui::Frame::window(&ctx.style())
                 .corner_radius(settings.global_rounding)
-                //.fill(settings.window_background_fill)
             )
+            .placed(ctx, id)
             .open(open)
             .show(ctx, |ui| {
-                self.ui(ui);
+                self.ui(ui, settings);
             });
     }
 
-    fn ui(&mut self, ui: &mut egui::Ui) {
+    fn ui(&mut self, ui: &mut egui::Ui, _settings: &AppSettings) {
         ui.style_mut().spacing.interact_size.y = 0.0;
-        ui.heading("Info");
+        ui.heading(t("info-heading"));
         ui.separator();
         ui.horizontal_wrapped(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
-            ui.label("This is a demo application. It is based on the Egui framework and is intended to be a simple example of how to use Egui. Visit the ");
-            ui.hyperlink_to("emarti GitHUB Repositories", "https://github.com/emartisoft");           
+            ui.label(t("info-text"));
+            ui.hyperlink_to(t("info-link"), "https://github.com/emartisoft");           
         });
         ui.add_space(20.0); 
     }
@@ -1,12 +1,122 @@
 use eframe::egui;
+use serde::{Deserialize, Serialize};
 
 pub mod info;
 pub mod testwindow;
 pub mod sqlitedata;
+pub mod queryconsole;
+pub mod schemabrowser;
+pub mod customerorders;
+pub mod chart;
+mod autorefresh;
+mod errorpanel;
+mod resultgrid;
+mod chartpaint;
+mod rowedits;
+pub mod registry;
 
 use crate::app::AppSettings;
+use crate::datasource::changefeed::ChangeBatch;
+
+// Bir görünümün çalışma alanından istediği işlemler (WorkspaceAction'ın görünüm karşılığı).
+pub enum ViewAction {
+    // Çalışma alanına yeni bir pencere ekle.
+    OpenView(Box<dyn View>),
+    // Aynı türden bir pencere zaten açıksa onu yeni görünümün durumuyla günceller ve
+    // öne getirir; yoksa yeni pencere olarak ekler (ör. ayrıntı pencereleri).
+    OpenOrFocus(Box<dyn View>),
+}
+
 pub trait View {
     fn title(&self) -> String;
     fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings);
-    fn ui(&mut self, ui: &mut egui::Ui);
+    fn ui(&mut self, ui: &mut egui::Ui, settings: &AppSettings);
+
+    // Kalıcı depolamada görünüm türünü tanımlayan sabit anahtar.
+    // Pencere başlığından bağımsızdır, böylece başlık değişse bile kayıtlar geçerli kalır.
+    fn kind(&self) -> &'static str;
+
+    // Görünümün yeniden açıldığında geri yüklenecek durumu.
+    // Varsayılan olarak görünüm hiçbir durum saklamaz.
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+
+    fn load_state(&mut self, _state: serde_json::Value) {}
+
+    // Son karede biriken işlemleri çalışma alanına teslim eder.
+    fn take_actions(&mut self) -> Vec<ViewAction> {
+        Vec::new()
+    }
+
+    // Kullanıcı pencere başlığını değiştirebiliyorsa true (ör. "Customers – Germany").
+    // Değiştirilen başlık görünümün kendi durumunda saklanır.
+    fn can_rename(&self) -> bool {
+        false
+    }
+
+    fn rename(&mut self, _title: String) {}
+
+    // Verisini sunucudan ya da dosyadan yeniden çeker (komut paletindeki "Refresh data").
+    // Veri göstermeyen görünümler için bir şey yapmaz.
+    fn refresh(&mut self, _ctx: &egui::Context, _settings: &AppSettings) {}
+
+    // Sunucunun değişiklik akışından gelen satır değişiklikleri; görünüm gizliyken de çağrılır.
+    // Tablo göstermeyen görünümler için bir şey yapmaz.
+    fn apply_changes(&mut self, _changes: &ChangeBatch) {}
+}
+
+// Dosyadan içe aktarılan pencerelerin kayıtlı yeri, pencere kimliğiyle egui'nin geçici
+// verisinde bekler ve `placed` ile bir kez uygulanır.
+fn placement_id(id: egui::Id) -> egui::Id {
+    id.with("imported_placement")
+}
+
+pub(crate) fn set_window_placement(ctx: &egui::Context, id: egui::Id, rect: egui::Rect) {
+    ctx.data_mut(|data| data.insert_temp(placement_id(id), Some(rect)));
+}
+
+// Görünümler pencerelerini oluştururken `.placed(ctx, id)` çağırır; böylece içe aktarılan
+// çalışma alanları pencereleri dosyadaki yerlerinde açar.
+pub trait PlaceWindow {
+    fn placed(self, ctx: &egui::Context, id: egui::Id) -> Self;
+}
+
+impl PlaceWindow for egui::Window<'_> {
+    fn placed(self, ctx: &egui::Context, id: egui::Id) -> Self {
+        match ctx.data_mut(|data| data.remove_temp::<Option<egui::Rect>>(placement_id(id))).flatten() {
+            Some(rect) => self.current_pos(rect.min).default_size(rect.size()),
+            None => self,
+        }
+    }
+}
+
+// Çalışma alanında açık bir pencere. `id` pencere kapatılıp açılsa ya da kardeş pencereler
+// kapansa bile değişmez; egui pencere konumunu ve boyutunu bu kimlikle hatırlar.
+pub(crate) struct ViewInstance {
+    pub id: u64,
+    pub view: Box<dyn View>,
+}
+
+// Bir görünümün depolamaya yazılan hali: türü ve kendi katkıda bulunduğu durum.
+// Görünüm türleri ViewRegistry'de olduğundan geri yükleme registry.restore() ile yapılır.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SavedView {
+    kind: String,
+    // Eski kayıtlarda bulunmaz; 0 ise geri yüklerken yeni bir kimlik atanır.
+    #[serde(default)]
+    id: u64,
+    #[serde(default)]
+    state: serde_json::Value,
+}
+
+pub(crate) fn save_views(views: &[ViewInstance]) -> Vec<SavedView> {
+    views
+        .iter()
+        .map(|instance| SavedView {
+            kind: instance.view.kind().to_string(),
+            id: instance.id,
+            state: instance.view.save_state(),
+        })
+        .collect()
 }
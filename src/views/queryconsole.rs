@@ -0,0 +1,216 @@
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use web_time::Instant;
+
+use super::resultgrid::{self, FilterMode, ResultGrid};
+use super::{errorpanel, PlaceWindow, View};
+use crate::app::AppSettings;
+use crate::datasource::schema::quote_identifier;
+use crate::datasource::{self, DataSource, FetchError, QueryResult};
+use crate::export::ExportMenu;
+use crate::i18n::{self, t, t_with};
+use crate::task::{self, Task};
+
+pub const WINDOW_TITLE: &str = "SQL Query Console";
+pub const VIEW_KIND: &str = "query_console";
+
+const DEFAULT_SQL: &str = "SELECT * FROM Customers LIMIT 100;";
+
+// Kullanıcının yazdığı SELECT ifadesini veri kaynağına gönderir ve sonucu,
+// sütunları dönen satırlardan türetilen bir tabloda gösterir.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueryConsole {
+    title: String,
+    source: DataSource,
+    sql: String,
+    result: QueryResult,
+    grid: ResultGrid, // Sonuç tablosunun sıralama ve filtreleri
+    round_trip_ms: Option<f64>, // İsteğin gönderilmesinden sonucun gelmesine kadar geçen süre
+    #[serde(skip)]
+    error: Option<FetchError>,
+    #[serde(skip)]
+    started_at: Option<Instant>,
+    #[serde(skip)]
+    run_on_open: bool, // Pencere ilk gösterildiğinde sorguyu kendiliğinden çalıştır
+    #[serde(skip)]
+    query_task: Option<Task<Result<String, FetchError>>>,
+    #[serde(skip)]
+    export: ExportMenu,
+}
+
+impl Default for QueryConsole {
+    fn default() -> Self {
+        Self {
+            title: WINDOW_TITLE.to_string(),
+            source: DataSource::default(),
+            sql: DEFAULT_SQL.to_string(),
+            result: QueryResult::default(),
+            grid: ResultGrid::default(),
+            round_trip_ms: None,
+            error: None,
+            started_at: None,
+            run_on_open: false,
+            query_task: None,
+            export: ExportMenu::default(),
+        }
+    }
+}
+
+impl QueryConsole {
+    // Bir tablonun ya da görünümün verisini gösteren, açılır açılmaz sorguyu çalıştıran konsol.
+    pub fn for_table(source: DataSource, table_name: &str) -> Self {
+        Self {
+            title: t_with("query-console-table-title", &[("table", table_name.into())]),
+            source,
+            sql: format!("SELECT * FROM {} LIMIT 1000;", quote_identifier(table_name)),
+            run_on_open: true,
+            ..Default::default()
+        }
+    }
+
+    fn process_result(&mut self, raw_json_result: Result<String, FetchError>) {
+        self.round_trip_ms = self
+            .started_at
+            .take()
+            .map(|started| started.elapsed().as_secs_f64() * 1000.0);
+        let parsed = raw_json_result.and_then(|raw_json| {
+            let value = serde_json::from_str::<serde_json::Value>(&raw_json).map_err(|e| {
+                FetchError::InvalidJson { message: format!("Response is not valid JSON: {}", e) }
+            })?;
+            QueryResult::from_json(value).map_err(|message| FetchError::SchemaMismatch { message })
+        });
+        match parsed {
+            Ok(result) => {
+                self.result = result;
+                self.grid.invalidate();
+            }
+            Err(e) => {
+                self.result = QueryResult::default();
+                self.error = Some(e);
+            }
+        }
+    }
+
+    fn run_query(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.error = None;
+        self.started_at = Some(Instant::now());
+        self.query_task = Some(self.source.run_query(ctx, settings, &self.sql));
+    }
+
+    fn is_running(&self) -> bool {
+        self.query_task.is_some()
+    }
+}
+
+impl View for QueryConsole {
+    fn title(&self) -> String {
+        i18n::view_title(VIEW_KIND, &self.title, WINDOW_TITLE)
+    }
+
+    fn kind(&self) -> &'static str {
+        VIEW_KIND
+    }
+
+    fn refresh(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.run_query(ctx, settings);
+    }
+
+    fn can_rename(&self) -> bool {
+        true
+    }
+
+    fn rename(&mut self, title: String) {
+        self.title = title;
+    }
+
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) {
+        if let Ok(restored) = serde_json::from_value::<QueryConsole>(state) {
+            *self = restored;
+        }
+    }
+
+    fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings) {
+        egui::Window::new(self.title())
+            .id(id)
+            .default_width(640.0)
+            .default_height(480.0)
+            .frame(egui::Frame::window(&ctx.style())
+                .corner_radius(settings.global_rounding)
+            )
+            .placed(ctx, id)
+            .open(open)
+            .show(ctx, |ui| {
+                self.ui(ui, settings);
+            });
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, settings: &AppSettings) {
+        if self.run_on_open {
+            self.run_on_open = false;
+            self.run_query(ui.ctx(), settings);
+        }
+
+        self.source.picker_ui(ui);
+
+        let editor = ui.add(
+            egui::TextEdit::multiline(&mut self.sql)
+                .code_editor()
+                .hint_text("SELECT ...")
+                .desired_width(f32::INFINITY)
+                .desired_rows(4),
+        );
+        // Düzenleyicideyken Ctrl+Enter (macOS'ta Cmd+Enter) sorguyu çalıştırır.
+        let run_shortcut = editor.has_focus()
+            && ui.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::Enter));
+
+        ui.horizontal(|ui| {
+            let run_clicked = ui
+                .add_enabled(!self.is_running(), egui::Button::new(t("query-run")))
+                .on_hover_text("Ctrl+Enter")
+                .clicked();
+            if (run_clicked || run_shortcut) && !self.is_running() {
+                self.run_query(ui.ctx(), settings);
+            }
+            ui.weak(self.source.describe(settings, datasource::QUERY_ENDPOINT));
+        });
+
+        if let Some(result) = task::take_ready(&mut self.query_task) {
+            self.process_result(result);
+        } else if self.is_running() {
+            task::spinner(ui, &t("query-running"));
+        }
+
+        if let Some(error) = &self.error {
+            let source = self.source.describe(settings, datasource::QUERY_ENDPOINT);
+            if errorpanel::show(ui, error, &source) && !self.is_running() {
+                self.run_query(ui.ctx(), settings);
+            }
+        }
+
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label(t_with("rows-count", &[("count", self.result.rows.len().into())]));
+            if let Some(ms) = self.result.elapsed_ms {
+                ui.separator();
+                ui.label(t_with("query-executed-in", &[("ms", i18n::format_decimal(ms, 1).into())]));
+            }
+            if let Some(ms) = self.round_trip_ms {
+                ui.separator();
+                ui.weak(t_with("query-round-trip", &[("ms", i18n::format_decimal(ms, 1).into())]));
+            }
+            ui.separator();
+            let (grid, result) = (&self.grid, &self.result);
+            self.export.ui(ui, &self.title, result, || grid.rows_in_view(result, FilterMode::Client));
+        });
+
+        self.grid.show(ui, "query_console_result", &self.result, FilterMode::Client, |ui, _, _, value| {
+            resultgrid::default_cell(ui, value);
+        });
+    }
+}
@@ -0,0 +1,126 @@
+use super::{chart, customerorders, queryconsole, schemabrowser, sqlitedata, testwindow, SavedView, View, ViewInstance};
+
+// Çalışma alanı menüsünden açılabilen ya da kayıttan geri yüklenebilen bir görünüm türü.
+pub struct ViewDescriptor {
+    // View::kind() ile aynı olmalı; kayıtlı görünümler bu anahtarla eşleştirilir.
+    pub kind: &'static str,
+    pub display_name: &'static str,
+    pub icon: &'static str,
+    // Menüde aynı kategorideki görünümler birlikte listelenir.
+    pub category: &'static str,
+    // true ise çalışma alanında bu türden en fazla bir pencere açılır;
+    // menü düğmesi zaten açık olan pencereyi öne getirir.
+    pub single_instance: bool,
+    // false ise yalnızca kayıttan geri yüklenir (ör. başka bir görünümün açtığı ayrıntı pencereleri).
+    pub show_in_menu: bool,
+    pub create: fn() -> Box<dyn View>,
+}
+
+// Uygulamanın tanıdığı görünüm türleri. Sol menü bu listeden oluşturulur.
+// Uygulamayı gömen bir program kendi View türlerini `register` ile ekleyip
+// `Application::with_registry` ile başlatabilir.
+#[derive(Default)]
+pub struct ViewRegistry {
+    descriptors: Vec<ViewDescriptor>,
+}
+
+impl ViewRegistry {
+    // Bu depodaki görünümlerin hepsi kayıtlı olarak başlar.
+    pub fn with_builtin_views() -> Self {
+        let mut registry = Self::default();
+        registry.register(ViewDescriptor {
+            kind: sqlitedata::VIEW_KIND,
+            display_name: sqlitedata::WINDOW_TITLE,
+            icon: "🗄",
+            category: "Data",
+            single_instance: false,
+            show_in_menu: true,
+            create: || Box::new(sqlitedata::SqliteData::default()),
+        });
+        registry.register(ViewDescriptor {
+            kind: queryconsole::VIEW_KIND,
+            display_name: queryconsole::WINDOW_TITLE,
+            icon: "🖮",
+            category: "Data",
+            single_instance: false,
+            show_in_menu: true,
+            create: || Box::new(queryconsole::QueryConsole::default()),
+        });
+        registry.register(ViewDescriptor {
+            kind: schemabrowser::VIEW_KIND,
+            display_name: schemabrowser::WINDOW_TITLE,
+            icon: "🗂",
+            category: "Data",
+            single_instance: true,
+            show_in_menu: true,
+            create: || Box::new(schemabrowser::SchemaBrowser::default()),
+        });
+        registry.register(ViewDescriptor {
+            kind: customerorders::VIEW_KIND,
+            display_name: customerorders::WINDOW_TITLE,
+            icon: "🧾",
+            category: "Data",
+            single_instance: true,
+            show_in_menu: false,
+            create: || Box::new(customerorders::CustomerOrders::default()),
+        });
+        registry.register(ViewDescriptor {
+            kind: chart::VIEW_KIND,
+            display_name: chart::WINDOW_TITLE,
+            icon: "📊",
+            category: "Dashboards",
+            single_instance: false,
+            show_in_menu: true,
+            create: || Box::new(chart::ChartView::default()),
+        });
+        registry.register(ViewDescriptor {
+            kind: testwindow::VIEW_KIND,
+            display_name: testwindow::WINDOW_TITLE,
+            icon: "🔧",
+            category: "Other",
+            single_instance: true,
+            show_in_menu: true,
+            create: || Box::new(testwindow::TestWindow::default()),
+        });
+        registry
+    }
+
+    // Aynı türden bir kayıt varsa yenisiyle değiştirilir; böylece yerleşik bir görünüm de
+    // uygulamayı gömen program tarafından değiştirilebilir.
+    pub fn register(&mut self, descriptor: ViewDescriptor) {
+        match self.descriptors.iter_mut().find(|existing| existing.kind == descriptor.kind) {
+            Some(existing) => *existing = descriptor,
+            None => self.descriptors.push(descriptor),
+        }
+    }
+
+    pub fn get(&self, kind: &str) -> Option<&ViewDescriptor> {
+        self.descriptors.iter().find(|descriptor| descriptor.kind == kind)
+    }
+
+    // Menüde gösterilecek görünümler, kategorilerin ilk kayıt sırasına göre gruplanmış olarak.
+    pub fn menu(&self) -> Vec<(&'static str, Vec<&ViewDescriptor>)> {
+        let mut groups: Vec<(&'static str, Vec<&ViewDescriptor>)> = Vec::new();
+        for descriptor in self.descriptors.iter().filter(|descriptor| descriptor.show_in_menu) {
+            match groups.iter_mut().find(|(category, _)| *category == descriptor.category) {
+                Some((_, items)) => items.push(descriptor),
+                None => groups.push((descriptor.category, vec![descriptor])),
+            }
+        }
+        groups
+    }
+
+    // Kayıtlı görünümleri yeniden oluşturur. Bu derlemede (ya da gömen programda)
+    // kayıtlı olmayan türler sessizce atlanır. Kimlikler olduğu gibi döner; eksik ya da
+    // çakışan kimlikleri çalışma alanı düzeltir.
+    pub(crate) fn restore(&self, saved: Vec<SavedView>) -> Vec<ViewInstance> {
+        saved
+            .into_iter()
+            .filter_map(|saved_view| {
+                let mut view = (self.get(&saved_view.kind)?.create)();
+                view.load_state(saved_view.state);
+                Some(ViewInstance { id: saved_view.id, view })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,188 @@
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::datasource::{cell_text, QueryResult, TableQuery};
+use crate::i18n::{self, t, t_with};
+
+// Sıralama ve filtrelerin nerede uygulanacağı.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    // Sonucun tamamı bellekte: satırlar istemcide sıralanır ve süzülür.
+    Client,
+    // Sonuç sayfalı: değişiklikler sunucuya gönderilmek üzere görünüme bildirilir.
+    Server,
+}
+
+// Sütunları sorgu sonucundan türetilen, başlığa tıklayarak sıralanan ve
+// sütun başına filtrelenebilen genel tablo. Sıralama/filtre durumu görünümle birlikte kaydedilir.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResultGrid {
+    pub query: TableQuery,
+    // Son uygulanan (istemci) ya da sunucuya bildirilen (sunucu) sıralama ve filtreler.
+    #[serde(skip)]
+    applied: Option<TableQuery>,
+    // İstemci modunda gösterilen satırların sonuçtaki indeksleri.
+    #[serde(skip)]
+    visible: Vec<usize>,
+}
+
+impl ResultGrid {
+    // Yeni bir sonuç geldiğinde çağrılır; istemci tarafı sıralama yeniden hesaplanır.
+    pub fn invalidate(&mut self) {
+        self.applied = None;
+    }
+
+    // Sunucu modunda görünüm, mevcut `query` ile bir istek gönderdiğinde çağırır.
+    pub fn mark_applied(&mut self) {
+        self.applied = Some(self.query.clone());
+    }
+
+    // Tabloda görünen satırların sırası (ör. dışa aktarmak için). Sunucu modunda
+    // satırlar zaten sunucunun sıraladığı ve süzdüğü sayfadır.
+    pub fn rows_in_view(&self, result: &QueryResult, mode: FilterMode) -> Vec<usize> {
+        match mode {
+            FilterMode::Client => self.query.apply(result),
+            FilterMode::Server => (0..result.rows.len()).collect(),
+        }
+    }
+
+    fn refresh_client_order(&mut self, result: &QueryResult) {
+        if self.applied.as_ref() != Some(&self.query) || self.visible.len() > result.rows.len() {
+            self.visible = self.query.apply(result);
+            self.applied = Some(self.query.clone());
+        }
+    }
+
+    // Tabloyu çizer. Sunucu modunda sıralama ya da bir filtre değiştiğinde true döner;
+    // görünüm bu durumda veriyi yeni `query` ile yeniden istemelidir.
+    // `cell` her görünür hücre için çağrılır (satır indeksi, sütun indeksi, değer).
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: impl std::hash::Hash,
+        result: &QueryResult,
+        mode: FilterMode,
+        mut cell: impl FnMut(&mut egui::Ui, usize, usize, &Value),
+    ) -> bool {
+        if result.columns.is_empty() {
+            ui.weak(t("grid-no-columns"));
+            return false;
+        }
+
+        let mut sort_clicked = false;
+        let mut filter_committed = false;
+
+        if !self.query.is_empty() && ui.small_button(t("grid-clear")).clicked() {
+            self.query = TableQuery::default();
+            sort_clicked = true;
+        }
+
+        if mode == FilterMode::Client {
+            self.refresh_client_order(result);
+            if self.visible.len() != result.rows.len() {
+                ui.weak(t_with("grid-matching-rows", &[("count", self.visible.len().into()), ("total", result.rows.len().into())]));
+            }
+        }
+
+        egui::ScrollArea::horizontal()
+            .id_salt(ui.id().with(&id_salt).with("scroll"))
+            .show(ui, |ui| {
+                TableBuilder::new(ui)
+                    .id_salt((&id_salt, &result.columns))
+                    .striped(true)
+                    .resizable(true)
+                    .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                    .columns(Column::auto().at_least(60.0).clip(true), result.columns.len())
+                    .header(46.0, |mut header| {
+                        for name in &result.columns {
+                            header.col(|ui| {
+                                ui.vertical(|ui| {
+                                    let indicator = match self.query.sort_state(name) {
+                                        Some((priority, descending)) => {
+                                            let arrow = if descending { "⏷" } else { "⏶" };
+                                            if self.query.sort.len() > 1 {
+                                                format!(" {}{}", arrow, priority + 1)
+                                            } else {
+                                                format!(" {}", arrow)
+                                            }
+                                        }
+                                        None => String::new(),
+                                    };
+                                    let header_button = ui
+                                        .add(egui::Button::new(egui::RichText::new(format!("{}{}", name, indicator)).strong()).frame(false))
+                                        .on_hover_text(t("grid-sort-hint"));
+                                    if header_button.clicked() {
+                                        let additive = ui.input(|i| i.modifiers.shift);
+                                        self.query.cycle_sort(name, additive);
+                                        sort_clicked = true;
+                                    }
+
+                                    let filter = self.query.filters.entry(name.clone()).or_default();
+                                    let filter_edit = ui
+                                        .add(egui::TextEdit::singleline(filter).hint_text(t("grid-filter")).desired_width(f32::INFINITY))
+                                        .on_hover_text(t("grid-filter-hint"));
+                                    if filter_edit.lost_focus() {
+                                        filter_committed = true;
+                                    }
+                                });
+                            });
+                        }
+                    })
+                    .body(|body| {
+                        let row_count = match mode {
+                            FilterMode::Client => self.visible.len(),
+                            FilterMode::Server => result.rows.len(),
+                        };
+                        // Yalnızca görünür satırlar çizilir; büyük sonuçlar da akıcı kaydırılır.
+                        body.rows(20.0, row_count, |mut row| {
+                            let row_index = match mode {
+                                FilterMode::Client => self.visible[row.index()],
+                                FilterMode::Server => row.index(),
+                            };
+                            for (column_index, value) in result.rows[row_index].iter().enumerate() {
+                                row.col(|ui| {
+                                    cell(ui, row_index, column_index, value);
+                                });
+                            }
+                        });
+                    });
+            });
+
+        // Boş filtre kutuları durumda tutulmaz.
+        self.query.filters.retain(|_, text| !text.trim().is_empty());
+
+        match mode {
+            FilterMode::Client => false,
+            FilterMode::Server => {
+                // Filtreler yazarken değil, Enter'a basıldığında ya da kutudan çıkıldığında gönderilir.
+                let changed = (sort_clicked || filter_committed) && self.applied.as_ref() != Some(&self.query);
+                if changed {
+                    self.applied = Some(self.query.clone());
+                }
+                changed
+            }
+        }
+    }
+}
+
+// Varsayılan hücre gösterimi. Ondalık sayılar ve tarihler seçili dile göre biçimlenir;
+// tam sayılar (çoğunlukla kimlikler) olduğu gibi gösterilir.
+pub fn default_cell(ui: &mut egui::Ui, value: &Value) {
+    match value {
+        Value::Null => {
+            ui.weak("NULL");
+        }
+        Value::Number(number) if number.is_f64() => {
+            ui.label(i18n::localize_number(&number.to_string()));
+        }
+        Value::String(text) => {
+            ui.label(i18n::localize_date(text).unwrap_or_else(|| text.clone()));
+        }
+        _ => {
+            ui.label(cell_text(value));
+        }
+    }
+}
@@ -0,0 +1,461 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use eframe::egui;
+use egui::{Color32, RichText};
+use serde_json::{Map, Value};
+
+use crate::app::AppSettings;
+use crate::datasource::{cell_text, DataSource, EditableTable, FetchError, RowChange};
+use crate::i18n::{t, t_with};
+use crate::task::{self, Task};
+
+// Düzenlenmiş, eklenmek üzere bekleyen ve silinmek üzere işaretlenmiş satırların renkleri.
+const DIRTY_FILL: Color32 = Color32::from_rgba_premultiplied(90, 80, 0, 60);
+const DELETED_FILL: Color32 = Color32::from_rgba_premultiplied(110, 20, 20, 60);
+
+// Kaydedilmemiş bir değişikliğin ait olduğu satır: tablodaki bir satır (anahtarıyla)
+// ya da "Add row" formuyla eklenmiş, henüz sunucuda olmayan bir satır.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum RowKey {
+    Existing(i64),
+    New(u64),
+}
+
+// Tek bir satırın kayıt isteği; bittiğinde yuva boşalır.
+type SaveSlot = Option<Task<Result<String, FetchError>>>;
+
+// Süren bir kayıt isteği. Güncellemelerde gönderilen değerler de tutulur: istek sürerken
+// aynı satırda yazılanlar, istek başarılı olunca silinmez. Eklenen ve silinen satırlar
+// istek bitene kadar değiştirilemez.
+struct PendingSave {
+    row_key: RowKey,
+    sent: BTreeMap<String, String>,
+    slot: SaveSlot,
+}
+
+// "Save all / Discard" çubuğunda seçilen işlem.
+#[derive(PartialEq)]
+pub enum BarAction {
+    None,
+    Save,
+    Discard,
+}
+
+// Bir tablonun kaydedilmemiş değişiklikleri. Değerler metin olarak düzenlenir; "Save all"
+// önce her hücreyi doğrular, sonra her satır için ayrı bir POST/PUT/DELETE isteği gönderir.
+// Değişiklikler anahtarla tutulduğu için sayfa, sıralama ya da filtre değişince kaybolmaz.
+pub struct RowEdits {
+    table: EditableTable,
+    columns: &'static [&'static str], // Düzenlenebilen sütunlar
+    validate: fn(&str, &str) -> Option<String>, // (sütun, metin) -> hata iletisi
+    updates: BTreeMap<i64, BTreeMap<String, String>>,
+    deletes: BTreeSet<i64>,
+    inserts: BTreeMap<u64, BTreeMap<String, String>>,
+    next_insert_id: u64,
+    new_row: BTreeMap<String, String>, // "Add row" formunun içeriği
+    // Sütun bazında doğrulama hataları; sütuna bağlanamayan hatalar satıra yazılır.
+    cell_errors: BTreeMap<(RowKey, String), String>,
+    row_errors: BTreeMap<RowKey, String>,
+    saving: Vec<PendingSave>,
+    saved_any: bool,
+}
+
+impl RowEdits {
+    pub fn new(table: EditableTable, columns: &'static [&'static str], validate: fn(&str, &str) -> Option<String>) -> Self {
+        Self {
+            table,
+            columns,
+            validate,
+            updates: BTreeMap::new(),
+            deletes: BTreeSet::new(),
+            inserts: BTreeMap::new(),
+            next_insert_id: 0,
+            new_row: BTreeMap::new(),
+            cell_errors: BTreeMap::new(),
+            row_errors: BTreeMap::new(),
+            saving: Vec::new(),
+            saved_any: false,
+        }
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.updates.len() + self.deletes.len() + self.inserts.len()
+    }
+
+    pub fn is_saving(&self) -> bool {
+        !self.saving.is_empty()
+    }
+
+    // Satırın eklenmesi ya da silinmesi sürüyor mu; sürüyorsa satır değiştirilemez.
+    fn is_locked(&self, row_key: RowKey) -> bool {
+        self.saving
+            .iter()
+            .any(|pending| pending.row_key == row_key && pending.sent.is_empty())
+    }
+
+    pub fn is_editable(&self, column: &str) -> bool {
+        self.columns.contains(&column)
+    }
+
+    pub fn discard(&mut self) {
+        self.updates.clear();
+        self.deletes.clear();
+        self.inserts.clear();
+        self.cell_errors.clear();
+        self.row_errors.clear();
+    }
+
+    // Düzenlenebilir bir hücreyi metin kutusu olarak çizer. Özgün değere geri dönülürse
+    // hücre yeniden temiz sayılır.
+    pub fn cell_ui(&mut self, ui: &mut egui::Ui, key: i64, column: &str, original: &Value) {
+        let original_text = if original.is_null() { String::new() } else { cell_text(original) };
+        let row_key = RowKey::Existing(key);
+        let edited = self.updates.get(&key).and_then(|row| row.get(column));
+        if self.deletes.contains(&key) {
+            ui.painter().rect_filled(ui.max_rect(), 0.0, DELETED_FILL);
+            ui.label(RichText::new(edited.unwrap_or(&original_text)).strikethrough());
+            return;
+        }
+        if edited.is_some() {
+            ui.painter().rect_filled(ui.max_rect(), 0.0, DIRTY_FILL);
+        }
+        let mut text = edited.cloned().unwrap_or_else(|| original_text.clone());
+        let error = self.cell_errors.get(&(row_key, column.to_string())).cloned();
+        let response = Self::text_edit(ui, &mut text, error.as_deref());
+        if response.changed() {
+            self.cell_errors.remove(&(row_key, column.to_string()));
+            let row = self.updates.entry(key).or_default();
+            if text == original_text {
+                row.remove(column);
+            } else {
+                row.insert(column.to_string(), text);
+            }
+            if row.is_empty() {
+                self.updates.remove(&key);
+            }
+        }
+    }
+
+    // Anahtar sütunu: değer ve satırı silinmek üzere işaretleyen (ya da işareti kaldıran) düğme.
+    pub fn key_cell_ui(&mut self, ui: &mut egui::Ui, key: i64) {
+        let deleted = self.deletes.contains(&key);
+        if deleted {
+            ui.painter().rect_filled(ui.max_rect(), 0.0, DELETED_FILL);
+        }
+        let (icon, hint) = if deleted { ("↺", t("edits-keep-row")) } else { ("🗑", t("edits-delete-row")) };
+        let button = ui.add_enabled(!self.is_locked(RowKey::Existing(key)), egui::Button::new(icon).small());
+        if button.on_hover_text(hint).clicked() {
+            if deleted {
+                self.deletes.remove(&key);
+            } else {
+                self.deletes.insert(key);
+            }
+        }
+        let text = RichText::new(key.to_string());
+        ui.label(if deleted { text.strikethrough() } else { text });
+        if let Some(error) = self.row_errors.get(&RowKey::Existing(key)) {
+            ui.colored_label(ui.visuals().error_fg_color, "⚠").on_hover_text(error);
+        }
+    }
+
+    // Hatalı hücreler kırmızı çerçeveyle gösterilir; ileti fareyle üzerine gelince okunur.
+    fn text_edit(ui: &mut egui::Ui, text: &mut String, error: Option<&str>) -> egui::Response {
+        let response = ui.add(egui::TextEdit::singleline(text).desired_width(f32::INFINITY));
+        match error {
+            Some(error) => {
+                ui.painter().rect_stroke(
+                    response.rect,
+                    2.0,
+                    egui::Stroke::new(1.5, ui.visuals().error_fg_color),
+                    egui::StrokeKind::Outside,
+                );
+                response.on_hover_text(error)
+            }
+            None => response,
+        }
+    }
+
+    // "Add row" formu ve eklenmeyi bekleyen satırlar.
+    pub fn insert_ui(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new(t("edits-add-row")).id_salt("add_row").show(ui, |ui| {
+            egui::Grid::new(ui.id().with("new_row")).num_columns(2).show(ui, |ui| {
+                for column in self.columns {
+                    ui.label(*column);
+                    let text = self.new_row.entry(column.to_string()).or_default();
+                    let error = (self.validate)(column, text).filter(|_| !text.is_empty());
+                    Self::text_edit(ui, text, error.as_deref());
+                    ui.end_row();
+                }
+            });
+            let errors: Vec<String> = self
+                .columns
+                .iter()
+                .filter_map(|column| {
+                    (self.validate)(column, self.new_row.get(*column).map(String::as_str).unwrap_or_default())
+                        .map(|error| format!("{}: {}", column, error))
+                })
+                .collect();
+            let add = ui
+                .add_enabled(errors.is_empty(), egui::Button::new(t("edits-add")))
+                .on_disabled_hover_text(errors.join("\n"));
+            if add.clicked() {
+                self.inserts.insert(self.next_insert_id, std::mem::take(&mut self.new_row));
+                self.next_insert_id += 1;
+            }
+        });
+
+        let mut removed = None;
+        let locked: BTreeSet<RowKey> =
+            self.inserts.keys().map(|id| RowKey::New(*id)).filter(|row_key| self.is_locked(*row_key)).collect();
+        for (id, row) in self.inserts.iter_mut() {
+            let row_key = RowKey::New(*id);
+            egui::Frame::new().fill(DIRTY_FILL).inner_margin(2.0).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    // Eklenmekte olan satır yeniden gönderilmesin diye istek bitene kadar kilitlenir.
+                    ui.add_enabled_ui(!locked.contains(&row_key), |ui| {
+                        if ui.small_button("🗑").on_hover_text(t("edits-remove-new-row")).clicked() {
+                            removed = Some(*id);
+                        }
+                        ui.weak(t("edits-new"));
+                        for column in self.columns {
+                            let text = row.entry(column.to_string()).or_default();
+                            let error = self.cell_errors.get(&(row_key, column.to_string()));
+                            ui.add_sized([160.0, 18.0], |ui: &mut egui::Ui| Self::text_edit(ui, text, error.map(String::as_str)));
+                        }
+                    });
+                    if let Some(error) = self.row_errors.get(&row_key) {
+                        ui.colored_label(ui.visuals().error_fg_color, "⚠").on_hover_text(error);
+                    }
+                });
+            });
+        }
+        if let Some(id) = removed {
+            self.inserts.remove(&id);
+        }
+    }
+
+    // Bekleyen değişiklik sayısı ile "Save all" ve "Discard" düğmeleri.
+    pub fn bar_ui(&mut self, ui: &mut egui::Ui) -> BarAction {
+        let mut action = BarAction::None;
+        let pending = self.pending_count();
+        if pending == 0 && !self.is_saving() {
+            return action;
+        }
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(t_with("edits-unsaved", &[("count", pending.into())])).strong());
+            if ui.add_enabled(!self.is_saving(), egui::Button::new(t("edits-save-all"))).clicked() {
+                action = BarAction::Save;
+            }
+            if ui.add_enabled(!self.is_saving(), egui::Button::new(t("edits-discard"))).clicked() {
+                action = BarAction::Discard;
+            }
+            if self.is_saving() {
+                task::spinner(ui, &t("edits-saving"));
+            }
+        });
+        let error_count = self.cell_errors.len() + self.row_errors.len();
+        if error_count > 0 {
+            ui.colored_label(
+                ui.visuals().error_fg_color,
+                t_with("edits-failed", &[("count", error_count.into())]),
+            );
+        }
+        action
+    }
+
+    // Tüm hücreleri doğrular; hata yoksa her değişiklik için bir istek başlatır.
+    pub fn save(&mut self, source: &DataSource, ctx: &egui::Context, settings: &AppSettings) {
+        self.cell_errors.clear();
+        self.row_errors.clear();
+        for (key, row) in &self.updates {
+            for (column, text) in row {
+                if let Some(error) = (self.validate)(column, text) {
+                    self.cell_errors.insert((RowKey::Existing(*key), column.clone()), error);
+                }
+            }
+        }
+        for (id, row) in &self.inserts {
+            for column in self.columns {
+                if let Some(error) = (self.validate)(column, row.get(*column).map(String::as_str).unwrap_or_default()) {
+                    self.cell_errors.insert((RowKey::New(*id), column.to_string()), error);
+                }
+            }
+        }
+        if !self.cell_errors.is_empty() {
+            return;
+        }
+
+        let to_values = |row: &BTreeMap<String, String>| -> Map<String, Value> {
+            row.iter().map(|(column, text)| (column.clone(), Value::from(text.as_str()))).collect()
+        };
+        let mut changes: Vec<(RowKey, BTreeMap<String, String>, RowChange)> = Vec::new();
+        for (key, row) in &self.updates {
+            // Silinecek satırların düzenlemeleri gönderilmez.
+            if !self.deletes.contains(key) {
+                changes.push((RowKey::Existing(*key), row.clone(), RowChange::Update { key: *key, values: to_values(row) }));
+            }
+        }
+        for key in &self.deletes {
+            changes.push((RowKey::Existing(*key), BTreeMap::new(), RowChange::Delete { key: *key }));
+        }
+        for (id, row) in &self.inserts {
+            changes.push((RowKey::New(*id), BTreeMap::new(), RowChange::Insert { values: to_values(row) }));
+        }
+        self.saved_any = false;
+        self.saving = changes
+            .into_iter()
+            .map(|(row_key, sent, change)| PendingSave {
+                row_key,
+                sent,
+                slot: Some(source.write_row(ctx, settings, self.table, change)),
+            })
+            .collect();
+    }
+
+    // Biten istekleri işler. Son istek de bittiğinde ve en az bir değişiklik kaydedildiyse
+    // true döner; görünüm bu durumda veriyi yeniden çekmelidir.
+    pub fn poll(&mut self) -> bool {
+        if self.saving.is_empty() {
+            return false;
+        }
+        let mut finished = Vec::new();
+        for pending in self.saving.iter_mut() {
+            if let Some(result) = task::take_ready(&mut pending.slot) {
+                finished.push((pending.row_key, std::mem::take(&mut pending.sent), result));
+            }
+        }
+        self.saving.retain(|pending| pending.slot.is_some());
+        for (row_key, sent, result) in finished {
+            match result {
+                Ok(_) => {
+                    self.saved_any = true;
+                    match row_key {
+                        // Silme isteği: satırın düzenlemeleri de gider.
+                        RowKey::Existing(key) if sent.is_empty() => {
+                            self.deletes.remove(&key);
+                            self.updates.remove(&key);
+                        }
+                        // Yalnızca gönderilip o zamandan beri değişmemiş değerler kaydedilmiş sayılır.
+                        RowKey::Existing(key) => {
+                            if let Some(row) = self.updates.get_mut(&key) {
+                                row.retain(|column, text| sent.get(column) != Some(text));
+                                if row.is_empty() {
+                                    self.updates.remove(&key);
+                                }
+                            }
+                        }
+                        RowKey::New(id) => {
+                            self.inserts.remove(&id);
+                        }
+                    }
+                }
+                Err(error) => self.record_error(row_key, error),
+            }
+        }
+        self.saving.is_empty() && std::mem::take(&mut self.saved_any)
+    }
+
+    // Sunucu sütun bazında hata döndürdüyse ilgili hücrelere, yoksa satıra yazılır.
+    fn record_error(&mut self, row_key: RowKey, error: FetchError) {
+        let fields = error.field_errors();
+        if fields.is_empty() {
+            let message = match error.server_details() {
+                Some(details) => format!("{}\n{}", error, details),
+                None => error.to_string(),
+            };
+            self.row_errors.insert(row_key, message);
+        } else {
+            for (column, message) in fields {
+                self.cell_errors.insert((row_key, column.clone()), message.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::CUSTOMERS_TABLE;
+
+    fn edits() -> RowEdits {
+        RowEdits::new(CUSTOMERS_TABLE, &["CustomerName", "Address"], |_, _| None)
+    }
+
+    fn row(values: &[(&str, &str)]) -> BTreeMap<String, String> {
+        values.iter().map(|(column, text)| (column.to_string(), text.to_string())).collect()
+    }
+
+    fn finished(row_key: RowKey, sent: BTreeMap<String, String>, result: Result<String, FetchError>) -> PendingSave {
+        PendingSave { row_key, sent, slot: Some(Task::ready(result)) }
+    }
+
+    #[test]
+    fn saved_update_keeps_values_typed_while_saving() {
+        let mut edits = edits();
+        let sent = row(&[("CustomerName", "Ada"), ("Address", "Kadıköy")]);
+        edits.updates.insert(1, sent.clone());
+        edits.saving.push(finished(RowKey::Existing(1), sent, Ok(String::new())));
+        // İstek sürerken adres yeniden değiştirildi.
+        edits.updates.get_mut(&1).unwrap().insert("Address".to_string(), "Moda".to_string());
+
+        assert!(edits.poll());
+        assert_eq!(edits.updates.get(&1), Some(&row(&[("Address", "Moda")])));
+        assert!(!edits.is_saving());
+    }
+
+    #[test]
+    fn saved_update_with_no_new_typing_is_cleared() {
+        let mut edits = edits();
+        let sent = row(&[("CustomerName", "Ada")]);
+        edits.updates.insert(1, sent.clone());
+        edits.saving.push(finished(RowKey::Existing(1), sent, Ok(String::new())));
+        assert!(edits.poll());
+        assert_eq!(edits.pending_count(), 0);
+    }
+
+    #[test]
+    fn delete_marked_while_update_saves_is_kept() {
+        let mut edits = edits();
+        let sent = row(&[("CustomerName", "Ada")]);
+        edits.updates.insert(1, sent.clone());
+        edits.saving.push(finished(RowKey::Existing(1), sent, Ok(String::new())));
+        edits.deletes.insert(1);
+        assert!(edits.poll());
+        assert!(edits.deletes.contains(&1));
+    }
+
+    #[test]
+    fn inserts_and_deletes_are_locked_until_saved() {
+        let mut edits = edits();
+        edits.inserts.insert(0, row(&[("CustomerName", "Ada")]));
+        edits.deletes.insert(2);
+        edits.saving.push(PendingSave { row_key: RowKey::New(0), sent: BTreeMap::new(), slot: None });
+        edits.saving.push(PendingSave { row_key: RowKey::Existing(2), sent: BTreeMap::new(), slot: None });
+        edits.saving.push(PendingSave { row_key: RowKey::Existing(3), sent: row(&[("Address", "Moda")]), slot: None });
+        assert!(edits.is_locked(RowKey::New(0)));
+        assert!(edits.is_locked(RowKey::Existing(2)));
+        assert!(!edits.is_locked(RowKey::Existing(3)));
+
+        edits.saving = vec![
+            finished(RowKey::New(0), BTreeMap::new(), Ok(String::new())),
+            finished(RowKey::Existing(2), BTreeMap::new(), Ok(String::new())),
+        ];
+        assert!(edits.poll());
+        assert_eq!(edits.pending_count(), 0);
+    }
+
+    #[test]
+    fn failed_save_keeps_the_edit_and_records_the_error() {
+        let mut edits = edits();
+        let sent = row(&[("CustomerName", "")]);
+        edits.updates.insert(1, sent.clone());
+        let error = FetchError::from_status("/customers/1", 400, r#"{"error": "Invalid", "fields": {"CustomerName": "is required"}}"#);
+        edits.saving.push(finished(RowKey::Existing(1), sent, Err(error)));
+        assert!(!edits.poll());
+        assert_eq!(edits.pending_count(), 1);
+        assert_eq!(
+            edits.cell_errors.get(&(RowKey::Existing(1), "CustomerName".to_string())).map(String::as_str),
+            Some("is required")
+        );
+    }
+}
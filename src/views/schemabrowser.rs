@@ -0,0 +1,229 @@
+use eframe::egui;
+use egui::RichText;
+use serde::{Deserialize, Serialize};
+
+use super::{errorpanel, queryconsole, PlaceWindow, View, ViewAction};
+use crate::app::AppSettings;
+use crate::datasource::schema::{ObjectKind, Schema, SchemaObject};
+use crate::datasource::{self, DataSource, FetchError};
+use crate::i18n::{self, t};
+use crate::task::{self, Task};
+
+pub const WINDOW_TITLE: &str = "Schema Browser";
+pub const VIEW_KIND: &str = "schema_browser";
+
+// Veritabanındaki tabloları ve görünümleri; sütunlarını, birincil anahtarlarını,
+// indekslerini ve yabancı anahtarlarını listeler. Bir tabloya çift tıklamak
+// verisini yeni bir tablo penceresinde açar.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchemaBrowser {
+    source: DataSource,
+    schema: Schema,
+    filter: String,
+    schema_fetched_on_open: bool,
+    #[serde(skip)]
+    error: Option<FetchError>,
+    #[serde(skip)]
+    pending_actions: Vec<ViewAction>,
+    #[serde(skip)]
+    schema_task: Option<Task<Result<String, FetchError>>>,
+}
+
+impl SchemaBrowser {
+    fn process_schema_json(&mut self, raw_json_result: Result<String, FetchError>) {
+        self.schema_fetched_on_open = true;
+        let parsed = raw_json_result.and_then(|raw_json| {
+            serde_json::from_str::<Schema>(&raw_json)
+                .map_err(|e| FetchError::SchemaMismatch { message: format!("Response is not a valid schema: {}", e) })
+        });
+        match parsed {
+            Ok(schema) => self.schema = schema,
+            Err(e) => {
+                self.schema = Schema::default();
+                self.error = Some(e);
+            }
+        }
+    }
+
+    fn trigger_fetch_schema(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.error = None;
+        self.schema_task = Some(self.source.fetch_schema(ctx, settings));
+    }
+
+    fn open_object(&mut self, object: &SchemaObject) {
+        let console = queryconsole::QueryConsole::for_table(self.source.clone(), &object.name);
+        self.pending_actions.push(ViewAction::OpenView(Box::new(console)));
+    }
+
+    fn object_ui(&mut self, ui: &mut egui::Ui, object: &SchemaObject) {
+        let icon = match object.kind {
+            ObjectKind::Table => "📋",
+            ObjectKind::View => "👁",
+        };
+        let header = egui::CollapsingHeader::new(format!("{} {}", icon, object.name))
+            .id_salt(("schema_object", &object.name))
+            .show(ui, |ui| {
+                if ui.small_button(t("schema-open-data")).clicked() {
+                    self.open_object(object);
+                }
+
+                egui::Grid::new(("schema_columns", &object.name))
+                    .striped(true)
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        for column in &object.columns {
+                            let mut name = RichText::new(&column.name);
+                            if column.primary_key > 0 {
+                                name = name.strong();
+                            }
+                            ui.label(name);
+                            ui.weak(if column.declared_type.is_empty() { "ANY" } else { &column.declared_type });
+                            let mut flags = Vec::new();
+                            if column.primary_key > 0 {
+                                flags.push("🔑 PK".to_string());
+                            }
+                            if column.not_null {
+                                flags.push("NOT NULL".to_string());
+                            }
+                            if let Some(default_value) = &column.default_value {
+                                flags.push(format!("DEFAULT {}", default_value));
+                            }
+                            ui.label(flags.join("  "));
+                            ui.end_row();
+                        }
+                    });
+
+                if !object.indexes.is_empty() {
+                    ui.label(RichText::new(t("schema-indexes")).strong());
+                    for index in &object.indexes {
+                        let unique = if index.unique { "UNIQUE " } else { "" };
+                        ui.label(format!("{}{} ({})", unique, index.name, index.columns.join(", ")));
+                    }
+                }
+
+                if !object.foreign_keys.is_empty() {
+                    ui.label(RichText::new(t("schema-foreign-keys")).strong());
+                    for key in &object.foreign_keys {
+                        ui.label(format!(
+                            "({}) → {}({})",
+                            key.columns.join(", "),
+                            key.referenced_table,
+                            key.referenced_columns.join(", ")
+                        ));
+                    }
+                }
+            });
+
+        if header.header_response.double_clicked() {
+            self.open_object(object);
+        }
+        header.header_response.on_hover_text(t("schema-open-data-hint"));
+    }
+}
+
+impl View for SchemaBrowser {
+    fn title(&self) -> String {
+        i18n::view_title(VIEW_KIND, WINDOW_TITLE, WINDOW_TITLE)
+    }
+
+    fn kind(&self) -> &'static str {
+        VIEW_KIND
+    }
+
+    fn refresh(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.trigger_fetch_schema(ctx, settings);
+    }
+
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) {
+        if let Ok(restored) = serde_json::from_value::<SchemaBrowser>(state) {
+            *self = restored;
+        }
+    }
+
+    fn take_actions(&mut self) -> Vec<ViewAction> {
+        std::mem::take(&mut self.pending_actions)
+    }
+
+    fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings) {
+        egui::Window::new(self.title())
+            .id(id)
+            .default_width(360.0)
+            .default_height(480.0)
+            .frame(egui::Frame::window(&ctx.style())
+                .corner_radius(settings.global_rounding)
+            )
+            .placed(ctx, id)
+            .open(open)
+            .show(ctx, |ui| {
+                self.ui(ui, settings);
+            });
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, settings: &AppSettings) {
+        let should_fetch = !self.schema_fetched_on_open && self.schema_task.is_none();
+        if should_fetch {
+            self.trigger_fetch_schema(ui.ctx(), settings);
+        }
+
+        if self.source.picker_ui(ui) {
+            self.trigger_fetch_schema(ui.ctx(), settings);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button(t("refresh")).clicked() {
+                self.trigger_fetch_schema(ui.ctx(), settings);
+            }
+            ui.add(
+                egui::TextEdit::singleline(&mut self.filter)
+                    .hint_text(t("schema-filter-tables"))
+                    .desired_width(f32::INFINITY),
+            );
+        });
+
+        if let Some(result) = task::take_ready(&mut self.schema_task) {
+            self.process_schema_json(result);
+        } else if self.schema_task.is_some() {
+            task::spinner(ui, &t("schema-loading"));
+        }
+
+        if let Some(error) = &self.error {
+            let source = self.source.describe(settings, datasource::SCHEMA_ENDPOINT);
+            if errorpanel::show(ui, error, &source) {
+                self.trigger_fetch_schema(ui.ctx(), settings);
+            }
+        }
+
+        ui.separator();
+
+        let filter = self.filter.to_lowercase();
+        // Nesneler çizim sırasında self'i değiştirebildiği (open_object) için kopyalanır.
+        let objects: Vec<SchemaObject> = self
+            .schema
+            .objects
+            .iter()
+            .filter(|object| filter.is_empty() || object.name.to_lowercase().contains(&filter))
+            .cloned()
+            .collect();
+
+        egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+            for kind in [ObjectKind::Table, ObjectKind::View] {
+                let of_kind: Vec<&SchemaObject> = objects.iter().filter(|o| o.kind == kind).collect();
+                if of_kind.is_empty() {
+                    continue;
+                }
+                ui.label(RichText::new(t(match kind {
+                    ObjectKind::Table => "schema-tables",
+                    ObjectKind::View => "schema-views",
+                })).strong());
+                for object in of_kind {
+                    self.object_ui(ui, object);
+                }
+            }
+        });
+    }
+}
@@ -1,125 +1,145 @@
 use eframe::egui;
-use super::View;
+use super::autorefresh::AutoRefresh;
+use super::resultgrid::{self, FilterMode, ResultGrid};
+use super::rowedits::{BarAction, RowEdits};
+use super::customerorders::CustomerOrders;
+use super::{errorpanel, PlaceWindow, View, ViewAction};
 use crate::app::AppSettings;
 use serde_json;
-use egui_extras; // egui_extras'ı doğrudan kullanacağız
 use serde::{Deserialize, Serialize};
 
-#[cfg(not(target_arch = "wasm32"))]
-use reqwest; // Native HTTP istekleri için
+use crate::datasource::changefeed::{ChangeBatch, ChangeOp};
+use crate::datasource::{self, cell_text, DataSource, FetchError, Paging, QueryResult};
+use crate::export::ExportMenu;
+use crate::i18n::{self, t, t_with};
+use crate::task::{self, Task};
 
-#[cfg(target_arch = "wasm32")]
-use {
-    poll_promise::Promise,
-    eframe::wasm_bindgen::JsCast, // eframe üzerinden JsCast
-    wasm_bindgen_futures::JsFuture,
-    web_sys::{Request, RequestInit, RequestMode, Response},
-};
+// Tabloda gösterilen sütunlar; yanıtta bu sütunların hepsinin bulunması beklenir.
+const CUSTOMER_ID_COLUMN: &str = "CustomerID";
+const CUSTOMER_NAME_COLUMN: &str = "CustomerName";
+const ADDRESS_COLUMN: &str = "Address";
+// Düzenleme kipinde değiştirilebilen sütunlar; CustomerID veritabanı tarafından atanır.
+const EDITABLE_COLUMNS: &[&str] = &[CUSTOMER_NAME_COLUMN, ADDRESS_COLUMN];
+// server/index.ts içindeki doğrulamayla aynı sınırlar.
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_ADDRESS_LENGTH: usize = 200;
 
+pub const WINDOW_TITLE: &str = "Connect Sqlite Database";
+pub const VIEW_KIND: &str = "sqlite_data";
 
+// Sayfa boyutu seçenekleri; büyük sayfalar da satırlar sanal olarak çizildiği için akıcı kalır.
+const PAGE_SIZES: [usize; 6] = [25, 100, 500, 1_000, 10_000, 100_000];
+const DEFAULT_PAGE_SIZE: usize = 100;
 
-// Sunucudan gelen müşteri verisi için bir struct tanımlayalım
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "PascalCase")] // JSON anahtarlarının PascalCase olduğunu belirtir
-struct Customer {
-    customer_name: String,
-    address: String,
-    #[serde(rename = "CustomerID")] // JSON'daki 'CustomerID' anahtarıyla eşleştir
-    customer_id: i64, 
+// Seçim, sayfa ve tablo ayarları kalıcı depolamaya yazılır. Satırlar yazılmaz (büyük sayfalar
+// depolamayı şişirir, eski veri de yanıltır); pencere açılınca sunucudan yeniden çekilir.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct SqliteData {
+    title: String, // Pencere başlığı; aynı tablonun birden fazla penceresi adlandırılabilir
+    source: DataSource, // Verinin HTTP sunucusundan mı yerel dosyadan mı okunacağı
+    #[serde(skip)]
+    customer_data_json: String, // Çekilen JSON verisini saklamak için
+    #[serde(skip)]
+    error: Option<FetchError>, // Son veri çekme hatasını saklamak için
+    #[serde(skip)]
+    data_task: Option<Task<Result<String, FetchError>>>,
+    #[serde(skip)]
+    customers: QueryResult, // Parse edilmiş müşteri satırlarını saklamak için
+    selected_customer_id_for_label: Option<i64>, // Tıklanan müşterinin ID'sini saklamak için
+    #[serde(skip)]
+    data_fetched_on_open: bool, // Pencere açıldığında verinin çekilip çekilmediğini takip eder
+    page_size: usize, // Sunucudan tek seferde istenen satır sayısı (LIMIT)
+    page_number: usize, // 0'dan başlayan sayfa numarası (OFFSET = page_number * page_size)
+    grid: ResultGrid, // Sıralama ve filtreler; sayfalı olduğu için sunucuda uygulanır
+    auto_refresh: AutoRefresh, // Geçerli sayfanın kendiliğinden yenilenme aralığı
+    #[serde(skip)]
+    export: ExportMenu, // Geçerli sayfayı CSV/JSON/XLSX olarak kaydeder
+    edit_mode: bool, // Hücreler metin kutusu olarak gösterilir, satır eklenip silinebilir
+    #[serde(skip)]
+    edits: RowEdits, // Kaydedilmemiş düzenlemeler, eklemeler ve silmeler
+    #[serde(skip)]
+    pending_actions: Vec<ViewAction>, // Müşteri sipariş penceresini açma istekleri
+    #[serde(skip)]
+    needs_reload: bool, // Canlı bir değişiklik yerinde uygulanamadı; sayfa arka planda yeniden istenecek
 }
 
-pub const WINDOW_TITLE: &str = "Connect Sqlite Database";
-
-#[cfg(not(target_arch = "wasm32"))]
-fn fetch_customer_data_from_server() -> Result<String, String> {
-    match reqwest::blocking::get("http://localhost:3000/customers") {
-        Ok(response) => {
-            if response.status().is_success() {
-                response.text().map_err(|e| format!("Failed to read response text: {}", e))
-            } else {
-                Err(format!("Request failed with status: {}", response.status()))
-            }
+// Bir müşteri hücresinin yeni değerini denetler; geçersizse hücrede gösterilecek iletiyi döndürür.
+fn validate_customer_field(column: &str, text: &str) -> Option<String> {
+    match column {
+        CUSTOMER_NAME_COLUMN if text.trim().is_empty() => Some(t("customers-name-required")),
+        CUSTOMER_NAME_COLUMN if text.chars().count() > MAX_NAME_LENGTH => {
+            Some(t_with("customers-name-too-long", &[("max", MAX_NAME_LENGTH.into())]))
         }
-        Err(e) => Err(format!("HTTP request failed: {}", e)),
+        ADDRESS_COLUMN if text.chars().count() > MAX_ADDRESS_LENGTH => {
+            Some(t_with("customers-address-too-long", &[("max", MAX_ADDRESS_LENGTH.into())]))
+        }
+        _ => None,
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-async fn fetch_customer_data_from_server_wasm() -> Result<String, String> {
-    let opts = RequestInit::new(); // Derleyici uyarısına göre 'mut' kaldırıldı
-    opts.set_method("GET");
-    opts.set_mode(RequestMode::Cors); // CORS gerekli olabilir
-
-    let request = Request::new_with_str_and_init("http://localhost:3000/customers", &opts)
-        .map_err(|e| format!("Failed to create request: {:?}", e))?;
-
-    let window = web_sys::window().ok_or_else(|| "Failed to get window object".to_string())?;
-    let resp_value = JsFuture::from(window.fetch_with_request(&request))
-        .await
-        .map_err(|e| format!("Fetch failed: {:?}", e))?;
-
-    // Yanıtın Response türünde olduğundan emin ol
-    let resp: Response = resp_value
-        .dyn_into()
-        .map_err(|e| format!("Failed to cast to Response: {:?}", e))?;
-
-    if resp.ok() { // status 200-299
-        let text = JsFuture::from(resp.text().map_err(|e| format!("Failed to get text from response: {:?}", e))?)
-            .await
-            .map_err(|e| format!("Failed to convert text promise: {:?}", e))?;
-        text.as_string().ok_or_else(|| "Response text was not a string".to_string())
-    } else {
-        Err(format!("Request failed with status: {}", resp.status()))
+impl Default for SqliteData {
+    fn default() -> Self {
+        Self {
+            title: WINDOW_TITLE.to_string(),
+            source: DataSource::default(),
+            customer_data_json: String::new(),
+            error: None,
+            data_task: None,
+            customers: QueryResult::default(),
+            selected_customer_id_for_label: None,
+            data_fetched_on_open: false,
+            page_size: DEFAULT_PAGE_SIZE,
+            page_number: 0,
+            grid: ResultGrid::default(),
+            auto_refresh: AutoRefresh::default(),
+            export: ExportMenu::default(),
+            edit_mode: false,
+            edits: RowEdits::new(datasource::CUSTOMERS_TABLE, EDITABLE_COLUMNS, validate_customer_field),
+            pending_actions: Vec::new(),
+            needs_reload: false,
+        }
     }
 }
 
-#[derive(Default)]
-pub struct SqliteData {
-    customer_data_json: String, // Çekilen JSON verisini saklamak için
-    error_message: Option<String>, // Hata mesajlarını saklamak için
-    #[cfg(target_arch = "wasm32")]
-    data_promise: Option<Promise<Result<String, String>>>,
-    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))] // Native'de kullanılmayacak ama WASM için gerekli
-    parsed_customers: Vec<Customer>, // Parse edilmiş müşteri verilerini saklamak için
-    selected_customer_id_for_label: Option<i64>, // Tıklanan müşterinin ID'sini saklamak için
-    data_fetched_on_open: bool, // Pencere açıldığında verinin çekilip çekilmediğini takip eder
-}
-
 impl SqliteData {
-    fn process_fetched_json(&mut self, raw_json_result: Result<String, String>) {
+    fn process_fetched_json(&mut self, raw_json_result: Result<String, FetchError>) {
         match raw_json_result {
             Ok(raw_json) => {
                 match serde_json::from_str::<serde_json::Value>(&raw_json) {
                     Ok(parsed_json) => { // Değişken adı düzeltildi
-                        match serde_json::to_string_pretty(&parsed_json) {
-                            Ok(pretty_json) => {
-                                self.customer_data_json = pretty_json;
-                            }
-                            Err(_) => {
-                                self.customer_data_json = raw_json;
-                                self.error_message = Some("Warning: Could not pretty-print JSON.".to_string());
-                            }
-                        }
-                        // Şimdi de Customer listesi olarak parse etmeye çalışalım
-                        match serde_json::from_value::<Vec<Customer>>(parsed_json.clone()) { // parsed_json kullanıldı ve clone eklendi
+                        self.customer_data_json = serde_json::to_string_pretty(&parsed_json).unwrap_or(raw_json);
+                        // Şimdi de genel satırlara çevirip beklenen sütunları denetleyelim
+                        let parsed = QueryResult::from_json(parsed_json).and_then(|result| {
+                            result
+                                .require_columns(&[CUSTOMER_ID_COLUMN, CUSTOMER_NAME_COLUMN, ADDRESS_COLUMN])
+                                .map(|_| result)
+                        });
+                        match parsed {
                             Ok(customers) => {
-                                self.parsed_customers = customers;
+                                self.customers = customers;
+                                self.error = None;
+                                self.clamp_page();
                             }
                             Err(e) => {
-                                self.parsed_customers.clear();
-                                self.error_message = Some(format!("Warning: Could not parse JSON into Customer list: {}", e));
+                                self.customers = QueryResult::default();
+                                self.error = Some(FetchError::SchemaMismatch {
+                                    message: format!("Could not read customer rows: {}", e),
+                                });
                             }
                         }
                     }
-                    Err(_) => {
+                    Err(e) => {
                         self.customer_data_json = raw_json;
-                        self.parsed_customers.clear();
-                        self.error_message = Some("Warning: Fetched data is not valid JSON.".to_string());
+                        self.customers = QueryResult::default();
+                        self.error = Some(FetchError::InvalidJson {
+                            message: format!("Fetched data is not valid JSON: {}", e),
+                        });
                     }
                 }
             }
             Err(e) => {
-                self.error_message = Some(e);
+                self.error = Some(e);
             }
             
         }
@@ -128,34 +148,178 @@ impl SqliteData {
     // Bu metod SqliteData'ya özel olduğu için impl SqliteData bloğunda kalmalı.
 
     // Veri çekme işlemini başlatan yardımcı fonksiyon
-    fn trigger_fetch_data(&mut self) {
-        self.error_message = None;
+    // İstek arka planda çalışır; sonuç ui() içinde görev tamamlandığında işlenir.
+    fn trigger_fetch_data(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.error = None;
         self.customer_data_json.clear();
-        self.parsed_customers.clear();
+        self.customers = QueryResult::default();
+        self.reload(ctx, settings);
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            let result = fetch_customer_data_from_server();
-            self.process_fetched_json(result);
-            self.data_fetched_on_open = true; // Native'de işlem senkron olduğu için hemen true yapabiliriz
-        }
+    // Geçerli sayfayı, gösterilen satırları silmeden yeniden ister; yeni sonuç gelene kadar
+    // tablo yerinde kaldığı için kaydırma konumu ve seçim korunur.
+    fn reload(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        // Önceki bekleyen istek varsa sonucu artık beklenmez.
+        let paging = Paging {
+            limit: self.page_size,
+            offset: self.page_number * self.page_size,
+            query: self.grid.query.clone(),
+            key_column: Some(datasource::CUSTOMERS_TABLE.key_column),
+        };
+        self.grid.mark_applied();
+        self.auto_refresh.started(ctx);
+        self.data_task = Some(self.source.fetch(ctx, settings, datasource::CUSTOMERS_ENDPOINT, datasource::CUSTOMERS_QUERY, Some(paging)));
+    }
 
-        #[cfg(target_arch = "wasm32")]
-        {
-            let (sender, promise) = poll_promise::Promise::new();
-            wasm_bindgen_futures::spawn_local(async move {
-                let result = fetch_customer_data_from_server_wasm().await;
-                let _ = sender.send(result);
-            });
-            self.data_promise = Some(promise);
-            // data_fetched_on_open WASM'da promise tamamlandığında true yapılmalı
+    // Toplam satır sayısı azalıp geçerli sayfa sona taştıysa son sayfaya geçilir ve o sayfa istenir.
+    fn clamp_page(&mut self) {
+        if self.customers.total.is_none() {
+            return;
+        }
+        let last_page = self.page_count() - 1;
+        if self.page_number > last_page {
+            self.page_number = last_page;
+            self.needs_reload = true;
         }
     }
+
+    fn page_count(&self) -> usize {
+        let total = self.customers.total.unwrap_or(self.customers.rows.len() as u64) as usize;
+        total.div_ceil(self.page_size.max(1)).max(1)
+    }
+
+    // Sayfa boyutu ve sayfa numarası denetimleri; değişiklik olursa true döner.
+    fn pager_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        let page_count = self.page_count();
+        ui.horizontal(|ui| {
+            ui.label(t("customers-page-size"));
+            egui::ComboBox::from_id_salt(ui.id().with("page_size"))
+                .selected_text(self.page_size.to_string())
+                .width(80.0)
+                .show_ui(ui, |ui| {
+                    for size in PAGE_SIZES {
+                        if ui.selectable_value(&mut self.page_size, size, size.to_string()).changed() {
+                            self.page_number = 0;
+                            changed = true;
+                        }
+                    }
+                });
+
+            ui.separator();
+
+            if ui.add_enabled(self.page_number > 0, egui::Button::new("⏮")).clicked() {
+                self.page_number = 0;
+                changed = true;
+            }
+            if ui.add_enabled(self.page_number > 0, egui::Button::new("◀")).clicked() {
+                self.page_number -= 1;
+                changed = true;
+            }
+            let mut page = self.page_number + 1;
+            let page_edit = ui.add(egui::DragValue::new(&mut page).range(1..=page_count));
+            if page_edit.changed() {
+                self.page_number = page - 1;
+                changed = true;
+            }
+            ui.label(t_with("customers-page-count", &[("count", page_count.into())]));
+            let has_next = self.page_number + 1 < page_count;
+            if ui.add_enabled(has_next, egui::Button::new("▶")).clicked() {
+                self.page_number += 1;
+                changed = true;
+            }
+            if ui.add_enabled(has_next, egui::Button::new("⏭")).clicked() {
+                self.page_number = page_count - 1;
+                changed = true;
+            }
+
+            if let Some(total) = self.customers.total {
+                ui.separator();
+                let first = self.page_number * self.page_size;
+                let last = first + self.customers.rows.len();
+                ui.label(t_with(
+                    "customers-rows-range",
+                    &[("first", (first + 1).min(last).into()), ("last", last.into()), ("total", total.into())],
+                ));
+            }
+        });
+        changed
+    }
 }
 
 impl View for SqliteData {
     fn title(&self) -> String {
-        WINDOW_TITLE.to_string()
+        i18n::view_title(VIEW_KIND, &self.title, WINDOW_TITLE)
+    }
+
+    fn kind(&self) -> &'static str {
+        VIEW_KIND
+    }
+
+    fn refresh(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        self.reload(ctx, settings);
+    }
+
+    // Sayfadaki satırların değişiklikleri yerinde uygulanır. Sayfada olmayan satırların eklenmesi
+    // ya da silinmesi sayfa sınırlarını kaydırdığından o durumda sayfa yeniden istenir. Sıralama
+    // ya da filtre varken eklenen ya da güncellenen satırın sayfadaki yeri (sayfada kalıp
+    // kalmayacağı) bilinemediği için her ekleme ve güncellemede sayfa yeniden istenir.
+    fn apply_changes(&mut self, changes: &ChangeBatch) {
+        if self.source != DataSource::HttpServer {
+            return;
+        }
+        if changes.missed {
+            self.needs_reload = true;
+        }
+        let Some(key_col) = self.customers.column_index(CUSTOMER_ID_COLUMN) else {
+            return;
+        };
+        let customers_table = datasource::CUSTOMERS_TABLE.table;
+        let query_active = !self.grid.query.is_empty();
+        for event in changes.events.iter().filter(|event| event.table == customers_table) {
+            let position = self.customers.rows.iter().position(|row| row[key_col].as_i64() == Some(event.key));
+            match (event.op, position) {
+                (ChangeOp::Insert | ChangeOp::Update, _) if query_active => self.needs_reload = true,
+                (ChangeOp::Delete, Some(i)) => {
+                    self.customers.rows.remove(i);
+                    if let Some(total) = self.customers.total.as_mut() {
+                        *total = total.saturating_sub(1);
+                    }
+                }
+                (ChangeOp::Insert | ChangeOp::Update, Some(i)) => {
+                    let row = &mut self.customers.rows[i];
+                    for (col, name) in self.customers.columns.iter().enumerate() {
+                        if let Some(value) = event.row.get(name) {
+                            row[col] = value.clone();
+                        }
+                    }
+                }
+                (ChangeOp::Update, None) => {}
+                _ => self.needs_reload = true,
+            }
+        }
+    }
+
+    fn can_rename(&self) -> bool {
+        true
+    }
+
+    fn rename(&mut self, title: String) {
+        self.title = title;
+    }
+
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) {
+        if let Ok(restored) = serde_json::from_value::<SqliteData>(state) {
+            *self = restored;
+        }
+    }
+
+    fn take_actions(&mut self) -> Vec<ViewAction> {
+        std::mem::take(&mut self.pending_actions)
     }
 
     fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings) {
@@ -163,68 +327,93 @@ impl View for SqliteData {
         egui::Window::new(self.title())
             .id(id)
             .default_width(480.0)
-            .frame(egui::Frame::window(&*ctx.style())
+            .frame(egui::Frame::window(&ctx.style())
                 .corner_radius(settings.global_rounding)
-                //.fill(settings.window_background_fill)
             )
+            .placed(ctx, id)
             .open(open) // Doğrudan 'open' değişkenini kullan
             .show(ctx, |ui| {
-                self.ui(ui);
+                self.ui(ui, settings);
                 // Pencere ilk kez açılıyorsa ve veri henüz çekilmemişse veriyi çek
                 // `open` burada pencerenin o anki görünürlüğünü değil, bir sonraki karede açık olup olmayacağını belirtir.
                 // Bu yüzden, veri çekme işlemini ui() içinde veya burada daha dikkatli yönetmek gerekebilir.
-                // Şimdilik, eğer data_promise None ise ve data_fetched_on_open false ise tetikleyelim.
-                // Veya daha basitçe, eğer parsed_customers boşsa ve data_promise yoksa.
+                // Şimdilik, eğer data_task None ise ve data_fetched_on_open false ise tetikleyelim.
+                // Veya daha basitçe, eğer customers boşsa ve data_task yoksa.
             });
     }
 
-    fn ui(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Customer Data from Server");
+    fn ui(&mut self, ui: &mut egui::Ui, settings: &AppSettings) {
+        ui.heading(t("customers-heading"));
         ui.separator();
 
         // Pencere ilk açıldığında veya veri henüz çekilmemişse veriyi çek
-        #[cfg(not(target_arch = "wasm32"))]
-        let should_fetch = !self.data_fetched_on_open && self.parsed_customers.is_empty();
-        #[cfg(target_arch = "wasm32")]
-        let mut should_fetch = !self.data_fetched_on_open && self.parsed_customers.is_empty();
-        #[cfg(target_arch = "wasm32")]
-        {
-            
-            should_fetch = should_fetch && self.data_promise.is_none();
-        }
+        let should_fetch = !self.data_fetched_on_open && self.customers.rows.is_empty() && self.data_task.is_none();
         if should_fetch {
-            self.trigger_fetch_data();
+            self.trigger_fetch_data(ui.ctx(), settings);
+        }
+
+        if self.source.picker_ui(ui) {
+            self.trigger_fetch_data(ui.ctx(), settings); // Kaynak değiştiğinde veriyi yeniden çek
+        }
+
+        if ui.button(t("customers-fetch")).clicked() {
+            self.trigger_fetch_data(ui.ctx(), settings); // Butona tıklandığında da veri çekme işlemini tetikle
         }
 
-        if ui.button("Fetch Customer Data").clicked() {
-            self.trigger_fetch_data(); // Butona tıklandığında da veri çekme işlemini tetikle
+        // Düzenleme kipinde ya da kaydedilmemiş değişiklikler varken kendiliğinden yenilenmez;
+        // canlı değişikliklerin istediği yenileme de düzenleme bitene kadar bekler.
+        let busy = self.data_task.is_some();
+        let editing = self.edit_mode || self.edits.pending_count() > 0 || self.edits.is_saving();
+        self.auto_refresh.ui(ui, busy && !self.customers.rows.is_empty(), editing);
+        if self.auto_refresh.due(ui.ctx(), busy, editing) || (self.needs_reload && !busy && !editing) {
+            self.needs_reload = false;
+            self.reload(ui.ctx(), settings);
         }
 
-        #[cfg(target_arch = "wasm32")]
-        if let Some(promise) = &self.data_promise {
-            if let Some(result) = promise.ready() {
-                self.process_fetched_json(result.clone());
-                self.data_promise = None;
-                self.data_fetched_on_open = true; // WASM'da promise tamamlandığında true yap
-            } else {
-                ui.spinner();
-                ui.label("Fetching data from server (WASM)...");
+        ui.checkbox(&mut self.edit_mode, t("customers-edit-rows"))
+            .on_hover_text(t("customers-edit-rows-hint"));
+
+        if self.pager_ui(ui) {
+            self.trigger_fetch_data(ui.ctx(), settings); // Yeni sayfayı sunucudan iste
+        }
+
+        if let Some(result) = task::take_ready(&mut self.data_task) {
+            self.process_fetched_json(result);
+            self.data_fetched_on_open = true; // Görev tamamlandığında true yap
+            if self.error.is_none() {
+                self.auto_refresh.updated(ui.ctx());
             }
+        } else if self.data_task.is_some() && self.customers.rows.is_empty() {
+            task::spinner(ui, &t("customers-fetching"));
         }
 
-        if let Some(err_msg) = &self.error_message {
-            ui.colored_label(egui::Color32::RED, err_msg);
+        if let Some(error) = &self.error {
+            let source = self.source.describe(settings, datasource::CUSTOMERS_ENDPOINT);
+            if errorpanel::show(ui, error, &source) {
+                self.trigger_fetch_data(ui.ctx(), settings);
+            }
+        }
+
+        // Kayıt istekleri bittiğinde tablo sunucudaki son hâliyle yeniden çekilir.
+        if self.edits.poll() {
+            self.trigger_fetch_data(ui.ctx(), settings);
+        }
+        match self.edits.bar_ui(ui) {
+            BarAction::Save => self.edits.save(&self.source, ui.ctx(), settings),
+            BarAction::Discard => self.edits.discard(),
+            BarAction::None => {}
         }
 
         ui.add_space(10.0);
 
         // Tablo ve TextEdit arasında geçiş için bir sekme yapısı veya ayırıcı kullanılabilir.
         // Şimdilik ikisini de gösterelim.
-        ui.collapsing("Raw JSON Data", |ui| {
+        let hint = t_with("customers-raw-json-hint", &[("source", self.source.describe(settings, datasource::CUSTOMERS_ENDPOINT).into())]);
+        egui::CollapsingHeader::new(t("customers-raw-json")).id_salt("raw_json").show(ui, |ui| {
             ui.add_sized(
                 ui.available_size() - egui::vec2(0.0, 0.0), // İçerik için tüm alanı kullan
                 egui::TextEdit::multiline(&mut self.customer_data_json)
-                    .hint_text("Click 'Fetch Customer Data' to load data from http://localhost:3000/customers")
+                    .hint_text(hint)
                     .desired_width(f32::INFINITY)
                     .desired_rows(5)
             );
@@ -232,33 +421,48 @@ impl View for SqliteData {
 
         ui.separator();
         
-        egui::CollapsingHeader::new("Customer Data Table")
+        egui::CollapsingHeader::new(t("customers-table"))
+            .id_salt("customer_data_table")
             .default_open(true) // Başlangıçta açık olmasını sağlar
             .show(ui, |ui| {
-                egui_extras::TableBuilder::new(ui)
-                    .striped(true)
-                    .resizable(true)
-                    .column(egui_extras::Column::initial(100.0).at_least(40.0))
-                    .column(egui_extras::Column::initial(200.0).at_least(80.0))
-                    .column(egui_extras::Column::remainder().at_least(100.0))
-                    .header(20.0, |mut header| {
-                        header.col(|ui| { ui.strong("Customer ID"); });
-                        header.col(|ui| { ui.strong("Customer Name"); });
-                        header.col(|ui| { ui.strong("Address"); });
-                    })
-                    .body(|mut body| {
-                        for customer_data in &self.parsed_customers {
-                            body.row(30.0, |mut row| {
-                                row.col(|ui| { ui.label(customer_data.customer_id.to_string()); });
-                                row.col(|ui| {
-                                    if ui.link(&customer_data.customer_name).clicked() {
-                                        self.selected_customer_id_for_label = Some(customer_data.customer_id);
-                                    }
-                                });
-                                row.col(|ui| { ui.label(&customer_data.address); });
-                            });
+                let (grid, customers) = (&self.grid, &self.customers);
+                self.export.ui(ui, &t("customers-export-name"), customers, || grid.rows_in_view(customers, FilterMode::Server));
+                let name_col = self.customers.column_index(CUSTOMER_NAME_COLUMN);
+                let id_col = self.customers.column_index(CUSTOMER_ID_COLUMN);
+                if self.edit_mode {
+                    self.edits.insert_ui(ui);
+                }
+                let mut clicked_customer_id = None;
+                let edit_mode = self.edit_mode;
+                let query_changed = self.grid.show(ui, "customer_table", &self.customers, FilterMode::Server, |ui, row, col, value| {
+                    let key = id_col.and_then(|id_col| self.customers.rows[row][id_col].as_i64());
+                    if let (true, Some(key)) = (edit_mode, key) {
+                        let column = &self.customers.columns[col];
+                        if Some(col) == id_col {
+                            self.edits.key_cell_ui(ui, key);
+                        } else if self.edits.is_editable(column) {
+                            self.edits.cell_ui(ui, key, column, value);
+                        } else {
+                            resultgrid::default_cell(ui, value);
                         }
-                    });
+                    } else if Some(col) == name_col {
+                        if ui.link(cell_text(value)).on_hover_text(t("customers-show-orders")).clicked() {
+                            clicked_customer_id = key.map(|key| (key, cell_text(value)));
+                        }
+                    } else {
+                        resultgrid::default_cell(ui, value);
+                    }
+                });
+                if let Some((customer_id, customer_name)) = clicked_customer_id {
+                    self.selected_customer_id_for_label = Some(customer_id);
+                    let orders = CustomerOrders::for_customer(self.source.clone(), customer_id, &customer_name);
+                    self.pending_actions.push(ViewAction::OpenOrFocus(Box::new(orders)));
+                }
+                if query_changed {
+                    // Sıralama ya da filtre değişince sonuçlar ilk sayfadan başlar.
+                    self.page_number = 0;
+                    self.trigger_fetch_data(ui.ctx(), settings);
+                }
             });
 
         ui.separator();
@@ -266,10 +470,126 @@ impl View for SqliteData {
         if let Some(customer_id) = self.selected_customer_id_for_label {
             ui.colored_label(
                 egui::Color32::from_rgb(100, 200, 100), // Yeşilimsi bir renk
-                format!("Selected Customer ID: {}", customer_id)
+                t_with("customers-selected-id", &[("id", customer_id.into())])
             );
         }
 
         ui.add_space(20.0); 
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn page(ids: &[i64], total: u64) -> String {
+        let rows: Vec<_> = ids.iter().map(|id| json!([id, format!("Customer {}", id), "Street"])).collect();
+        json!({"columns": [CUSTOMER_ID_COLUMN, CUSTOMER_NAME_COLUMN, ADDRESS_COLUMN], "rows": rows, "total": total}).to_string()
+    }
+
+    #[test]
+    fn saved_state_leaves_rows_out_and_fetches_again() {
+        let mut view = SqliteData::default();
+        view.process_fetched_json(Ok(page(&[1, 2, 3], 3)));
+        view.data_fetched_on_open = true;
+        view.selected_customer_id_for_label = Some(2);
+
+        let state = view.save_state();
+        assert!(state.get("customers").is_none());
+        assert!(state.get("customer_data_json").is_none());
+
+        let mut restored = SqliteData::default();
+        restored.load_state(state);
+        assert!(restored.customers.rows.is_empty());
+        assert!(!restored.data_fetched_on_open);
+        assert_eq!(restored.selected_customer_id_for_label, Some(2));
+    }
+
+    #[test]
+    fn page_past_the_end_moves_to_last_page() {
+        let mut view = SqliteData { page_size: 25, page_number: 3, ..Default::default() };
+        view.process_fetched_json(Ok(page(&[], 30)));
+        assert_eq!(view.page_number, 1);
+        assert!(view.needs_reload);
+
+        let mut view = SqliteData { page_size: 25, page_number: 3, ..Default::default() };
+        view.process_fetched_json(Ok(page(&[], 0)));
+        assert_eq!(view.page_number, 0);
+    }
+
+    fn loaded(ids: &[i64], total: u64) -> SqliteData {
+        let mut view = SqliteData::default();
+        view.process_fetched_json(Ok(page(ids, total)));
+        view
+    }
+
+    fn batch(events: serde_json::Value) -> ChangeBatch {
+        ChangeBatch { events: serde_json::from_value(events).unwrap(), missed: false }
+    }
+
+    fn names(view: &SqliteData) -> Vec<String> {
+        view.customers.rows.iter().map(|row| cell_text(&row[1])).collect()
+    }
+
+    #[test]
+    fn changes_on_the_page_are_applied_in_place() {
+        let mut view = loaded(&[1, 2, 3], 10);
+        view.apply_changes(&batch(json!([
+            {"table": "Customers", "op": "update", "key": 2, "row": {"CustomerID": 2, "CustomerName": "Ada"}},
+            {"table": "Customers", "op": "delete", "key": 3},
+            {"table": "Orders", "op": "delete", "key": 1},
+        ])));
+        assert_eq!(names(&view), vec!["Customer 1", "Ada"]);
+        assert_eq!(view.customers.total, Some(9));
+        assert!(!view.needs_reload);
+    }
+
+    #[test]
+    fn changes_off_the_page_reload_only_when_they_shift_it() {
+        let mut view = loaded(&[1, 2], 10);
+        view.apply_changes(&batch(json!([{"table": "Customers", "op": "update", "key": 9, "row": {"CustomerName": "Ada"}}])));
+        assert!(!view.needs_reload);
+        view.apply_changes(&batch(json!([{"table": "Customers", "op": "insert", "key": 11, "row": {"CustomerName": "Ada"}}])));
+        assert!(view.needs_reload);
+
+        let mut view = loaded(&[1, 2], 10);
+        view.apply_changes(&batch(json!([{"table": "Customers", "op": "delete", "key": 9}])));
+        assert!(view.needs_reload);
+    }
+
+    #[test]
+    fn sorted_or_filtered_page_reloads_instead_of_patching() {
+        let mut view = loaded(&[1, 2], 10);
+        view.grid.query.filters.insert(CUSTOMER_NAME_COLUMN.to_string(), "Customer".to_string());
+        view.apply_changes(&batch(json!([{"table": "Customers", "op": "update", "key": 2, "row": {"CustomerName": "Ada"}}])));
+        assert!(view.needs_reload);
+        // Satır yerinde değiştirilmez; filtreye artık uymayabilir.
+        assert_eq!(names(&view), vec!["Customer 1", "Customer 2"]);
+
+        let mut view = loaded(&[1, 2], 10);
+        view.grid.query.cycle_sort(CUSTOMER_NAME_COLUMN, false);
+        view.apply_changes(&batch(json!([{"table": "Customers", "op": "update", "key": 9, "row": {"CustomerName": "Ada"}}])));
+        assert!(view.needs_reload);
+    }
+
+    #[test]
+    fn missed_changes_reload_and_local_files_are_ignored() {
+        let mut view = loaded(&[1], 1);
+        view.apply_changes(&ChangeBatch { events: Vec::new(), missed: true });
+        assert!(view.needs_reload);
+
+        let mut view = loaded(&[1], 1);
+        view.source = DataSource::LocalFile { path: "customers.db".to_string() };
+        view.apply_changes(&ChangeBatch { events: Vec::new(), missed: true });
+        assert!(!view.needs_reload);
+    }
+
+    #[test]
+    fn page_within_total_is_kept() {
+        let mut view = SqliteData { page_size: 25, page_number: 1, ..Default::default() };
+        view.process_fetched_json(Ok(page(&[26, 27], 27)));
+        assert_eq!(view.page_number, 1);
+        assert!(!view.needs_reload);
+    }
+}
@@ -1,8 +1,10 @@
 use eframe::egui;
-use super::View;
+use super::{PlaceWindow, View};
 use crate::app::AppSettings;
+use crate::i18n::{self, t};
 
 pub const WINDOW_TITLE: &str = "Test Window";
+pub const VIEW_KIND: &str = "test_window";
 
 #[derive(Default)] // close_button_pressed varsayılan olarak false olacaktır
 pub struct TestWindow {
@@ -11,7 +13,11 @@ pub struct TestWindow {
 
 impl View for TestWindow {
     fn title(&self) -> String {
-        WINDOW_TITLE.to_string()
+        i18n::view_title(VIEW_KIND, WINDOW_TITLE, WINDOW_TITLE)
+    }
+
+    fn kind(&self) -> &'static str {
+        VIEW_KIND
     }
 
     fn show(&mut self, ctx: &egui::Context, id: egui::Id, open: &mut bool, settings: &AppSettings) {
@@ -21,13 +27,13 @@ impl View for TestWindow {
         egui::Window::new(self.title())
             .id(id)
             .default_width(320.0)
-            .frame(egui::Frame::window(&*ctx.style())
+            .frame(egui::Frame::window(&ctx.style())
                 .corner_radius(settings.global_rounding)
-                //.fill(settings.window_background_fill)
             )
+            .placed(ctx, id)
             .open(&mut window_is_open_for_egui)
             .show(ctx, |ui| {
-                self.ui(ui);
+                self.ui(ui, settings);
             });
 
         if self.close_button_pressed {
@@ -37,19 +43,19 @@ impl View for TestWindow {
         }
     }
 
-    fn ui(&mut self, ui: &mut egui::Ui) {
+    fn ui(&mut self, ui: &mut egui::Ui, _settings: &AppSettings) {
         // Orijinal stil ayarını kaldırabilir veya ihtiyaca göre tutabilirsiniz.
         // ui.style_mut().spacing.interact_size.y = 0.0;
 
-        ui.heading("Test Window");
+        ui.heading(self.title());
         ui.separator();
 
-        ui.label("This is a test window with a special label.");
+        ui.label(t("test-window-label"));
         ui.add_space(10.0); // Etiket ile buton arasına biraz boşluk ekleyelim
 
         // Butonu sağa dayalı olarak yerleştirmek için
         ui.with_layout(egui::Layout::top_down(egui::Align::Max), |ui_button_area| {
-            if ui_button_area.button("Close").clicked() {
+            if ui_button_area.button(t("close")).clicked() {
                 self.close_button_pressed = true;
             }
         });
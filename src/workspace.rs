@@ -1,41 +1,84 @@
+use crate::views::registry::{ViewDescriptor, ViewRegistry};
 use crate::views::*;
 use eframe::egui;
 use crate::app::AppSettings;
+use crate::datasource::changefeed::ChangeBatch;
+use crate::export::{self, OpenedFile};
+use crate::i18n::{t, t_or, t_with};
+use crate::task::{self, Task};
 use eframe::egui::containers::panel::Side;
-use eframe::egui::{Color32, Id, RichText};
+use eframe::egui::{Id, RichText};
+use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Default, Clone, Copy)]
-enum ConfirmDeleteState {
-    #[default]
-    Idle,
-    Pending,
-}
+mod dock;
+mod file;
+
+use dock::{DockAction, DockTree};
 
-#[derive(PartialEq, Clone, Copy)]
 pub enum WorkspaceAction {
     None,
     AddWorkspace,
+    // Dosyadan okunan çalışma alanı yeni bir sekme olarak eklenir.
+    ImportWorkspace(Box<Workspace>),
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct Workspace {
     pub name: String,
-    confirm_delete_state: ConfirmDeleteState,
+    // Sekmeler yeniden sıralansa da değişmeyen kimlik; pencere kimlikleri bundan türetilir.
+    // Eski kayıtlarda 0'dır, Application yüklerken yeni bir kimlik atar.
+    pub id: u64,
     info: Option<info::Info>,
-    views: Vec<Box<dyn View>>,
+    #[serde(skip)]
+    views: Vec<ViewInstance>,
+    // Bir sonraki pencereye verilecek kimlik; kapatılan pencerelerin kimlikleri yeniden kullanılmaz.
+    next_view_id: u64,
+    // Adı değiştirilen pencerenin kimliği ve düzenlenen başlık.
+    #[serde(skip)]
+    renaming: Option<(u64, String)>,
+    // true ise görünümler bölünebilir panellerde sekme olarak gösterilir (bkz. dock.rs).
+    docked: bool,
+    dock: DockTree,
+    // Yerleşik düzende yine de yüzen pencere olarak kalması istenen görünümler.
+    floating: Vec<u64>,
+    // İçe aktarılan pencerelerin kayıtlı yerleri; pencereler ilk kez çizilirken uygulanır.
+    #[serde(skip)]
+    placements: Vec<(u64, egui::Rect)>,
+    #[serde(skip)]
+    import_task: Option<Task<OpenedFile>>,
+    // Son dışa/içe aktarmanın sonucu, düğmelerin altında gösterilir.
+    #[serde(skip)]
+    file_status: Option<Result<String, String>>,
+    // Depolamadaki görünüm listesi. Kaydetmeden önce `store_views`, yükledikten sonra
+    // `restore_views` ile `views` ile eşitlenir (görünüm türleri ViewRegistry'den gelir).
+    #[serde(rename = "views")]
+    saved_views: Vec<SavedView>,
+}
+
+
+// Pencere kimliği dizinden değil görünümün kalıcı kimliğinden türetilir; böylece bir pencere
+// kapandığında diğerlerinin konumu kaymaz.
+fn window_id(parent_id: Id, view_id: u64) -> Id {
+    parent_id.with(("view", view_id))
+}
+
+fn move_to_top(ctx: &egui::Context, parent_id: Id, view_id: u64) {
+    ctx.move_to_top(egui::LayerId::new(egui::Order::Middle, window_id(parent_id, view_id)));
 }
 
 impl Workspace {
-    pub fn ui(&mut self, parent_id: Id, open: &mut bool, ctx: &egui::Context, settings: &AppSettings) -> WorkspaceAction {
+    pub fn ui(&mut self, parent_id: Id, open: &mut bool, ctx: &egui::Context, settings: &AppSettings, registry: &ViewRegistry) -> WorkspaceAction {
         let mut action_to_take = WorkspaceAction::None;
         egui::SidePanel::new(Side::Left, parent_id.with("workspace_left_panel"))
             .resizable(false)
             .show(ctx, |ui| {
                 ui.add_space(5.);
 
-                ui.label(RichText::new("Current workspace").strong());
+                ui.label(RichText::new(t("workspace-current")).strong());
                 ui.heading(RichText::new(&self.name).strong()); // Çalışma alanı adını kullan
                 ui.separator();
-                ui.label(RichText::new("Menu").strong());
+                ui.label(RichText::new(t("workspace-menu")).strong());
 
                 if ui
                     .selectable_label(self.info.is_some(), info::WINDOW_TITLE)
@@ -43,125 +86,394 @@ impl Workspace {
                 {
                     self.info = match self.info {
                         Some(_) => None,
-                        None => Some(info::Info::default()),
+                        None => Some(info::Info),
                     };
                 }
 
-                if ui.button(sqlitedata::WINDOW_TITLE).clicked() {
-                    let mut sqlite_window_exists = false;
-                    for view in self.views.iter() {
-                        if view.title() == sqlitedata::WINDOW_TITLE {
-                            sqlite_window_exists = true;
-                            break;
+                for (category, descriptors) in registry.menu() {
+                    ui.weak(t_or(&format!("category-{}", category.to_lowercase()), category));
+                    for descriptor in descriptors {
+                        let name = t_or(&format!("view-{}", descriptor.kind), descriptor.display_name);
+                        let label = format!("{} {}", descriptor.icon, name);
+                        if ui.button(label).clicked() {
+                            self.open_view(ctx, parent_id, descriptor);
                         }
                     }
-                    if !sqlite_window_exists {
-                        self.views.push(Box::new(sqlitedata::SqliteData::default()));
-                    }
-                }
-              
-
-                if ui.button(testwindow::WINDOW_TITLE).clicked() {
-                    let mut test_window_exists = false;
-                    for view in self.views.iter() {
-                        if view.title() == testwindow::WINDOW_TITLE {
-                            test_window_exists = true;
-                            break;
-                        }
-                    }
-                    if !test_window_exists {
-                        self.views.push(Box::new(testwindow::TestWindow::default()));
-                    }
                 }
 
                 ui.separator();
-                ui.label(RichText::new("Workspace").strong());
-
-                let (button_text, fill_color) = match self.confirm_delete_state {
-                    ConfirmDeleteState::Idle => ("🗑 Delete workspace", None),
-                    ConfirmDeleteState::Pending => ("🗑 Are you sure?", Some(Color32::LIGHT_RED)),
-                };
+                ui.label(RichText::new(t("workspace-section")).strong());
 
-                let mut delete_button = egui::Button::new(button_text);
-                if let Some(color) = fill_color {
-                    delete_button = delete_button.fill(color);
+                // Silinen çalışma alanı çöp kutusuna gider; "Undo" ile ya da çöp kutusundan geri alınabilir.
+                if ui.button(t("workspace-delete")).clicked() {
+                    *open = false;
                 }
 
-                if ui.add(delete_button).clicked() {
-                    match self.confirm_delete_state {
-                        ConfirmDeleteState::Idle => {
-                            self.confirm_delete_state = ConfirmDeleteState::Pending;
-                        }
-                        ConfirmDeleteState::Pending => {
-                            *open = false;
-                            // Workspace silineceği için durumu sıfırlamaya gerek yok,
-                            // eğer silinmezse reset_confirm_delete çağrılacak.
-                        }
-                    }
+                if ui.button(t("workspace-add")).clicked() {
+                    action_to_take = WorkspaceAction::AddWorkspace;
                 }
 
-                if ui.button("➕ Add workspace").clicked() {
-                    action_to_take = WorkspaceAction::AddWorkspace;
+                if ui.button(t("workspace-export")).clicked() {
+                    self.export_file(ctx, parent_id);
+                }
+                if ui
+                    .add_enabled(self.import_task.is_none(), egui::Button::new(t("workspace-import")))
+                    .clicked()
+                {
+                    self.import_task = Some(export::open_file(ctx, &t("workspace-file-label"), file::EXTENSION));
+                }
+                if let Some(imported) = self.poll_import(registry) {
+                    action_to_take = WorkspaceAction::ImportWorkspace(Box::new(imported));
+                }
+                match &self.file_status {
+                    Some(Ok(message)) => {
+                        ui.weak(message);
+                    }
+                    Some(Err(message)) => {
+                        ui.colored_label(ui.visuals().error_fg_color, message);
+                    }
+                    None => {}
                 }
 
                 ui.separator();
-                ui.label(RichText::new("Windows").strong());
+                ui.label(RichText::new(t("workspace-windows")).strong());
+
+                if ui
+                    .checkbox(&mut self.docked, t("workspace-docked"))
+                    .on_hover_text(t("workspace-docked-hint"))
+                    .changed()
+                    && self.docked
+                {
+                    self.dock_all();
+                }
 
-                if ui.button("Organize windows").clicked() {
-                    ui.ctx().memory_mut(|mem| {
-                        mem.reset_areas();
-                    });
-                    ui.close_menu();
+                if ui.button(t("workspace-organize-windows")).clicked() {
+                    Self::organize_windows(ctx);
                 }
 
-                if ui.button("Close all windows").clicked() {
-                    self.info = None;
-                    self.views.clear();
+                if ui.button(t("workspace-close-all-windows")).clicked() {
+                    self.close_all_windows();
                 }
+
+                self.window_list_ui(ui, parent_id);
             });
 
         let mut to_delete = Vec::new();
-        egui::CentralPanel::default().show(ctx, |_ui| {
+        let mut opened_views = Vec::new();
+        let mut focused_views = Vec::new();
+        egui::CentralPanel::default().show(ctx, |ui| {
             let mut open = true;
 
             if let Some(info) = self.info.as_mut() {
                 info.show(ctx, parent_id.with("info"), &mut open, settings);
-                if open == false {
+                if !open {
                     self.info = None;
                 }
             }
 
-            for (i, view) in self.views.iter_mut().enumerate() {
-                let mut open = true;
-                view.show(ctx, parent_id.with(i), &mut open, settings);
-                if open == false {
-                    to_delete.push(i);
+            for (view_id, rect) in self.placements.drain(..) {
+                set_window_placement(ctx, window_id(parent_id, view_id), rect);
+            }
+            if self.docked {
+                let dock_actions = self.dock.ui(ui, &mut self.views, settings, |view_id| window_id(parent_id, view_id));
+                for dock_action in dock_actions {
+                    match dock_action {
+                        DockAction::Float(view_id) => {
+                            self.dock.remove(view_id);
+                            self.floating.push(view_id);
+                        }
+                        DockAction::Close(view_id) => to_delete.push(view_id),
+                    }
+                }
+            }
+            for instance in self.views.iter_mut() {
+                // Yerleşik düzende panellerdeki görünümler yukarıda çizildi.
+                if !self.docked || !self.dock.contains(instance.id) {
+                    let mut open = true;
+                    instance.view.show(ctx, window_id(parent_id, instance.id), &mut open, settings);
+                    if !open {
+                        to_delete.push(instance.id);
+                    }
+                }
+                for view_action in instance.view.take_actions() {
+                    match view_action {
+                        ViewAction::OpenView(new_view) => opened_views.push(new_view),
+                        ViewAction::OpenOrFocus(new_view) => focused_views.push(new_view),
+                    }
                 }
             }
         });
 
-        let mut removed = 0;
-        for i in to_delete {
-            self.views.remove(i - removed);
-            removed += 1;
+        self.close_views(&to_delete);
+        for new_view in opened_views {
+            self.add_view(new_view);
+        }
+        for new_view in focused_views {
+            match self.views.iter_mut().find(|instance| instance.view.kind() == new_view.kind()) {
+                Some(instance) => {
+                    instance.view.load_state(new_view.save_state());
+                    let view_id = instance.id;
+                    self.focus_view(ctx, parent_id, view_id);
+                }
+                None => self.add_view(new_view),
+            }
         }
+        self.prune_layout();
 
         action_to_take
     }
 
-    pub fn reset_confirm_delete(&mut self) {
-        self.confirm_delete_state = ConfirmDeleteState::Idle;
+    fn export_file(&mut self, ctx: &egui::Context, parent_id: Id) {
+        let saved = file::encode(self, ctx, parent_id).and_then(|text| {
+            let file_name = export::file_name(&self.name, file::EXTENSION);
+            export::save_file(&file_name, &t("workspace-file-label"), file::EXTENSION, "application/json", text.as_bytes())
+        });
+        self.file_status = match saved {
+            Ok(Some(target)) => Some(Ok(t_with("workspace-exported", &[("target", target.into())]))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        };
+    }
+
+    // Seçilen dosya okunduysa yeni çalışma alanını döndürür.
+    fn poll_import(&mut self, registry: &ViewRegistry) -> Option<Workspace> {
+        let opened = task::take_ready(&mut self.import_task)?;
+        let imported = opened.and_then(|opened| {
+            opened.map(|(source, text)| file::decode(&text, registry).map(|workspace| (source, workspace))).transpose()
+        });
+        match imported {
+            Ok(Some((source, workspace))) => {
+                self.file_status = Some(Ok(t_with("workspace-imported", &[("name", workspace.name.clone().into()), ("source", source.into())])));
+                Some(workspace)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                self.file_status = Some(Err(e));
+                None
+            }
+        }
+    }
+
+    // Tek örnekli bir görünüm zaten açıksa yenisi açılmaz, mevcut pencere öne gelir.
+    pub fn open_view(&mut self, ctx: &egui::Context, parent_id: Id, descriptor: &ViewDescriptor) {
+        let existing = self.views.iter().find(|instance| instance.view.kind() == descriptor.kind);
+        match existing.filter(|_| descriptor.single_instance) {
+            Some(instance) => {
+                let view_id = instance.id;
+                self.focus_view(ctx, parent_id, view_id);
+            }
+            None => self.add_view((descriptor.create)()),
+        }
+    }
+
+    // Pencereleri egui'nin varsayılan yerlerine geri yerleştirir.
+    pub fn organize_windows(ctx: &egui::Context) {
+        ctx.memory_mut(|mem| mem.reset_areas());
+    }
+
+    // Kapanan pencere yeniden adlandırılıyorsa yarım kalan ad da bırakılır.
+    fn close_views(&mut self, view_ids: &[u64]) {
+        self.views.retain(|instance| !view_ids.contains(&instance.id));
+        if self.renaming.as_ref().is_some_and(|(id, _)| view_ids.contains(id)) {
+            self.renaming = None;
+        }
+    }
+
+    pub fn close_all_windows(&mut self) {
+        self.info = None;
+        self.views.clear();
+        self.renaming = None;
+    }
+
+    // Açık görünümlerin hepsi verisini yeniden çeker.
+    pub fn refresh_views(&mut self, ctx: &egui::Context, settings: &AppSettings) {
+        for instance in self.views.iter_mut() {
+            instance.view.refresh(ctx, settings);
+        }
+    }
+
+    pub fn apply_changes(&mut self, changes: &ChangeBatch) {
+        for instance in self.views.iter_mut() {
+            instance.view.apply_changes(changes);
+        }
+    }
+
+    fn add_view(&mut self, view: Box<dyn View>) {
+        self.next_view_id += 1;
+        self.views.push(ViewInstance { id: self.next_view_id, view });
+        if self.docked {
+            self.dock.add(self.next_view_id);
+        }
+    }
+
+    // Yüzen pencereyi öne getirir; yerleşik düzende sekmesini seçer.
+    fn focus_view(&mut self, ctx: &egui::Context, parent_id: Id, view_id: u64) {
+        self.dock.focus(view_id);
+        move_to_top(ctx, parent_id, view_id);
+    }
+
+    // Yerleşik düzene geçerken ağaçta ya da yüzen listede olmayan görünümler panellere eklenir.
+    fn dock_all(&mut self) {
+        for instance in &self.views {
+            if !self.floating.contains(&instance.id) {
+                self.dock.add(instance.id);
+            }
+        }
+    }
+
+    fn set_floating(&mut self, view_id: u64, floating: bool) {
+        if floating {
+            self.dock.remove(view_id);
+            self.floating.push(view_id);
+        } else {
+            self.floating.retain(|id| *id != view_id);
+            self.dock.add(view_id);
+        }
+    }
+
+    // Kapatılan görünümlerin kimliklerini düzen bilgisinden temizler.
+    fn prune_layout(&mut self) {
+        let ids: Vec<u64> = self.views.iter().map(|instance| instance.id).collect();
+        self.dock.retain(|view_id| ids.contains(&view_id));
+        self.floating.retain(|view_id| ids.contains(view_id));
+    }
+
+    // Açık pencerelerin listesi: tıklamak pencereyi öne getirir, ✏ başlığı değiştirir, ✖ kapatır.
+    fn window_list_ui(&mut self, ui: &mut egui::Ui, parent_id: Id) {
+        if self.views.is_empty() {
+            return;
+        }
+        ui.add_space(5.);
+        let mut close = None;
+        let mut focus = None;
+        let mut toggle_floating = None;
+        for instance in self.views.iter_mut() {
+            ui.horizontal(|ui| {
+                if let Some((_, title)) = self.renaming.as_mut().filter(|(id, _)| *id == instance.id) {
+                    let response = ui.add(egui::TextEdit::singleline(title).desired_width(140.0));
+                    if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                        self.renaming = None;
+                    } else if response.lost_focus() {
+                        // Boş başlık kabul edilmez; pencere eski adıyla kalır. Değişmeyen başlık
+                        // yazılmaz ki varsayılan başlık dil değişince çevrilmeye devam etsin.
+                        if !title.trim().is_empty() && title.trim() != instance.view.title() {
+                            instance.view.rename(title.trim().to_string());
+                        }
+                        self.renaming = None;
+                    } else {
+                        response.request_focus();
+                    }
+                    return;
+                }
+                if ui.small_button("✖").on_hover_text(t("window-close")).clicked() {
+                    close = Some(instance.id);
+                }
+                if instance.view.can_rename()
+                    && ui.small_button("✏").on_hover_text(t("window-rename")).clicked()
+                {
+                    self.renaming = Some((instance.id, instance.view.title()));
+                }
+                if self.docked {
+                    let docked = self.dock.contains(instance.id);
+                    let (icon, hint) = if docked { ("⧉", t("window-float")) } else { ("📌", t("window-dock")) };
+                    if ui.small_button(icon).on_hover_text(hint).clicked() {
+                        toggle_floating = Some((instance.id, docked));
+                    }
+                }
+                if ui.link(instance.view.title()).clicked() {
+                    focus = Some(instance.id);
+                }
+            });
+        }
+        if let Some(view_id) = close {
+            self.close_views(&[view_id]);
+        }
+        if let Some((view_id, floating)) = toggle_floating {
+            self.set_floating(view_id, floating);
+        }
+        if let Some(view_id) = focus {
+            self.focus_view(ui.ctx(), parent_id, view_id);
+        }
+    }
+
+    // Açık görünümlerin durumunu depolamaya yazılacak listeye aktarır.
+    pub fn store_views(&mut self) {
+        self.saved_views = save_views(&self.views);
+    }
+
+    // Depolamadan okunan görünümleri kayıtlı türlerine göre yeniden oluşturur.
+    pub fn restore_views(&mut self, registry: &ViewRegistry) {
+        let saved = std::mem::take(&mut self.saved_views);
+        self.views.clear();
+        let restored = registry.restore(saved);
+        self.reserve_view_ids(restored.iter().map(|instance| instance.id));
+        for instance in restored {
+            self.insert_restored(instance);
+        }
+        self.prune_layout();
+    }
+
+    // Kimliksiz görünümlere verilecek yeni kimlikler, sonra eklenecek kayıtlı kimliklerle
+    // çakışmasın diye hepsi eklenmeden önce çağrılır.
+    fn reserve_view_ids(&mut self, view_ids: impl IntoIterator<Item = u64>) {
+        self.next_view_id = view_ids.into_iter().fold(self.next_view_id, u64::max);
+    }
+
+    // Geri yüklenen görünümü kimliğini koruyarak ekler ve kullanılan kimliği döndürür.
+    // Eski kayıtlarda kimlik yoktur (0); çakışan kimlikler de yenilenir (bkz. reserve_view_ids).
+    fn insert_restored(&mut self, instance: ViewInstance) -> u64 {
+        if instance.id == 0 || self.views.iter().any(|open| open.id == instance.id) {
+            self.add_view(instance.view);
+            self.next_view_id
+        } else {
+            let id = instance.id;
+            self.next_view_id = self.next_view_id.max(id);
+            self.views.push(instance);
+            id
+        }
+    }
+
+    // Pencere kimliklerinin türetildiği kök; sekme sırasından bağımsızdır.
+    pub fn egui_id(&self) -> Id {
+        Id::new(("workspace", self.id))
+    }
+
+    pub fn view_count(&self) -> usize {
+        self.views.len()
+    }
+
+    // Çalışma alanını açık pencereleri ve pencerelerin durumlarıyla birlikte kopyalar.
+    // Kopya, kaydedip geri yükleme yoluyla yapılır; böylece görünümlerin Clone olması gerekmez.
+    pub fn duplicate(&self, name: String, id: u64, registry: &ViewRegistry) -> Self {
+        let mut copy = Self::new_with_name(name);
+        copy.id = id;
+        copy.info = self.info.as_ref().map(|_| info::Info);
+        copy.docked = self.docked;
+        copy.dock = self.dock.clone();
+        copy.floating = self.floating.clone();
+        copy.saved_views = save_views(&self.views);
+        copy.restore_views(registry);
+        copy
     }
 
     pub fn new_with_name(name: String) -> Self {
         Self {
             name,
-            confirm_delete_state: ConfirmDeleteState::Idle,
+            id: 0,
             // Yeni oluşturulan çalışma alanlarında varsayılan olarak Info penceresi
             // açık olmayabilir, isteğe bağlı olarak None veya Some(info::Info::default())
             // ayarlanabilir. Şimdilik None olarak bırakıyorum.
             info: None,
             views: Default::default(),
+            next_view_id: 0,
+            renaming: None,
+            docked: false,
+            dock: DockTree::default(),
+            floating: Vec::new(),
+            placements: Vec::new(),
+            import_task: None,
+            file_status: None,
+            saved_views: Vec::new(),
         }
     }
 }
@@ -170,11 +482,20 @@ impl Default for Workspace {
     fn default() -> Self {
         Self {
             name: "Workspace".to_string(),
-            confirm_delete_state: ConfirmDeleteState::Idle,
+            id: 0,
             // Varsayılan olarak Info penceresi açık olsun mu?
             // new_with_name ile tutarlı olması için None veya Some olarak ayarlanabilir.
             info: None, // Veya Some(info::Info::default())
             views: Default::default(),
+            next_view_id: 0,
+            renaming: None,
+            docked: false,
+            dock: DockTree::default(),
+            floating: Vec::new(),
+            placements: Vec::new(),
+            import_task: None,
+            file_status: None,
+            saved_views: Vec::new(),
         }
     }
 }
@@ -0,0 +1,368 @@
+// Yerleşik (docked) düzen: görünümler bölünebilir panellerde sekme olarak gösterilir.
+// Ağaçta yalnızca görünüm kimlikleri (ViewInstance::id) tutulur; görünümlerin kendisi
+// Workspace::views içinde kalır, böylece yüzen pencere ile panel arasında geçiş durumu kaybetmez.
+// Bir sekmeyi başka bir panelin kenarına sürüklemek o paneli yatay ya da dikey olarak böler,
+// ortasına bırakmak sekmeyi o panele ekler.
+
+use eframe::egui::{self, Id, Rect, Sense};
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppSettings;
+use crate::i18n::t;
+use crate::views::ViewInstance;
+
+const TAB_BAR_HEIGHT: f32 = 24.0;
+const SPLITTER_WIDTH: f32 = 6.0;
+// Panelin bu oranı kadar kenar şeridine bırakılan sekme paneli böler.
+const EDGE_ZONE: f32 = 0.25;
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Axis {
+    // Yan yana iki panel.
+    Horizontal,
+    // Üst üste iki panel.
+    Vertical,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum Node {
+    Tabs { tabs: Vec<u64>, active: usize },
+    Split { axis: Axis, fraction: f32, children: Box<[Node; 2]> },
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DropZone {
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+// Sekme çubuğundan istenen işlemler; ağaç çizildikten sonra uygulanır.
+pub enum DockAction {
+    Float(u64),
+    Close(u64),
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DockTree {
+    root: Option<Node>,
+    // Sürüklenen sekme; bırakılınca hedef panele taşınır.
+    #[serde(skip)]
+    dragging: Option<u64>,
+}
+
+impl Node {
+    fn contains(&self, view_id: u64) -> bool {
+        match self {
+            Node::Tabs { tabs, .. } => tabs.contains(&view_id),
+            Node::Split { children, .. } => children.iter().any(|child| child.contains(view_id)),
+        }
+    }
+
+    // Görünümü kaldırır; boş kalan panel ağaçtan düşer (None döner).
+    fn remove(self, view_id: u64) -> Option<Node> {
+        match self {
+            Node::Tabs { mut tabs, active } => {
+                tabs.retain(|tab| *tab != view_id);
+                (!tabs.is_empty()).then(|| Node::Tabs { active: active.min(tabs.len() - 1), tabs })
+            }
+            Node::Split { axis, fraction, children } => {
+                let [first, second] = *children;
+                match (first.remove(view_id), second.remove(view_id)) {
+                    (Some(first), Some(second)) => Some(Node::Split { axis, fraction, children: Box::new([first, second]) }),
+                    (Some(only), None) | (None, Some(only)) => Some(only),
+                    (None, None) => None,
+                }
+            }
+        }
+    }
+
+    fn retain(self, keep: &dyn Fn(u64) -> bool) -> Option<Node> {
+        match self {
+            Node::Tabs { mut tabs, active } => {
+                tabs.retain(|tab| keep(*tab));
+                (!tabs.is_empty()).then(|| Node::Tabs { active: active.min(tabs.len() - 1), tabs })
+            }
+            Node::Split { axis, fraction, children } => {
+                let [first, second] = *children;
+                match (first.retain(keep), second.retain(keep)) {
+                    (Some(first), Some(second)) => Some(Node::Split { axis, fraction, children: Box::new([first, second]) }),
+                    (Some(only), None) | (None, Some(only)) => Some(only),
+                    (None, None) => None,
+                }
+            }
+        }
+    }
+
+    // `anchor` sekmesini içeren paneli bulur.
+    fn leaf_with(&mut self, anchor: u64) -> Option<&mut Node> {
+        match self {
+            Node::Tabs { tabs, .. } if tabs.contains(&anchor) => Some(self),
+            Node::Tabs { .. } => None,
+            Node::Split { children, .. } => {
+                let [first, second] = &mut **children;
+                first.leaf_with(anchor).or_else(|| second.leaf_with(anchor))
+            }
+        }
+    }
+
+    fn first_leaf(&mut self) -> &mut Node {
+        match self {
+            Node::Tabs { .. } => self,
+            Node::Split { children, .. } => children[0].first_leaf(),
+        }
+    }
+
+    fn at_path(&mut self, path: &[usize]) -> Option<&mut Node> {
+        match (path.split_first(), self) {
+            (None, node) => Some(node),
+            (Some((&i, rest)), Node::Split { children, .. }) => children.get_mut(i)?.at_path(rest),
+            (Some(_), Node::Tabs { .. }) => None,
+        }
+    }
+
+    // Panellerin ve bölücülerin ekrandaki yerlerini hesaplar.
+    fn layout(&self, rect: Rect, path: &mut Vec<usize>, leaves: &mut Vec<(Vec<u64>, usize, Rect)>, splitters: &mut Vec<(Vec<usize>, Axis, Rect, Rect)>) {
+        match self {
+            Node::Tabs { tabs, active } => leaves.push((tabs.clone(), *active, rect)),
+            Node::Split { axis, fraction, children } => {
+                let (first, handle, second) = split_rect(rect, *axis, *fraction);
+                splitters.push((path.clone(), *axis, handle, rect));
+                for (i, (child, child_rect)) in children.iter().zip([first, second]).enumerate() {
+                    path.push(i);
+                    child.layout(child_rect, path, leaves, splitters);
+                    path.pop();
+                }
+            }
+        }
+    }
+}
+
+fn split_rect(rect: Rect, axis: Axis, fraction: f32) -> (Rect, Rect, Rect) {
+    let half = SPLITTER_WIDTH / 2.0;
+    match axis {
+        Axis::Horizontal => {
+            let x = rect.left() + rect.width() * fraction;
+            (
+                Rect::from_min_max(rect.min, egui::pos2(x - half, rect.bottom())),
+                Rect::from_min_max(egui::pos2(x - half, rect.top()), egui::pos2(x + half, rect.bottom())),
+                Rect::from_min_max(egui::pos2(x + half, rect.top()), rect.max),
+            )
+        }
+        Axis::Vertical => {
+            let y = rect.top() + rect.height() * fraction;
+            (
+                Rect::from_min_max(rect.min, egui::pos2(rect.right(), y - half)),
+                Rect::from_min_max(egui::pos2(rect.left(), y - half), egui::pos2(rect.right(), y + half)),
+                Rect::from_min_max(egui::pos2(rect.left(), y + half), rect.max),
+            )
+        }
+    }
+}
+
+fn drop_zone(rect: Rect, pointer: egui::Pos2) -> DropZone {
+    let x = (pointer.x - rect.left()) / rect.width();
+    let y = (pointer.y - rect.top()) / rect.height();
+    // En yakın kenar belirleyicidir; köşelerde yatay bölme tercih edilir.
+    let edges = [(x, DropZone::Left), (1.0 - x, DropZone::Right), (y, DropZone::Top), (1.0 - y, DropZone::Bottom)];
+    edges
+        .into_iter()
+        .filter(|(distance, _)| *distance < EDGE_ZONE)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map_or(DropZone::Center, |(_, zone)| zone)
+}
+
+fn zone_rect(rect: Rect, zone: DropZone) -> Rect {
+    match zone {
+        DropZone::Center => rect,
+        DropZone::Left => Rect::from_min_max(rect.min, egui::pos2(rect.center().x, rect.bottom())),
+        DropZone::Right => Rect::from_min_max(egui::pos2(rect.center().x, rect.top()), rect.max),
+        DropZone::Top => Rect::from_min_max(rect.min, egui::pos2(rect.right(), rect.center().y)),
+        DropZone::Bottom => Rect::from_min_max(egui::pos2(rect.left(), rect.center().y), rect.max),
+    }
+}
+
+impl DockTree {
+    pub fn contains(&self, view_id: u64) -> bool {
+        self.root.as_ref().is_some_and(|root| root.contains(view_id))
+    }
+
+    // Görünümü ilk panele yeni sekme olarak ekler ve seçer.
+    pub fn add(&mut self, view_id: u64) {
+        if self.contains(view_id) {
+            return;
+        }
+        match self.root.as_mut().map(Node::first_leaf) {
+            Some(Node::Tabs { tabs, active }) => {
+                tabs.push(view_id);
+                *active = tabs.len() - 1;
+            }
+            _ => self.root = Some(Node::Tabs { tabs: vec![view_id], active: 0 }),
+        }
+    }
+
+    pub fn remove(&mut self, view_id: u64) {
+        self.root = self.root.take().and_then(|root| root.remove(view_id));
+    }
+
+    // Kapatılan görünümleri ağaçtan temizler.
+    pub fn retain(&mut self, keep: impl Fn(u64) -> bool) {
+        self.root = self.root.take().and_then(|root| root.retain(&keep));
+    }
+
+    // Görünümün sekmesini kendi panelinde seçili hale getirir.
+    pub fn focus(&mut self, view_id: u64) {
+        if let Some(Node::Tabs { tabs, active }) = self.root.as_mut().and_then(|root| root.leaf_with(view_id)) {
+            if let Some(i) = tabs.iter().position(|tab| *tab == view_id) {
+                *active = i;
+            }
+        }
+    }
+
+    // Sürüklenen sekmeyi `anchor` sekmesinin bulunduğu panele taşır ya da o paneli böler.
+    fn drop_tab(&mut self, view_id: u64, anchor_panel: &[u64], zone: DropZone) {
+        let Some(anchor) = anchor_panel.iter().copied().find(|tab| *tab != view_id) else {
+            // Tek sekmeli paneli kendi üzerine bırakmak bir şey değiştirmez.
+            return;
+        };
+        if zone == DropZone::Center && anchor_panel.contains(&view_id) {
+            return;
+        }
+        self.remove(view_id);
+        let Some(leaf) = self.root.as_mut().and_then(|root| root.leaf_with(anchor)) else {
+            return;
+        };
+        let new_tabs = Node::Tabs { tabs: vec![view_id], active: 0 };
+        let (axis, new_first) = match zone {
+            DropZone::Center => {
+                if let Node::Tabs { tabs, active } = leaf {
+                    tabs.push(view_id);
+                    *active = tabs.len() - 1;
+                }
+                return;
+            }
+            DropZone::Left => (Axis::Horizontal, true),
+            DropZone::Right => (Axis::Horizontal, false),
+            DropZone::Top => (Axis::Vertical, true),
+            DropZone::Bottom => (Axis::Vertical, false),
+        };
+        let existing = std::mem::replace(leaf, Node::Tabs { tabs: Vec::new(), active: 0 });
+        let children = if new_first { [new_tabs, existing] } else { [existing, new_tabs] };
+        *leaf = Node::Split { axis, fraction: 0.5, children: Box::new(children) };
+    }
+
+    // Panelleri `ui`nin kalan alanına çizer. `window_id` yüzen pencere kimliğiyle aynı kökten
+    // türetilir; böylece görünümlerin iç durumu (tablo sütunları, kaydırma) iki kipte ayrı tutulur.
+    pub fn ui(&mut self, ui: &mut egui::Ui, views: &mut [ViewInstance], settings: &AppSettings, window_id: impl Fn(u64) -> Id) -> Vec<DockAction> {
+        let mut actions = Vec::new();
+        let area = ui.available_rect_before_wrap();
+        ui.allocate_rect(area, Sense::hover());
+        let Some(root) = &self.root else {
+            ui.put(area, egui::Label::new(egui::RichText::new(t("dock-empty")).weak()));
+            return actions;
+        };
+
+        let mut leaves = Vec::new();
+        let mut splitters = Vec::new();
+        root.layout(area, &mut Vec::new(), &mut leaves, &mut splitters);
+
+        // Bölücüler sürüklenerek panellerin oranı değiştirilir.
+        for (path, axis, handle, parent) in &splitters {
+            let response = ui.interact(*handle, ui.id().with(("dock_splitter", path)), Sense::drag());
+            let hovered = response.hovered() || response.dragged();
+            if hovered {
+                ui.ctx().set_cursor_icon(match axis {
+                    Axis::Horizontal => egui::CursorIcon::ResizeHorizontal,
+                    Axis::Vertical => egui::CursorIcon::ResizeVertical,
+                });
+            }
+            let stroke = if hovered { ui.visuals().selection.stroke } else { ui.visuals().widgets.noninteractive.bg_stroke };
+            match axis {
+                Axis::Horizontal => ui.painter().vline(handle.center().x, handle.y_range(), stroke),
+                Axis::Vertical => ui.painter().hline(handle.x_range(), handle.center().y, stroke),
+            };
+            if let (true, Some(pointer)) = (response.dragged(), response.interact_pointer_pos()) {
+                let fraction = match axis {
+                    Axis::Horizontal => (pointer.x - parent.left()) / parent.width(),
+                    Axis::Vertical => (pointer.y - parent.top()) / parent.height(),
+                };
+                if let Some(Node::Split { fraction: current, .. }) = self.root.as_mut().and_then(|root| root.at_path(path)) {
+                    *current = fraction.clamp(0.1, 0.9);
+                }
+            }
+        }
+
+        let mut selected = None;
+        for (tabs, active, rect) in &leaves {
+            let (bar_rect, content_rect) = rect.split_top_bottom_at_y(rect.top() + TAB_BAR_HEIGHT);
+            let mut bar = ui.new_child(egui::UiBuilder::new().max_rect(bar_rect).layout(egui::Layout::left_to_right(egui::Align::Center)));
+            bar.set_clip_rect(bar_rect);
+            for (i, view_id) in tabs.iter().enumerate() {
+                let Some(instance) = views.iter().find(|instance| instance.id == *view_id) else {
+                    continue;
+                };
+                let response = bar
+                    .selectable_label(i == *active, instance.view.title())
+                    .interact(Sense::click_and_drag());
+                if response.clicked() {
+                    selected = Some(*view_id);
+                }
+                if response.drag_started() {
+                    self.dragging = Some(*view_id);
+                }
+                response.context_menu(|ui| {
+                    if ui.button(t("dock-float")).clicked() {
+                        actions.push(DockAction::Float(*view_id));
+                        ui.close_menu();
+                    }
+                    if ui.button(t("dock-close")).clicked() {
+                        actions.push(DockAction::Close(*view_id));
+                        ui.close_menu();
+                    }
+                });
+            }
+            ui.painter().hline(bar_rect.x_range(), bar_rect.bottom(), ui.visuals().widgets.noninteractive.bg_stroke);
+
+            let Some(instance) = tabs.get(*active).and_then(|view_id| views.iter_mut().find(|instance| instance.id == *view_id)) else {
+                continue;
+            };
+            let mut content = ui.new_child(
+                egui::UiBuilder::new()
+                    .id_salt(window_id(instance.id))
+                    .max_rect(content_rect.shrink(4.0))
+                    .layout(egui::Layout::top_down(egui::Align::Min)),
+            );
+            content.set_clip_rect(content_rect);
+            instance.view.ui(&mut content, settings);
+        }
+        if let Some(view_id) = selected {
+            self.focus(view_id);
+        }
+
+        // Sürükleme sırasında hedef bölge vurgulanır; fare bırakılınca sekme taşınır.
+        if let Some(view_id) = self.dragging {
+            let pointer = ui.ctx().pointer_latest_pos();
+            let target = pointer.and_then(|pointer| {
+                leaves
+                    .iter()
+                    .find(|(_, _, rect)| rect.contains(pointer))
+                    .map(|(tabs, _, rect)| (tabs, *rect, drop_zone(*rect, pointer)))
+            });
+            if let Some((_, rect, zone)) = target {
+                let highlight = ui.visuals().selection.bg_fill.gamma_multiply(0.4);
+                ui.painter().rect_filled(zone_rect(rect, zone), 0.0, highlight);
+            }
+            if ui.input(|input| input.pointer.any_released()) {
+                self.dragging = None;
+                if let Some((tabs, _, zone)) = target {
+                    let tabs = tabs.clone();
+                    self.drop_tab(view_id, &tabs, zone);
+                }
+            }
+        }
+        actions
+    }
+}
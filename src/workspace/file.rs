@@ -0,0 +1,268 @@
+// Çalışma alanı düzenini paylaşmak için sürümlü JSON belgesi: ad, açık pencereler,
+// pencerelerin kendi durumları (sorgular, filtreler, grafik ayarları) ve ekrandaki yerleri.
+//
+// Sürüm geçmişi:
+//   0: Sürüm alanı olmayan dosyalar; uygulamanın depoladığı Workspace biçimi
+//      (`{"name", "info", "views": [{"kind", "state"}]}`), pencere yerleri yok.
+//   1: `format`, `version`, `info` (bool) ve her pencere için isteğe bağlı `rect`.
+//   2: Yerleşik düzen: `docked`, panel ağacı `dock` ve yüzen kalan pencereler `floating`.
+
+use eframe::egui::{self, Id};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::dock::DockTree;
+use super::{window_id, Workspace};
+use crate::views::registry::ViewRegistry;
+use crate::views::{info, save_views, SavedView};
+
+const FORMAT: &str = "emartident-workspace";
+pub const VERSION: u64 = 2;
+pub const EXTENSION: &str = "json";
+
+#[derive(Serialize, Deserialize)]
+struct WorkspaceFile {
+    format: String,
+    version: u64,
+    name: String,
+    #[serde(default)]
+    info: bool,
+    #[serde(default)]
+    views: Vec<FileView>,
+    #[serde(default)]
+    docked: bool,
+    #[serde(default)]
+    dock: DockTree,
+    #[serde(default)]
+    floating: Vec<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileView {
+    #[serde(flatten)]
+    view: SavedView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rect: Option<WindowRect>,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct WindowRect {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+impl From<egui::Rect> for WindowRect {
+    fn from(rect: egui::Rect) -> Self {
+        Self { x: rect.min.x, y: rect.min.y, width: rect.width(), height: rect.height() }
+    }
+}
+
+impl From<WindowRect> for egui::Rect {
+    fn from(rect: WindowRect) -> Self {
+        egui::Rect::from_min_size(egui::pos2(rect.x, rect.y), egui::vec2(rect.width, rect.height))
+    }
+}
+
+// Çalışma alanını belgeye çevirir. Pencere yerleri egui'nin hafızasından okunur;
+// henüz hiç çizilmemiş pencerelerin yeri yazılmaz.
+pub fn encode(workspace: &Workspace, ctx: &egui::Context, parent_id: Id) -> Result<String, String> {
+    let views = save_views(&workspace.views)
+        .into_iter()
+        .zip(&workspace.views)
+        .map(|(view, instance)| FileView {
+            view,
+            rect: ctx.memory(|mem| mem.area_rect(window_id(parent_id, instance.id))).map(WindowRect::from),
+        })
+        .collect();
+    let file = WorkspaceFile {
+        format: FORMAT.to_string(),
+        version: VERSION,
+        name: workspace.name.clone(),
+        info: workspace.info.is_some(),
+        views,
+        docked: workspace.docked,
+        dock: workspace.dock.clone(),
+        floating: workspace.floating.clone(),
+    };
+    serde_json::to_string_pretty(&file).map_err(|e| format!("Failed to encode the workspace: {}", e))
+}
+
+// Belgeyi okuyup yeni bir çalışma alanı oluşturur. Eski sürümler önce güncel biçime taşınır;
+// bu derlemede kayıtlı olmayan görünüm türleri atlanır.
+pub fn decode(text: &str, registry: &ViewRegistry) -> Result<Workspace, String> {
+    let value: Value = serde_json::from_str(text).map_err(|e| format!("The file is not valid JSON: {}", e))?;
+    let file: WorkspaceFile = serde_json::from_value(migrate(value)?)
+        .map_err(|e| format!("The file is not a workspace layout: {}", e))?;
+
+    let mut workspace = Workspace::new_with_name(file.name);
+    workspace.info = file.info.then_some(info::Info);
+    let restored: Vec<_> = file
+        .views
+        .into_iter()
+        .flat_map(|file_view| {
+            let rect = file_view.rect;
+            registry.restore(vec![file_view.view]).into_iter().map(move |instance| (instance, rect))
+        })
+        .collect();
+    workspace.reserve_view_ids(restored.iter().map(|(instance, _)| instance.id));
+    for (instance, rect) in restored {
+        // Görünüm kimliği çakışırsa yenisi verilir; yer, atanan kimlikle eşleştirilir.
+        let id = workspace.insert_restored(instance);
+        if let Some(rect) = rect {
+            workspace.placements.push((id, rect.into()));
+        }
+    }
+    workspace.docked = file.docked;
+    workspace.dock = file.dock;
+    workspace.floating = file.floating;
+    workspace.prune_layout();
+    Ok(workspace)
+}
+
+// Eski sürümleri adım adım güncel sürüme taşır.
+fn migrate(mut value: Value) -> Result<Value, String> {
+    let Some(object) = value.as_object_mut() else {
+        return Err("The file is not a workspace layout".to_string());
+    };
+    if let Some(format) = object.get("format").and_then(Value::as_str) {
+        if format != FORMAT {
+            return Err(format!("Unknown file format '{}'", format));
+        }
+    }
+    let mut version = object.get("version").and_then(Value::as_u64).unwrap_or(0);
+    if version > VERSION {
+        return Err(format!(
+            "The file was written by a newer version of the app (format version {}, supported up to {})",
+            version, VERSION
+        ));
+    }
+
+    if version == 0 {
+        // Depolama biçiminde "info" birim yapı olduğu için her zaman null'dır; açık olup
+        // olmadığı bilinemez, bu yüzden kapalı kabul edilir.
+        object.insert("format".to_string(), Value::from(FORMAT));
+        object.insert("info".to_string(), Value::Bool(false));
+        version = 1;
+    }
+    if version == 1 {
+        // Yerleşik düzen alanları yoksa çalışma alanı yüzen pencerelerle açılır.
+        version = 2;
+    }
+
+    object.insert("version".to_string(), Value::from(version));
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn version_0_gets_format_and_closed_info() {
+        let migrated = migrate(json!({"name": "Sales", "info": null, "views": []})).unwrap();
+        assert_eq!(migrated["format"], FORMAT);
+        assert_eq!(migrated["version"], VERSION);
+        assert_eq!(migrated["info"], false);
+        assert_eq!(migrated["name"], "Sales");
+    }
+
+    #[test]
+    fn version_1_keeps_its_fields() {
+        let file = json!({"format": FORMAT, "version": 1, "name": "Sales", "info": true, "views": []});
+        let migrated = migrate(file).unwrap();
+        assert_eq!(migrated["version"], VERSION);
+        assert_eq!(migrated["info"], true);
+        assert!(migrated.get("docked").is_none());
+    }
+
+    #[test]
+    fn current_version_is_unchanged() {
+        let file = json!({"format": FORMAT, "version": VERSION, "name": "Sales", "docked": true});
+        assert_eq!(migrate(file.clone()).unwrap(), file);
+    }
+
+    #[test]
+    fn rejects_unknown_format_newer_version_and_non_objects() {
+        let error = migrate(json!({"format": "something-else", "version": 1})).unwrap_err();
+        assert!(error.contains("something-else"), "{}", error);
+        let error = migrate(json!({"format": FORMAT, "version": VERSION + 1})).unwrap_err();
+        assert!(error.contains("newer version"), "{}", error);
+        assert!(migrate(json!([1, 2, 3])).is_err());
+        assert!(migrate(json!("workspace")).is_err());
+    }
+
+    #[test]
+    fn decodes_version_0_file() {
+        let registry = ViewRegistry::with_builtin_views();
+        let text = json!({
+            "name": "Old layout",
+            "info": null,
+            "views": [
+                {"kind": crate::views::queryconsole::VIEW_KIND, "state": {}},
+                {"kind": "not_in_this_build", "state": {}},
+            ],
+        })
+        .to_string();
+        let workspace = decode(&text, &registry).unwrap();
+        assert_eq!(workspace.name, "Old layout");
+        assert!(workspace.info.is_none());
+        // Bilinmeyen görünüm türü atlanır.
+        assert_eq!(workspace.view_count(), 1);
+        assert!(!workspace.docked);
+        assert!(workspace.placements.is_empty());
+    }
+
+    #[test]
+    fn decodes_version_1_window_rects() {
+        let registry = ViewRegistry::with_builtin_views();
+        let text = json!({
+            "format": FORMAT,
+            "version": 1,
+            "name": "Sales",
+            "info": true,
+            "views": [{"kind": crate::views::queryconsole::VIEW_KIND, "id": 7, "rect": {"x": 10.0, "y": 20.0, "width": 300.0, "height": 200.0}}],
+        })
+        .to_string();
+        let workspace = decode(&text, &registry).unwrap();
+        assert!(workspace.info.is_some());
+        assert_eq!(workspace.view_count(), 1);
+        let (_, rect) = workspace.placements[0];
+        assert_eq!(rect, egui::Rect::from_min_size(egui::pos2(10.0, 20.0), egui::vec2(300.0, 200.0)));
+    }
+
+    #[test]
+    fn views_without_ids_do_not_take_later_saved_ids() {
+        let registry = ViewRegistry::with_builtin_views();
+        let kind = crate::views::queryconsole::VIEW_KIND;
+        let text = json!({
+            "format": FORMAT,
+            "version": VERSION,
+            "name": "Sales",
+            "views": [
+                {"kind": kind},
+                {"kind": kind, "id": 1, "rect": {"x": 10.0, "y": 20.0, "width": 300.0, "height": 200.0}},
+            ],
+            "docked": true,
+            "dock": {"root": {"Tabs": {"tabs": [1], "active": 0}}},
+        })
+        .to_string();
+        let workspace = decode(&text, &registry).unwrap();
+        let ids: Vec<u64> = workspace.views.iter().map(|instance| instance.id).collect();
+        assert_eq!(ids, [2, 1]);
+        // Kayıtlı kimliğe bağlı yer ve panel, o kimliğin penceresinde kalır.
+        assert_eq!(workspace.placements[0].0, 1);
+        assert!(workspace.dock.contains(1));
+        assert!(!workspace.dock.contains(2));
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        let registry = ViewRegistry::with_builtin_views();
+        let Err(error) = decode("{not json", &registry) else { panic!("invalid JSON was accepted") };
+        assert!(error.contains("not valid JSON"), "{}", error);
+        assert!(decode(r#"{"format": "emartident-workspace", "version": 2}"#, &registry).is_err());
+    }
+}